@@ -0,0 +1,42 @@
+//! Benchmarks of the parser, over the oracle texts of the FDN cards.
+//!
+//! These need the card base.
+
+use boseiju::*;
+use criterion::Criterion;
+use criterion::black_box;
+
+/// Tokens of all the FDN cards we can lex, with the name of the card.
+fn fdn_tokens() -> Vec<(String, Vec<lexer::tokens::Token>)> {
+    mtg_cardbase::AllCardsIter::new()
+        .iter()
+        .filter(|card| card.set == "fdn")
+        .filter_map(|card| {
+            let oracle_text = lexer::preprocess(&card.name, card.oracle_text.as_ref()?);
+            /* Don't take into account cards we couldn't lex */
+            let tokens = lexer::lex(&oracle_text).ok()?;
+            Some((card.name.clone(), tokens))
+        })
+        .collect()
+}
+
+fn parser_benches(c: &mut Criterion) {
+    let cards = fdn_tokens();
+
+    c.bench_function("parse FDN cards", |b| {
+        b.iter(|| {
+            for (_, tokens) in cards.iter() {
+                let _ = black_box(parser::parse(black_box(tokens)));
+            }
+        })
+    });
+
+    /* The longest oracle text shows how the parser scales with the number of tokens */
+    if let Some((name, tokens)) = cards.iter().max_by_key(|(_, tokens)| tokens.len()) {
+        let bench_name = format!("parse longest FDN card ({name}, {} tokens)", tokens.len());
+        c.bench_function(&bench_name, |b| b.iter(|| parser::parse(black_box(tokens))));
+    }
+}
+
+criterion::criterion_group!(benches, parser_benches);
+criterion::criterion_main!(benches);
@@ -0,0 +1,270 @@
+//! Command line tool to parse an oracle text or a card, and print the result.
+//!
+//! Run with `--help` for the list of options.
+
+use boseiju::ability_tree::AbilityTreeNode;
+use std::io::Write;
+
+const USAGE: &str = "\
+Usage: boseiju [OPTIONS] [ORACLE_TEXT]
+
+Parse an oracle text, or a card from the card base, and print the result.
+If no oracle text and no card is provided, the oracle text is read from stdin.
+
+Options:
+  -n, --name <NAME>      Card name used for the self references of the oracle text (default: \"~\")
+  -c, --card <NAME>      Look up a card by name in the card base
+  -i, --id <ID>          Look up a card by Scryfall id in the card base
+  -f, --format <FORMAT>  Output format: tree, tokens, json, dot or mermaid (default: tree)
+      --preprocess       Only preprocess the oracle text, and print it
+      --lex              Only lex the oracle text, and print the tokens
+      --earley-chart     Print the Earley chart of the parser as a graph (dot or mermaid, default: dot)
+      --derivation       Print the derivation chosen by the parser as a graph (dot or mermaid, default: dot)
+      --spans            Print the spans of tokens and nodes (requires the spanned_tree feature)
+  -h, --help             Print this help";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Tree,
+    Tokens,
+    Json,
+    Dot,
+    Mermaid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Preprocess,
+    Lex,
+    EarleyChart,
+    Derivation,
+    Parse,
+}
+
+enum Input {
+    OracleText { name: String, oracle_text: Option<String> },
+    CardName(String),
+    CardId(String),
+}
+
+struct Options {
+    input: Input,
+    format: Format,
+    stage: Stage,
+    spans: bool,
+}
+
+fn main() {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return;
+        }
+        Err(e) => {
+            eprintln!("Error: {e}\n\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(e) = run(options) {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
+
+/// Parse the command line arguments, returns none if the help was requested.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut name = None;
+    let mut oracle_text = None;
+    let mut card_name = None;
+    let mut card_id = None;
+    let mut format = Format::Tree;
+    let mut stage = Stage::Parse;
+    let mut spans = false;
+
+    while let Some(arg) = args.next() {
+        let mut value = |option: &str| args.next().ok_or(format!("Missing value for option {option}"));
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-n" | "--name" => name = Some(value(&arg)?),
+            "-c" | "--card" => card_name = Some(value(&arg)?),
+            "-i" | "--id" => card_id = Some(value(&arg)?),
+            "-f" | "--format" => {
+                format = match value(&arg)?.as_str() {
+                    "tree" => Format::Tree,
+                    "tokens" => Format::Tokens,
+                    "json" => Format::Json,
+                    "dot" => Format::Dot,
+                    "mermaid" => Format::Mermaid,
+                    other => return Err(format!("Unknown format \"{other}\"")),
+                }
+            }
+            "--preprocess" => stage = Stage::Preprocess,
+            "--lex" => stage = Stage::Lex,
+            "--earley-chart" => stage = Stage::EarleyChart,
+            "--derivation" => stage = Stage::Derivation,
+            "--spans" => spans = true,
+            other if other.starts_with('-') => return Err(format!("Unknown option \"{other}\"")),
+            _ if oracle_text.is_some() => return Err(format!("Unexpected argument \"{arg}\"")),
+            _ => oracle_text = Some(arg),
+        }
+    }
+
+    if spans && !cfg!(feature = "spanned_tree") {
+        return Err("Spans are only available when built with the spanned_tree feature".to_string());
+    }
+
+    let input = match (card_name, card_id) {
+        (Some(_), Some(_)) => return Err("A card can't be looked up by name and id at the same time".to_string()),
+        (Some(_), None) | (None, Some(_)) if oracle_text.is_some() => {
+            return Err("An oracle text can't be provided along a card".to_string());
+        }
+        (Some(_), None) | (None, Some(_)) if name.is_some() => {
+            return Err("A name can't be provided along a card, the card name is used".to_string());
+        }
+        (Some(card_name), None) => Input::CardName(card_name),
+        (None, Some(card_id)) => Input::CardId(card_id),
+        (None, None) => Input::OracleText {
+            name: name.unwrap_or_else(|| "~".to_string()),
+            oracle_text,
+        },
+    };
+
+    Ok(Some(Options {
+        input,
+        format,
+        stage,
+        spans,
+    }))
+}
+
+fn run(options: Options) -> Result<(), Box<dyn std::error::Error>> {
+    let (card_name, oracle_text, raw_card) = match options.input {
+        Input::OracleText { name, oracle_text } => {
+            let oracle_text = match oracle_text {
+                Some(oracle_text) => oracle_text,
+                None => std::io::read_to_string(std::io::stdin())?,
+            };
+            (boseiju::lexer::CardName::new(&name), oracle_text, None)
+        }
+        Input::CardName(card_name) => {
+            let card = find_card(|card| card.name.eq_ignore_ascii_case(&card_name))
+                .ok_or(format!("No card named \"{card_name}\" in the card base"))?;
+            let card_name = boseiju::lexer::CardName::from_raw_card(&card);
+            (card_name, card.oracle_text.clone().unwrap_or_default(), Some(card))
+        }
+        Input::CardId(card_id) => {
+            let card = find_card(|card| card.id == card_id).ok_or(format!("No card with id \"{card_id}\" in the card base"))?;
+            let card_name = boseiju::lexer::CardName::from_raw_card(&card);
+            (card_name, card.oracle_text.clone().unwrap_or_default(), Some(card))
+        }
+    };
+
+    let mut stdout = std::io::stdout();
+
+    let preprocessed = boseiju::lexer::preprocess_card(&card_name, &oracle_text);
+    if options.stage == Stage::Preprocess {
+        match options.format {
+            Format::Json => println!("{}", serde_json::to_string(&preprocessed.text)?),
+            _ => println!("{}", preprocessed.text),
+        }
+        return Ok(());
+    }
+
+    let tokens = boseiju::lexer::lex(&preprocessed.text)?;
+    if options.stage == Stage::Lex || options.format == Format::Tokens {
+        match options.format {
+            Format::Json => println!("{}", serde_json::to_string_pretty(&tokens)?),
+            _ => print_tokens(&tokens, &preprocessed.text, options.spans),
+        }
+        return Ok(());
+    }
+
+    if matches!(options.stage, Stage::EarleyChart | Stage::Derivation) {
+        let graph = match options.stage {
+            Stage::EarleyChart => boseiju::parser::earley_chart_graph(&tokens),
+            _ => boseiju::parser::derivation_graph(&tokens)?,
+        };
+        let format = match options.format {
+            Format::Mermaid => boseiju::utils::GraphFormat::Mermaid,
+            _ => boseiju::utils::GraphFormat::Dot,
+        };
+        graph.write(format, &mut stdout)?;
+        return Ok(());
+    }
+
+    /* When looking up a card, print the whole card instead of the oracle text only */
+    if let Some(raw_card) = raw_card {
+        let card = boseiju::Card::try_from(&raw_card)?;
+        match options.format {
+            Format::Tree if options.spans => print_spanned_tree(card.layout.root(), &preprocessed.text, 0),
+            Format::Tree => card.display(&mut stdout)?,
+            Format::Json => println!("{}", serde_json::to_string_pretty(&card)?),
+            Format::Dot => boseiju::utils::write_dot(card.layout.root(), &mut stdout)?,
+            Format::Mermaid => boseiju::utils::write_mermaid(card.layout.root(), &mut stdout)?,
+            Format::Tokens => unreachable!(),
+        }
+        return Ok(());
+    }
+
+    let tree = boseiju::parser::parse(&tokens)?;
+    match options.format {
+        Format::Tree if options.spans => print_spanned_tree(&tree, &preprocessed.text, 0),
+        Format::Tree => {
+            tree.display_from_root(&mut stdout, "")?;
+            writeln!(stdout)?;
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(&tree)?),
+        Format::Dot => boseiju::utils::write_dot(&tree, &mut stdout)?,
+        Format::Mermaid => boseiju::utils::write_mermaid(&tree, &mut stdout)?,
+        Format::Tokens => unreachable!(),
+    }
+
+    Ok(())
+}
+
+fn find_card(predicate: impl Fn(&mtg_cardbase::Card) -> bool) -> Option<mtg_cardbase::Card> {
+    let cards = mtg_cardbase::AllCardsIter::new();
+    cards.iter().find(|card| predicate(card)).cloned()
+}
+
+#[cfg_attr(not(feature = "spanned_tree"), allow(unused_variables))]
+fn print_tokens(tokens: &[boseiju::lexer::tokens::Token], text: &str, spans: bool) {
+    for token in tokens.iter() {
+        #[cfg(feature = "spanned_tree")]
+        if spans {
+            let span = token.span();
+            println!(
+                "{:?} [{}..{}] \"{}\"",
+                token,
+                span.start,
+                span.end,
+                &text[span.start..span.end]
+            );
+            continue;
+        }
+        println!("{token:?}");
+    }
+}
+
+/// Print the tree with one node tag per line, along with the span of the node and the text it covers.
+#[cfg_attr(not(feature = "spanned_tree"), allow(unused_variables))]
+fn print_spanned_tree(node: &dyn AbilityTreeNode, text: &str, depth: usize) {
+    #[cfg(feature = "spanned_tree")]
+    {
+        let span = node.node_span();
+        let covered = text.get(span.start..span.end).unwrap_or_default();
+        println!(
+            "{:indent$}{} [{}..{}] {covered:?}",
+            "",
+            node.node_tag(),
+            span.start,
+            span.end,
+            indent = depth * 2
+        );
+        for child in node.children().into_iter() {
+            print_spanned_tree(child, text, depth + 1);
+        }
+    }
+}
@@ -3,16 +3,17 @@ use boseiju::*;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let oracle_text =
         "Trample\nWhenever a creature an opponent controls dies, Gimli deals 1 damage to that creature's controller.";
-    let card_name = "Gimli";
+    let card_name = lexer::CardName::new("Gimli, Counter of Kills");
 
-    let preprocessed = lexer::preprocess(card_name, oracle_text);
-    let tokens = lexer::lex(&preprocessed)?;
+    let preprocessed = lexer::preprocess_card(&card_name, oracle_text);
+    let tokens = lexer::lex(&preprocessed.text)?;
     let res = parser::parse(&tokens);
     let success = res.is_ok();
 
     println!("Parsing successefull: {success}");
     println!("");
     println!("oracle text: {oracle_text:?}");
+    println!("name substitutions: {:?}", preprocessed.name_substitutions);
     println!("tokens: {tokens:?}");
     println!("");
 
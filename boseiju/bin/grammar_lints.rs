@@ -0,0 +1,165 @@
+//! Analyse the grammar formed by the parser rules, and list all the lints grouped by kind.
+//!
+//! Run with `--help` for the list of options. By default, exits with an error code if any lint is found.
+//! Since the grammar already has lints, the number of lints of each kind can be recorded in a baseline file,
+//! so it only fails on new lints, and some kinds can be allowed entirely.
+//! The current numbers are recorded in `boseiju/grammar_lints.baseline`.
+
+use boseiju::*;
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
+const USAGE: &str = "\
+Usage: grammar_lints [OPTIONS]
+
+List the lints of the grammar formed by the parser rules, grouped by kind.
+Fails if a kind that is not allowed has more lints than in the baseline, or any lint without baseline.
+
+Options:
+      --allow <KIND>           Report the lints of this kind without failing on them, can be repeated
+      --baseline <FILE>        Number of lints of each kind to accept, one \"<KIND>: <COUNT>\" per line
+      --write-baseline <FILE>  Record the current number of lints of each kind in the baseline file
+  -h, --help                   Print this help
+
+Kinds: unproduced-node, unproducible-node, unreachable-rule, duplicate-rules, left-recursive-cycle";
+
+struct Options {
+    allowed: HashSet<String>,
+    baseline: Option<String>,
+    write_baseline: Option<String>,
+}
+
+fn main() -> std::process::ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return std::process::ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("Error: {e}\n\n{USAGE}");
+            return std::process::ExitCode::from(2);
+        }
+    };
+
+    match run(options) {
+        Ok(true) => std::process::ExitCode::SUCCESS,
+        Ok(false) => std::process::ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            std::process::ExitCode::from(2)
+        }
+    }
+}
+
+/// Name of a kind of lint on the command line and in baseline files.
+fn kind_arg(kind: &str) -> String {
+    kind.replace(' ', "-")
+}
+
+/// Parse the command line arguments, returns none if the help was requested.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut allowed = HashSet::new();
+    let mut baseline = None;
+    let mut write_baseline = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = |option: &str| args.next().ok_or(format!("Missing value for option {option}"));
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--allow" => {
+                let kind = value(&arg)?;
+                if !parser::GrammarLint::KINDS.iter().any(|known| kind_arg(known) == kind) {
+                    return Err(format!("Unknown lint kind \"{kind}\""));
+                }
+                allowed.insert(kind);
+            }
+            "--baseline" => baseline = Some(value(&arg)?),
+            "--write-baseline" => write_baseline = Some(value(&arg)?),
+            other => return Err(format!("Unknown option \"{other}\"")),
+        }
+    }
+
+    Ok(Some(Options {
+        allowed,
+        baseline,
+        write_baseline,
+    }))
+}
+
+/// Read the number of lints accepted for each kind from a baseline file.
+fn read_baseline(path: &str) -> Result<BTreeMap<String, usize>, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| format!("Failed to read the baseline \"{path}\": {e}"))?;
+    let mut baseline = BTreeMap::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = || format!("Invalid baseline line {}: \"{line}\"", index + 1);
+        let (kind, count) = line.split_once(':').ok_or_else(invalid)?;
+        let count = count.trim().parse().map_err(|_| invalid())?;
+        baseline.insert(kind.trim().to_string(), count);
+    }
+    Ok(baseline)
+}
+
+/// Print the lints, and check them against the options. Returns whether the check passed.
+fn run(options: Options) -> Result<bool, String> {
+    let lints = parser::grammar_lints();
+
+    let mut lints_by_kind: BTreeMap<&str, Vec<&parser::GrammarLint>> = BTreeMap::new();
+    for lint in lints.iter() {
+        lints_by_kind.entry(lint.kind()).or_default().push(lint);
+    }
+
+    for (kind, lints) in lints_by_kind.iter() {
+        println!("=== {kind} ({}) ===", lints.len());
+        for lint in lints.iter() {
+            println!("{lint}");
+        }
+        println!();
+    }
+
+    println!("Grammar lints summary:");
+    for (kind, lints) in lints_by_kind.iter() {
+        println!(" - {kind}: {}", lints.len());
+    }
+    if lints.is_empty() {
+        println!("No lints found.");
+    }
+
+    if let Some(path) = options.write_baseline.as_ref() {
+        let mut content = String::from("# Number of grammar lints of each kind, checked with `grammar_lints --baseline`\n");
+        for kind in parser::GrammarLint::KINDS.iter() {
+            let count = lints_by_kind.get(kind).map_or(0, Vec::len);
+            content.push_str(&format!("{}: {count}\n", kind_arg(kind)));
+        }
+        std::fs::write(path, content).map_err(|e| format!("Failed to write the baseline \"{path}\": {e}"))?;
+        println!("Baseline written to \"{path}\".");
+        return Ok(true);
+    }
+
+    let baseline = match options.baseline.as_ref() {
+        Some(path) => read_baseline(path)?,
+        None => BTreeMap::new(),
+    };
+
+    /* Kinds without baseline accept no lint */
+    let mut passed = true;
+    for kind in parser::GrammarLint::KINDS.iter() {
+        let kind_arg = kind_arg(kind);
+        if options.allowed.contains(&kind_arg) {
+            continue;
+        }
+        let count = lints_by_kind.get(kind).map_or(0, Vec::len);
+        let accepted = baseline.get(&kind_arg).cloned().unwrap_or(0);
+        if count > accepted {
+            eprintln!("Found {count} lints of kind \"{kind_arg}\", only {accepted} accepted");
+            passed = false;
+        } else if count < accepted {
+            println!("Found {count} lints of kind \"{kind_arg}\", the baseline of {accepted} can be lowered");
+        }
+    }
+    Ok(passed)
+}
@@ -1,5 +1,6 @@
 pub mod ability;
 pub mod action;
+pub mod anaphora;
 pub mod card_layout;
 pub mod colors;
 pub mod conditional;
@@ -13,6 +14,7 @@ pub mod player;
 pub mod replacement_effect;
 pub mod state;
 pub mod statement;
+pub mod targets;
 pub mod terminals;
 pub mod time;
 pub mod type_line;
@@ -20,6 +22,7 @@ pub mod zone;
 
 pub mod dummy_terminal;
 mod node_data;
+mod node_path;
 mod root;
 mod tree_node;
 
@@ -27,6 +30,7 @@ mod tree_node;
 pub mod span;
 
 pub use node_data::AbTreeNodeData;
+pub use node_path::NodePath;
 pub use root::AbilityTree;
 pub use tree_node::NodeKind;
 
@@ -79,7 +83,10 @@ pub const MAX_CHILDREN_PER_NODE: usize = 12;
 /// ```
 /// Here, this is a shortcut for having a `EmptyVariant(EmptyVariantData)` where the
 /// data would be an empty struct.
-pub trait AbilityTreeNode {
+///
+/// All nodes are also [`std::any::Any`], so an abstract node can be downcasted back
+/// to its concrete type when a tree walk needs the actual node data.
+pub trait AbilityTreeNode: std::any::Any {
     /// Get the node id.
     ///
     /// This identifier is unique to the kind of node it is, allowing to rebuild the node kind
@@ -91,6 +98,15 @@ pub trait AbilityTreeNode {
     /// This allows to recursively descend the ability tree over abstract nodes types.
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE>;
 
+    /// Whether any of the descendants of this node matches the given predicate.
+    ///
+    /// The node itself is not checked, only its children and their own descendants.
+    fn has_descendant(&self, predicate: &dyn Fn(&dyn AbilityTreeNode) -> bool) -> bool {
+        self.children()
+            .into_iter()
+            .any(|child| predicate(child) || child.has_descendant(predicate))
+    }
+
     /// Get the node data, if any.
     ///
     /// Some nodes may carry arbitrary data that are not children, like numbers or booleans.
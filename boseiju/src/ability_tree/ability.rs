@@ -2,6 +2,7 @@ pub mod ability_word;
 pub mod activated;
 pub mod common;
 pub mod keyword_ability;
+pub mod loyalty;
 pub mod spell;
 pub mod statik;
 pub mod triggered;
@@ -25,6 +26,13 @@ pub enum Ability {
     Written(WrittenAbility),
 }
 
+impl Ability {
+    /// All the targets that must be chosen for the ability, see [`crate::ability_tree::targets`].
+    pub fn targets(&self) -> Vec<crate::ability_tree::targets::TargetSlot> {
+        crate::ability_tree::targets::targets(self)
+    }
+}
+
 impl AbilityTreeNode for Ability {
     fn node_id(&self) -> usize {
         use idris::Idris;
@@ -89,6 +97,8 @@ pub enum WrittenAbility {
     Triggered(triggered::TriggeredAbility),
     /// A static abilty, [CR 113.3d]
     Static(statik::StaticAbility),
+    /// A loyalty ability, which is a special activated ability of planeswalkers, [CR 606]
+    Loyalty(loyalty::LoyaltyAbility),
 }
 
 impl AbilityTreeNode for WrittenAbility {
@@ -104,6 +114,7 @@ impl AbilityTreeNode for WrittenAbility {
             Self::Activated(ability) => abilities.push(ability as &dyn AbilityTreeNode),
             Self::Triggered(ability) => abilities.push(ability as &dyn AbilityTreeNode),
             Self::Static(ability) => abilities.push(ability as &dyn AbilityTreeNode),
+            Self::Loyalty(ability) => abilities.push(ability as &dyn AbilityTreeNode),
         };
         abilities
     }
@@ -114,6 +125,7 @@ impl AbilityTreeNode for WrittenAbility {
             WrittenAbility::Activated(activated) => activated.display(out)?,
             WrittenAbility::Triggered(triggered) => triggered.display(out)?,
             WrittenAbility::Static(statik) => statik.display(out)?,
+            WrittenAbility::Loyalty(loyalty) => loyalty.display(out)?,
         }
         Ok(())
     }
@@ -129,6 +141,7 @@ impl AbilityTreeNode for WrittenAbility {
             Self::Activated(child) => child.node_span(),
             Self::Triggered(child) => child.node_span(),
             Self::Static(child) => child.node_span(),
+            Self::Loyalty(child) => child.node_span(),
         }
     }
 }
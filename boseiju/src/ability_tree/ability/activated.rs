@@ -17,6 +17,18 @@ pub struct ActivatedAbility {
     pub span: crate::ability_tree::span::TreeSpan,
 }
 
+impl ActivatedAbility {
+    /// Whether this ability is a mana ability.
+    ///
+    /// From the comprehensive rules 605.1a:
+    /// An activated ability is a mana ability if it meets all of the following criteria:
+    /// it doesn't require a target, it could add mana to a player's mana pool when it resolves,
+    /// and it's not a loyalty ability.
+    pub fn is_mana_ability(&self) -> bool {
+        !self.effect.added_mana().is_empty() && !self.effect.requires_target()
+    }
+}
+
 impl AbilityTreeNode for ActivatedAbility {
     fn node_id(&self) -> usize {
         use idris::Idris;
@@ -46,6 +46,7 @@ pub fn treasure_token_ability() -> crate::AbilityTree {
                                             added_mana.push(mana);
                                             added_mana
                                         },
+                                        spend_restriction: None,
                                         #[cfg(feature = "spanned_tree")]
                                         span: Default::default(),
                                     }),
@@ -14,7 +14,10 @@ mod dash;
 mod disguise;
 mod echo;
 mod enchant;
+mod entwine;
 mod equip;
+mod escalate;
+mod evoke;
 mod fabricate;
 mod flashback;
 mod freerunning;
@@ -22,8 +25,10 @@ mod keyword_to_abilities;
 mod kicker;
 mod megamorph;
 mod morph;
+mod multikicker;
 mod ninjutsu;
 mod outlast;
+mod overload;
 mod prototype;
 mod rampage;
 mod reconfigure;
@@ -54,15 +59,20 @@ pub use dash::DashKeywordAbility;
 pub use disguise::DisguiseKeywordAbility;
 pub use echo::EchoKeywordAbility;
 pub use enchant::EnchantKeywordAbility;
+pub use entwine::EntwineKeywordAbility;
 pub use equip::EquipKeywordAbility;
+pub use escalate::EscalateKeywordAbility;
+pub use evoke::EvokeKeywordAbility;
 pub use fabricate::FabricateKeywordAbility;
 pub use flashback::FlashbackKeywordAbility;
 pub use freerunning::FreerunningKeywordAbility;
 pub use kicker::KickerKeywordAbility;
 pub use megamorph::MegamorphKeywordAbility;
 pub use morph::MorphKeywordAbility;
+pub use multikicker::MultikickerKeywordAbility;
 pub use ninjutsu::NinjutsuKeywordAbility;
 pub use outlast::OutlastKeywordAbility;
+pub use overload::OverloadKeywordAbility;
 pub use prototype::PrototypeKeywordAbility;
 pub use rampage::RampageKeywordAbility;
 pub use reconfigure::ReconfigureKeywordAbility;
@@ -102,15 +112,20 @@ pub enum ExpandedKeywordAbility {
     Disguise(DisguiseKeywordAbility),
     Echo(EchoKeywordAbility),
     Enchant(EnchantKeywordAbility),
+    Entwine(EntwineKeywordAbility),
     Equip(EquipKeywordAbility),
+    Escalate(EscalateKeywordAbility),
+    Evoke(EvokeKeywordAbility),
     Fabricate(FabricateKeywordAbility),
     Flashback(FlashbackKeywordAbility),
     Freerunning(FreerunningKeywordAbility),
     Kicker(KickerKeywordAbility),
     Megamorph(MegamorphKeywordAbility),
     Morph(MorphKeywordAbility),
+    Multikicker(MultikickerKeywordAbility),
     Ninjutsu(NinjutsuKeywordAbility),
     Outlast(OutlastKeywordAbility),
+    Overload(OverloadKeywordAbility),
     Prototype(PrototypeKeywordAbility),
     Rampage(RampageKeywordAbility),
     Reinforce(ReinforceKeywordAbility),
@@ -150,15 +165,20 @@ impl crate::ability_tree::AbilityTreeNode for ExpandedKeywordAbility {
             Self::Disguise(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Echo(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Enchant(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Entwine(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Equip(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Escalate(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Evoke(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Fabricate(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Flashback(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Freerunning(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Kicker(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Megamorph(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Morph(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Multikicker(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Ninjutsu(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Outlast(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Overload(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Prototype(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Rampage(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Reinforce(child) => children.push(child as &dyn AbilityTreeNode),
@@ -196,15 +216,20 @@ impl crate::ability_tree::AbilityTreeNode for ExpandedKeywordAbility {
             Self::Disguise(child) => child.display(out)?,
             Self::Echo(child) => child.display(out)?,
             Self::Enchant(child) => child.display(out)?,
+            Self::Entwine(child) => child.display(out)?,
             Self::Equip(child) => child.display(out)?,
+            Self::Escalate(child) => child.display(out)?,
+            Self::Evoke(child) => child.display(out)?,
             Self::Fabricate(child) => child.display(out)?,
             Self::Flashback(child) => child.display(out)?,
             Self::Freerunning(child) => child.display(out)?,
             Self::Kicker(child) => child.display(out)?,
             Self::Megamorph(child) => child.display(out)?,
             Self::Morph(child) => child.display(out)?,
+            Self::Multikicker(child) => child.display(out)?,
             Self::Ninjutsu(child) => child.display(out)?,
             Self::Outlast(child) => child.display(out)?,
+            Self::Overload(child) => child.display(out)?,
             Self::Prototype(child) => child.display(out)?,
             Self::Rampage(child) => child.display(out)?,
             Self::Reinforce(child) => child.display(out)?,
@@ -245,15 +270,20 @@ impl crate::ability_tree::AbilityTreeNode for ExpandedKeywordAbility {
             Self::Disguise(child) => child.node_span(),
             Self::Echo(child) => child.node_span(),
             Self::Enchant(child) => child.node_span(),
+            Self::Entwine(child) => child.node_span(),
             Self::Equip(child) => child.node_span(),
+            Self::Escalate(child) => child.node_span(),
+            Self::Evoke(child) => child.node_span(),
             Self::Fabricate(child) => child.node_span(),
             Self::Flashback(child) => child.node_span(),
             Self::Freerunning(child) => child.node_span(),
             Self::Kicker(child) => child.node_span(),
             Self::Megamorph(child) => child.node_span(),
             Self::Morph(child) => child.node_span(),
+            Self::Multikicker(child) => child.node_span(),
             Self::Ninjutsu(child) => child.node_span(),
             Self::Outlast(child) => child.node_span(),
+            Self::Overload(child) => child.node_span(),
             Self::Prototype(child) => child.node_span(),
             Self::Rampage(child) => child.node_span(),
             Self::Reinforce(child) => child.node_span(),
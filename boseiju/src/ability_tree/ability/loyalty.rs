@@ -0,0 +1,279 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// Loyalty abilities are the activated abilities of planeswalkers with loyalty symbols in their cost.
+///
+/// From the comprehensive rules:
+/// An activated ability with a loyalty symbol in its cost.
+/// See rule 606, “Loyalty Abilities.”
+///
+/// They are written as “\[Loyalty cost\]: \[Effect.\]”, and can only be activated once
+/// each turn, any time the player could cast a sorcery.
+///
+/// See also <https://mtg.fandom.com/wiki/Loyalty_ability>
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoyaltyAbility {
+    pub effect: crate::ability_tree::ability::spell::SpellAbility,
+    pub cost: LoyaltyCost,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for LoyaltyAbility {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::LoyaltyAbility.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.cost as &dyn AbilityTreeNode);
+        children.push(&self.effect as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "loyalty ability:")?;
+        out.push_inter_branch()?;
+        write!(out, "cost:")?;
+        out.push_final_branch()?;
+        self.cost.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "effects:")?;
+        out.push_final_branch()?;
+        self.effect.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "loyalty ability"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for LoyaltyAbility {
+    fn dummy_init() -> Self {
+        Self {
+            effect: crate::utils::dummy(),
+            cost: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
+
+/// The loyalty cost of a loyalty ability, [CR 606.4].
+///
+/// Loyalty costs are written with a loyalty symbol containing a signed amount,
+/// such as "+1", "−2", "0" or "−X". A positive cost means putting that many loyalty
+/// counters on the planeswalker, a negative cost means removing that many.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoyaltyCost {
+    Minus(LoyaltyCostMinus),
+    Plus(LoyaltyCostPlus),
+    Zero(LoyaltyCostZero),
+}
+
+impl AbilityTreeNode for LoyaltyCost {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::LoyaltyCost.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        match self {
+            Self::Minus(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Plus(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Zero(child) => children.push(child as &dyn AbilityTreeNode),
+        }
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "loyalty cost:")?;
+        out.push_final_branch()?;
+        match self {
+            Self::Minus(child) => child.display(out)?,
+            Self::Plus(child) => child.display(out)?,
+            Self::Zero(child) => child.display(out)?,
+        }
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "loyalty cost"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        match self {
+            Self::Minus(child) => child.node_span(),
+            Self::Plus(child) => child.node_span(),
+            Self::Zero(child) => child.node_span(),
+        }
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for LoyaltyCost {
+    fn dummy_init() -> Self {
+        Self::Zero(crate::utils::dummy())
+    }
+}
+
+/// A "+N" loyalty cost, putting N loyalty counters on the planeswalker.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoyaltyCostPlus {
+    pub amount: crate::ability_tree::number::Number,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for LoyaltyCostPlus {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::LoyaltyCostPlus.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.amount as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "+")?;
+        self.amount.display(out)?;
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "+ loyalty cost"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for LoyaltyCostPlus {
+    fn dummy_init() -> Self {
+        Self {
+            amount: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
+
+/// A "−N" loyalty cost, removing N loyalty counters from the planeswalker.
+///
+/// The amount can be X, in which case the player chooses the value of X when activating the ability.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoyaltyCostMinus {
+    pub amount: crate::ability_tree::number::Number,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for LoyaltyCostMinus {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::LoyaltyCostMinus.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.amount as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "-")?;
+        self.amount.display(out)?;
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "- loyalty cost"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for LoyaltyCostMinus {
+    fn dummy_init() -> Self {
+        Self {
+            amount: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
+
+/// A "0" loyalty cost, which doesn't change the loyalty of the planeswalker.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoyaltyCostZero {
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for LoyaltyCostZero {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::LoyaltyCostZero.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        arrayvec::ArrayVec::new_const()
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "0")
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "0 loyalty cost"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for LoyaltyCostZero {
+    fn dummy_init() -> Self {
+        Self {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -18,6 +18,30 @@ pub struct SpellAbility {
     pub span: crate::ability_tree::span::TreeSpan,
 }
 
+impl SpellAbility {
+    /// All the add mana imperatives the ability could execute as it resolves.
+    pub fn added_mana(&self) -> Vec<&crate::ability_tree::imperative::AddManaImperative> {
+        self.effects
+            .iter()
+            .flat_map(|statement| statement.imperatives())
+            .filter_map(|imperative| match &imperative.kind {
+                crate::ability_tree::imperative::ImperativeKind::AddMana(add_mana) => Some(add_mana),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// All the targets that must be chosen for the ability, see [`crate::ability_tree::targets`].
+    pub fn targets(&self) -> Vec<crate::ability_tree::targets::TargetSlot> {
+        crate::ability_tree::targets::targets(self)
+    }
+
+    /// Whether the ability requires choosing at least one target.
+    pub fn requires_target(&self) -> bool {
+        !self.targets().is_empty()
+    }
+}
+
 impl crate::ability_tree::AbilityTreeNode for SpellAbility {
     fn node_id(&self) -> usize {
         use idris::Idris;
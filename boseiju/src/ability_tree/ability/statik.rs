@@ -1,4 +1,7 @@
+pub mod additional_casting_cost;
 pub mod alterative_casting_permissions;
+pub mod alternative_casting_cost;
+pub mod characteristic_defining_ability;
 pub mod continuous_effect;
 pub mod cost_modification_effect;
 
@@ -84,6 +87,9 @@ pub enum StaticAbilityKind {
     ContinuousEffect(continuous_effect::ContinuousEffect),
     CostModificationEffect(cost_modification_effect::CostModificationEffect), /* Fixme: that's a continuous effect */
     AlternativeCastingPermissions(alterative_casting_permissions::AlternativeCastingPermissions), /* Fixme: that's a continuous effect */
+    AdditionalCastingCost(additional_casting_cost::AdditionalCastingCost),
+    AlternativeCastingCost(alternative_casting_cost::AlternativeCastingCost),
+    CharacteristicDefining(characteristic_defining_ability::CharacteristicDefiningAbility),
 }
 
 impl AbilityTreeNode for StaticAbilityKind {
@@ -98,6 +104,9 @@ impl AbilityTreeNode for StaticAbilityKind {
             Self::ContinuousEffect(child) => children.push(child as &dyn AbilityTreeNode),
             Self::CostModificationEffect(child) => children.push(child as &dyn AbilityTreeNode),
             Self::AlternativeCastingPermissions(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::AdditionalCastingCost(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::AlternativeCastingCost(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::CharacteristicDefining(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
     }
@@ -110,6 +119,9 @@ impl AbilityTreeNode for StaticAbilityKind {
             Self::ContinuousEffect(child) => child.display(out)?,
             Self::CostModificationEffect(child) => child.display(out)?,
             Self::AlternativeCastingPermissions(child) => child.display(out)?,
+            Self::AdditionalCastingCost(child) => child.display(out)?,
+            Self::AlternativeCastingCost(child) => child.display(out)?,
+            Self::CharacteristicDefining(child) => child.display(out)?,
         }
         out.pop_branch();
         Ok(())
@@ -125,6 +137,9 @@ impl AbilityTreeNode for StaticAbilityKind {
             Self::ContinuousEffect(child) => child.node_span(),
             Self::CostModificationEffect(child) => child.node_span(),
             Self::AlternativeCastingPermissions(child) => child.node_span(),
+            Self::AdditionalCastingCost(child) => child.node_span(),
+            Self::AlternativeCastingCost(child) => child.node_span(),
+            Self::CharacteristicDefining(child) => child.node_span(),
         }
     }
 }
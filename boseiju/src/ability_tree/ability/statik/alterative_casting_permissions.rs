@@ -2,13 +2,15 @@ use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 
 /// An alternative casting permissions grants the right to cast
-/// a card from another zone than your hand.
+/// a card from another zone than your hand, or to play it for lands.
 ///
-/// For example, gravecrawler says: "You may cast this card from your graveyard".
+/// For example, gravecrawler says: "You may cast this card from your graveyard",
+/// or "During each of your turns, you may play a land from your graveyard".
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AlternativeCastingPermissions {
     pub player: crate::ability_tree::player::PlayerSpecifier,
+    pub action: mtg_data::KeywordAction,
     pub object: crate::ability_tree::object::Card,
     pub from_zone: crate::ability_tree::zone::ZoneReference,
     pub additional_cost: Option<crate::ability_tree::cost::Cost>,
@@ -45,6 +47,8 @@ impl crate::ability_tree::AbilityTreeNode for AlternativeCastingPermissions {
         self.player.display(out)?;
         out.pop_branch();
         out.next_inter_branch()?;
+        write!(out, "action: {}", self.action.as_str())?;
+        out.next_inter_branch()?;
         write!(out, "objects:")?;
         out.push_final_branch()?;
         self.object.display(out)?;
@@ -81,6 +85,7 @@ impl crate::utils::DummyInit for AlternativeCastingPermissions {
     fn dummy_init() -> Self {
         Self {
             player: crate::utils::dummy(),
+            action: mtg_data::KeywordAction::Cast,
             object: crate::utils::dummy(),
             from_zone: crate::utils::dummy(),
             additional_cost: None,
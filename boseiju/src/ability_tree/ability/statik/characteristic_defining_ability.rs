@@ -0,0 +1,163 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A characteristic-defining ability.
+///
+/// From the comprehensive rules 604.3:
+/// Some static abilities are characteristic-defining abilities. A characteristic-defining ability
+/// conveys information about an object's characteristics that would normally be found elsewhere
+/// on that object (such as in its mana cost, type line, or power/toughness box).
+///
+/// These are the abilities that give a value to the "*" printed power and toughness,
+/// such as "~'s power and toughness are each equal to the number of creatures you control".
+///
+/// See also <https://mtg.fandom.com/wiki/Characteristic-defining_ability>
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharacteristicDefiningAbility {
+    pub characteristic: DefinedCharacteristic,
+    pub value: crate::ability_tree::number::GameStateNumber,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl CharacteristicDefiningAbility {
+    /// Characteristic-defining abilities for power and toughness are applied in layer 7a, [CR 613.4a].
+    pub fn layer(&self) -> crate::ability_tree::ability::statik::continuous_effect::Layer {
+        use crate::ability_tree::ability::statik::continuous_effect::Layer;
+        use crate::ability_tree::ability::statik::continuous_effect::PowerToughnessSublayer;
+        Layer::PowerToughness(PowerToughnessSublayer::CharacteristicDefining)
+    }
+}
+
+impl AbilityTreeNode for CharacteristicDefiningAbility {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::CharacteristicDefiningAbility.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.characteristic as &dyn AbilityTreeNode);
+        children.push(&self.value as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "characteristic-defining ability:")?;
+        out.push_inter_branch()?;
+        self.characteristic.display(out)?;
+        out.next_final_branch()?;
+        write!(out, "value:")?;
+        out.push_final_branch()?;
+        self.value.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "characteristic-defining ability"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for CharacteristicDefiningAbility {
+    fn dummy_init() -> Self {
+        Self {
+            characteristic: crate::utils::dummy(),
+            value: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
+
+/// The characteristics that a characteristic-defining ability can define.
+#[derive(idris_derive::Idris)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefinedCharacteristic {
+    Power {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+    PowerAndToughness {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+    Toughness {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+}
+
+impl DefinedCharacteristic {
+    pub fn defines_power(&self) -> bool {
+        matches!(self, Self::Power { .. } | Self::PowerAndToughness { .. })
+    }
+
+    pub fn defines_toughness(&self) -> bool {
+        matches!(self, Self::Toughness { .. } | Self::PowerAndToughness { .. })
+    }
+}
+
+impl AbilityTreeNode for DefinedCharacteristic {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::DefinedCharacteristicIdMarker.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        use idris::Idris;
+
+        let child_id = crate::ability_tree::NodeKind::DefinedCharacteristic(self.clone()).id();
+        let child = crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::new(child_id);
+
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(child as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "defined characteristic:")?;
+        out.push_final_branch()?;
+        match self {
+            Self::Power { .. } => write!(out, "power")?,
+            Self::PowerAndToughness { .. } => write!(out, "power and toughness")?,
+            Self::Toughness { .. } => write!(out, "toughness")?,
+        }
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "defined characteristic"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        match self {
+            Self::Power { span } => *span,
+            Self::PowerAndToughness { span } => *span,
+            Self::Toughness { span } => *span,
+        }
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for DefinedCharacteristic {
+    fn dummy_init() -> Self {
+        Self::PowerAndToughness {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
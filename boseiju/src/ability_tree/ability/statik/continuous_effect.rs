@@ -1,6 +1,8 @@
 mod continuous_effect_kind;
+mod layer;
 
 pub use continuous_effect_kind::*;
+pub use layer::*;
 
 use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
@@ -20,6 +22,13 @@ pub struct ContinuousEffect {
     pub span: crate::ability_tree::span::TreeSpan,
 }
 
+impl ContinuousEffect {
+    /// The layers in which this effect is applied, see [`ContinuousEffectKind::layers`].
+    pub fn layers(&self) -> Vec<Layer> {
+        self.effect.layers()
+    }
+}
+
 impl crate::ability_tree::AbilityTreeNode for ContinuousEffect {
     fn node_id(&self) -> usize {
         use idris::Idris;
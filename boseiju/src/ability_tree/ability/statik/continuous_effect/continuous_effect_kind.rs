@@ -1,6 +1,8 @@
+mod change_control;
 mod modify_object;
 mod modify_rules;
 
+pub use change_control::*;
 pub use modify_object::*;
 pub use modify_rules::*;
 
@@ -14,11 +16,32 @@ use crate::ability_tree::replacement_effect::ReplacementEffect;
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ContinuousEffectKind {
+    ChangeControl(ChangeControlEffect),
     ModifyRule(ModifyRuleEffect),
     ModifyObjectAbilities(ModifyObjectEffect),
     ReplacementEffect(ReplacementEffect),
 }
 
+impl ContinuousEffectKind {
+    /// The layers in which this effect is applied, in application order.
+    ///
+    /// Effects that modify the rules of the game and replacement effects are not applied
+    /// in layers, and have no layers.
+    pub fn layers(&self) -> Vec<super::Layer> {
+        match self {
+            Self::ChangeControl(_) => vec![super::Layer::Control],
+            Self::ModifyRule(_) => Vec::new(),
+            Self::ModifyObjectAbilities(effect) => {
+                let mut layers: Vec<_> = effect.modifications.iter().map(|modification| modification.layer()).collect();
+                layers.sort();
+                layers.dedup();
+                layers
+            }
+            Self::ReplacementEffect(_) => Vec::new(),
+        }
+    }
+}
+
 impl AbilityTreeNode for ContinuousEffectKind {
     fn node_id(&self) -> usize {
         use idris::Idris;
@@ -28,6 +51,7 @@ impl AbilityTreeNode for ContinuousEffectKind {
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
         let mut children = arrayvec::ArrayVec::new_const();
         match self {
+            Self::ChangeControl(child) => children.push(child as &dyn AbilityTreeNode),
             Self::ModifyRule(child) => children.push(child as &dyn AbilityTreeNode),
             Self::ModifyObjectAbilities(child) => children.push(child as &dyn AbilityTreeNode),
             Self::ReplacementEffect(child) => children.push(child as &dyn AbilityTreeNode),
@@ -40,6 +64,7 @@ impl AbilityTreeNode for ContinuousEffectKind {
         write!(out, "continuous effect kind")?;
         out.push_final_branch()?;
         match self {
+            Self::ChangeControl(child) => child.display(out)?,
             Self::ModifyRule(child) => child.display(out)?,
             Self::ModifyObjectAbilities(child) => child.display(out)?,
             Self::ReplacementEffect(child) => child.display(out)?,
@@ -55,6 +80,7 @@ impl AbilityTreeNode for ContinuousEffectKind {
     #[cfg(feature = "spanned_tree")]
     fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
         match self {
+            Self::ChangeControl(child) => child.node_span(),
             Self::ModifyRule(child) => child.node_span(),
             Self::ModifyObjectAbilities(child) => child.node_span(),
             Self::ReplacementEffect(child) => child.node_span(),
@@ -0,0 +1,69 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A control-changing effect, that gives the control of a permanent to a player.
+///
+/// For instance, "gain control of target creature" gives the control of the
+/// creature to the player executing the imperative.
+///
+/// See also <https://mtg.fandom.com/wiki/Control>
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeControlEffect {
+    pub object: crate::ability_tree::object::Permanent,
+    pub new_controller: crate::ability_tree::player::PlayerSpecifier,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for ChangeControlEffect {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::ChangeControlEffect.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.object as &dyn AbilityTreeNode);
+        children.push(&self.new_controller as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "change control effect:")?;
+        out.push_inter_branch()?;
+        write!(out, "object:")?;
+        out.push_final_branch()?;
+        self.object.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "new controller:")?;
+        out.push_final_branch()?;
+        self.new_controller.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "change control effect"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for ChangeControlEffect {
+    fn dummy_init() -> Self {
+        Self {
+            object: crate::utils::dummy(),
+            new_controller: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
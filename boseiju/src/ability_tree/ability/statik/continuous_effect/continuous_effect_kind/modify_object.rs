@@ -85,6 +85,19 @@ impl crate::utils::DummyInit for ModifyObjectEffect {
 pub enum ObjectAbilitiesModification {
     CharacteristicModification(ObjectCharacteristicModification), /* Fixme: some of these are only for creatures */
     GainAbility(ObjectGainAbility),
+    LoseAllAbilities(ObjectLoseAllAbilities),
+}
+
+impl ObjectAbilitiesModification {
+    /// The layer in which this modification is applied.
+    pub fn layer(&self) -> crate::ability_tree::ability::statik::continuous_effect::Layer {
+        use crate::ability_tree::ability::statik::continuous_effect::Layer;
+        match self {
+            Self::CharacteristicModification(modification) => modification.layer(),
+            Self::GainAbility(_) => Layer::Ability,
+            Self::LoseAllAbilities(_) => Layer::Ability,
+        }
+    }
 }
 
 impl AbilityTreeNode for ObjectAbilitiesModification {
@@ -98,6 +111,7 @@ impl AbilityTreeNode for ObjectAbilitiesModification {
         match self {
             Self::CharacteristicModification(child) => children.push(child as &dyn AbilityTreeNode),
             Self::GainAbility(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::LoseAllAbilities(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
     }
@@ -109,6 +123,7 @@ impl AbilityTreeNode for ObjectAbilitiesModification {
         match self {
             Self::CharacteristicModification(child) => child.display(out)?,
             Self::GainAbility(child) => child.display(out)?,
+            Self::LoseAllAbilities(child) => child.display(out)?,
         }
         out.pop_branch();
         Ok(())
@@ -123,6 +138,7 @@ impl AbilityTreeNode for ObjectAbilitiesModification {
         match self {
             Self::CharacteristicModification(child) => child.node_span(),
             Self::GainAbility(child) => child.node_span(),
+            Self::LoseAllAbilities(child) => child.node_span(),
         }
     }
 }
@@ -184,3 +200,46 @@ impl crate::utils::DummyInit for ObjectGainAbility {
         }
     }
 }
+
+/// An object modification that removes all abilities from that object, "loses all abilities".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectLoseAllAbilities {
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for ObjectLoseAllAbilities {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::ObjectLoseAllAbilities.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        arrayvec::ArrayVec::new_const()
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "object loses all abilities")
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "object lose all abilities modification"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for ObjectLoseAllAbilities {
+    fn dummy_init() -> Self {
+        Self {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -1,4 +1,8 @@
+mod color_change;
 mod power_toughness_modifiers;
+mod type_change;
+
+pub use color_change::ObjectColorChange;
 
 pub use power_toughness_modifiers::PowerToughnessModifiers;
 pub use power_toughness_modifiers::PowerToughnessModifiersMinusMinus;
@@ -6,6 +10,8 @@ pub use power_toughness_modifiers::PowerToughnessModifiersMinusPlus;
 pub use power_toughness_modifiers::PowerToughnessModifiersPlusMinus;
 pub use power_toughness_modifiers::PowerToughnessModifiersPlusPlus;
 pub use power_toughness_modifiers::PowerToughnessModifiersSet;
+pub use type_change::ObjectIsEveryCreatureType;
+pub use type_change::ObjectTypeChange;
 
 use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
@@ -20,7 +26,27 @@ use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ObjectCharacteristicModification {
+    ColorChange(ObjectColorChange),
+    IsEveryCreatureType(ObjectIsEveryCreatureType),
     PowerToughnessModifiers(PowerToughnessModifiers),
+    TypeChange(ObjectTypeChange),
+}
+
+impl ObjectCharacteristicModification {
+    /// The layer in which this modification is applied.
+    pub fn layer(&self) -> crate::ability_tree::ability::statik::continuous_effect::Layer {
+        use crate::ability_tree::ability::statik::continuous_effect::Layer;
+        use crate::ability_tree::ability::statik::continuous_effect::PowerToughnessSublayer;
+        match self {
+            Self::ColorChange(_) => Layer::Color,
+            Self::IsEveryCreatureType(_) => Layer::Type,
+            Self::PowerToughnessModifiers(PowerToughnessModifiers::Set(_)) => {
+                Layer::PowerToughness(PowerToughnessSublayer::Setting)
+            }
+            Self::PowerToughnessModifiers(_) => Layer::PowerToughness(PowerToughnessSublayer::Modifying),
+            Self::TypeChange(_) => Layer::Type,
+        }
+    }
 }
 
 impl AbilityTreeNode for ObjectCharacteristicModification {
@@ -32,7 +58,10 @@ impl AbilityTreeNode for ObjectCharacteristicModification {
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
         let mut children = arrayvec::ArrayVec::new_const();
         match self {
+            Self::ColorChange(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::IsEveryCreatureType(child) => children.push(child as &dyn AbilityTreeNode),
             Self::PowerToughnessModifiers(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::TypeChange(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
     }
@@ -42,7 +71,10 @@ impl AbilityTreeNode for ObjectCharacteristicModification {
         write!(out, "object characteristic modification:")?;
         out.push_final_branch()?;
         match self {
+            Self::ColorChange(child) => child.display(out)?,
+            Self::IsEveryCreatureType(child) => child.display(out)?,
             Self::PowerToughnessModifiers(child) => child.display(out)?,
+            Self::TypeChange(child) => child.display(out)?,
         }
         out.pop_branch();
         Ok(())
@@ -55,7 +87,10 @@ impl AbilityTreeNode for ObjectCharacteristicModification {
     #[cfg(feature = "spanned_tree")]
     fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
         match self {
+            Self::ColorChange(child) => child.node_span(),
+            Self::IsEveryCreatureType(child) => child.node_span(),
             Self::PowerToughnessModifiers(child) => child.node_span(),
+            Self::TypeChange(child) => child.node_span(),
         }
     }
 }
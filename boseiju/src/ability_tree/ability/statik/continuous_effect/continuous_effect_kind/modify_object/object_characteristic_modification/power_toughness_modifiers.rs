@@ -365,7 +365,7 @@ impl idris::Idris for PowerToughnessModifiersMinusPlus {
     }
 }
 
-/// A -X/-X power and toughness modifier.
+/// Sets the base power and toughness to X/Y, as in "has base power and toughness 1/1".
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PowerToughnessModifiersSet {
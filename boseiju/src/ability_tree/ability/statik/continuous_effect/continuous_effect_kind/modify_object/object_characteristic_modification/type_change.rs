@@ -0,0 +1,110 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A type-changing effect, that changes the card types and subtypes of an object.
+///
+/// Type changing effects either replace all the types of the object, as in
+/// "~ becomes an artifact creature", or add new types to the object, as in
+/// "~ becomes a 3/3 Elemental creature in addition to its other types".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectTypeChange {
+    pub new_types: Box<crate::ability_tree::type_line::TypeLine>,
+    /// Whether the object keeps its previous types, "in addition to its other types".
+    pub in_addition_to_other_types: bool,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for ObjectTypeChange {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::ObjectTypeChange.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(self.new_types.as_ref() as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "object type change:")?;
+        out.push_inter_branch()?;
+        write!(out, "new types:")?;
+        out.push_final_branch()?;
+        self.new_types.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "in addition to other types: {}", self.in_addition_to_other_types)?;
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "object type change"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for ObjectTypeChange {
+    fn dummy_init() -> Self {
+        Self {
+            new_types: Box::new(crate::utils::dummy()),
+            in_addition_to_other_types: false,
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
+
+/// A type-changing effect that gives an object all creature types, "~ is every creature type".
+///
+/// This is the same effect as the changeling keyword ability, see rule 702.73.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectIsEveryCreatureType {
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for ObjectIsEveryCreatureType {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::ObjectIsEveryCreatureType.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        arrayvec::ArrayVec::new_const()
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "object is every creature type")
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "object is every creature type"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for ObjectIsEveryCreatureType {
+    fn dummy_init() -> Self {
+        Self {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -1,5 +1,7 @@
+mod creature_cant_be_blocked_except_by;
 mod creature_cant_do_action;
 
+pub use creature_cant_be_blocked_except_by::CreatureCantBeBlockedExceptBy;
 pub use creature_cant_do_action::CreatureCantDoAction;
 
 use crate::ability_tree::AbilityTreeNode;
@@ -9,6 +11,7 @@ use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ModifyRuleEffect {
+    CreatureCantBeBlockedExceptBy(CreatureCantBeBlockedExceptBy),
     CreatureCantDoAction(CreatureCantDoAction),
 }
 
@@ -21,6 +24,7 @@ impl AbilityTreeNode for ModifyRuleEffect {
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
         let mut children = arrayvec::ArrayVec::new_const();
         match self {
+            Self::CreatureCantBeBlockedExceptBy(child) => children.push(child as &dyn AbilityTreeNode),
             Self::CreatureCantDoAction(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
@@ -31,6 +35,7 @@ impl AbilityTreeNode for ModifyRuleEffect {
         write!(out, "modify rule effect")?;
         out.push_final_branch()?;
         match self {
+            Self::CreatureCantBeBlockedExceptBy(child) => child.display(out)?,
             Self::CreatureCantDoAction(child) => child.display(out)?,
         }
         out.pop_branch();
@@ -44,6 +49,7 @@ impl AbilityTreeNode for ModifyRuleEffect {
     #[cfg(feature = "spanned_tree")]
     fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
         match self {
+            Self::CreatureCantBeBlockedExceptBy(child) => child.node_span(),
             Self::CreatureCantDoAction(child) => child.node_span(),
         }
     }
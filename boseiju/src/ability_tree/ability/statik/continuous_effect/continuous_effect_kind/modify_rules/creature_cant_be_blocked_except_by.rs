@@ -0,0 +1,66 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A blocking restriction, where a creature can only be blocked by some creatures.
+///
+/// For instance, "~ can't be blocked except by creatures with flying".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatureCantBeBlockedExceptBy {
+    pub creature: crate::ability_tree::object::Creature,
+    pub allowed_blockers: crate::ability_tree::object::Creature,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for CreatureCantBeBlockedExceptBy {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::CreatureCantBeBlockedExceptBy.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.creature as &dyn AbilityTreeNode);
+        children.push(&self.allowed_blockers as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "creature can't be blocked except by:")?;
+        out.push_inter_branch()?;
+        write!(out, "creature:")?;
+        out.push_final_branch()?;
+        self.creature.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "allowed blockers:")?;
+        out.push_final_branch()?;
+        self.allowed_blockers.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "creature can't be blocked except by"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for CreatureCantBeBlockedExceptBy {
+    fn dummy_init() -> Self {
+        Self {
+            creature: crate::utils::dummy(),
+            allowed_blockers: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
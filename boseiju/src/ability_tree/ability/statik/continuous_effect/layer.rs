@@ -0,0 +1,78 @@
+/// The layer in which a continuous effect is applied.
+///
+/// From the comprehensive rules:
+/// \[613.1\] The values of an object's characteristics are determined by starting with the actual
+/// object. \[...\] Then all applicable continuous effects are applied in a series of layers in the
+/// following order:
+///
+/// Layers are declared in application order, so sorting effects by their layer gives the order
+/// in which a game engine shall apply them.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    /// \[613.1a\] Layer 1: Rules and effects that modify copiable values are applied.
+    Copy,
+    /// \[613.1b\] Layer 2: Control-changing effects are applied.
+    Control,
+    /// \[613.1c\] Layer 3: Text-changing effects are applied.
+    Text,
+    /// \[613.1d\] Layer 4: Type-changing effects are applied.
+    Type,
+    /// \[613.1e\] Layer 5: Color-changing effects are applied.
+    Color,
+    /// \[613.1f\] Layer 6: Ability-adding effects, ability-removing effects, and effects that say
+    /// an object can't have an ability are applied.
+    Ability,
+    /// \[613.1g\] Layer 7: Power- and/or toughness-changing effects are applied.
+    PowerToughness(PowerToughnessSublayer),
+}
+
+/// The sublayers of the power and toughness layer, [CR 613.4].
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PowerToughnessSublayer {
+    /// \[613.4a\] Layer 7a: Effects from characteristic-defining abilities that define power and/or toughness are applied.
+    CharacteristicDefining,
+    /// \[613.4b\] Layer 7b: Effects that set power and/or toughness to a specific number or value are applied.
+    Setting,
+    /// \[613.4c\] Layer 7c: Effects and counters that modify power and/or toughness (but don't set
+    /// power and/or toughness to a specific number or value) are applied.
+    Modifying,
+    /// \[613.4d\] Layer 7d: Effects that switch a creature's power and toughness are applied.
+    Switching,
+}
+
+impl Layer {
+    /// The number of the layer, from 1 to 7.
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::Copy => 1,
+            Self::Control => 2,
+            Self::Text => 3,
+            Self::Type => 4,
+            Self::Color => 5,
+            Self::Ability => 6,
+            Self::PowerToughness(_) => 7,
+        }
+    }
+
+    /// The letter of the sublayer, for the power and toughness layer.
+    pub fn sublayer(&self) -> Option<char> {
+        match self {
+            Self::PowerToughness(PowerToughnessSublayer::CharacteristicDefining) => Some('a'),
+            Self::PowerToughness(PowerToughnessSublayer::Setting) => Some('b'),
+            Self::PowerToughness(PowerToughnessSublayer::Modifying) => Some('c'),
+            Self::PowerToughness(PowerToughnessSublayer::Switching) => Some('d'),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Layer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.sublayer() {
+            Some(sublayer) => write!(f, "layer {}{sublayer}", self.number()),
+            None => write!(f, "layer {}", self.number()),
+        }
+    }
+}
@@ -25,6 +25,27 @@ pub struct TriggeredAbility {
     pub span: crate::ability_tree::span::TreeSpan,
 }
 
+impl TriggeredAbility {
+    /// Whether this ability is a mana ability.
+    ///
+    /// From the comprehensive rules 605.1b:
+    /// A triggered ability is a mana ability if it meets all of the following criteria:
+    /// it doesn't require a target, it triggers from activating or resolving an activated mana ability
+    /// or from mana being added to a player's mana pool, and it could add mana to a player's mana pool when it resolves.
+    pub fn is_mana_ability(&self) -> bool {
+        use crate::ability_tree::action::PermanentAction;
+        use crate::ability_tree::event::Event;
+
+        let triggers_from_mana_ability = match &self.trigger_condition.kind {
+            TriggerConditionKind::Event(Event::PermanentPerformsAction(event)) => {
+                matches!(event.action, PermanentAction::TappedForMana(_))
+            }
+            _ => false,
+        };
+        triggers_from_mana_ability && !self.effect.added_mana().is_empty() && !self.effect.requires_target()
+    }
+}
+
 impl AbilityTreeNode for TriggeredAbility {
     fn node_id(&self) -> usize {
         use idris::Idris;
@@ -2,11 +2,13 @@ mod creature_attacks_action;
 mod creature_blocks_action;
 mod creature_deals_damage_action;
 mod creature_dies_action;
+mod creature_is_dealt_damage_action;
 
 pub use creature_attacks_action::CreatureAttacksAction;
 pub use creature_blocks_action::CreatureBlocksAction;
 pub use creature_deals_damage_action::CreatureDealsDamageAction;
 pub use creature_dies_action::CreatureDiesAction;
+pub use creature_is_dealt_damage_action::CreatureIsDealtDamageAction;
 
 use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
@@ -19,6 +21,7 @@ pub enum CreatureAction {
     Blocks(CreatureBlocksAction),
     DealsDamage(CreatureDealsDamageAction),
     Dies(CreatureDiesAction),
+    IsDealtDamage(CreatureIsDealtDamageAction),
 }
 
 impl crate::ability_tree::AbilityTreeNode for CreatureAction {
@@ -34,6 +37,7 @@ impl crate::ability_tree::AbilityTreeNode for CreatureAction {
             Self::Blocks(child) => children.push(child as &dyn AbilityTreeNode),
             Self::DealsDamage(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Dies(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::IsDealtDamage(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
     }
@@ -47,6 +51,7 @@ impl crate::ability_tree::AbilityTreeNode for CreatureAction {
             Self::Blocks(event) => event.display(out)?,
             Self::DealsDamage(event) => event.display(out)?,
             Self::Dies(event) => event.display(out)?,
+            Self::IsDealtDamage(event) => event.display(out)?,
         }
         out.pop_branch();
         Ok(())
@@ -63,6 +68,7 @@ impl crate::ability_tree::AbilityTreeNode for CreatureAction {
             Self::Blocks(child) => child.node_span(),
             Self::DealsDamage(child) => child.node_span(),
             Self::Dies(child) => child.node_span(),
+            Self::IsDealtDamage(child) => child.node_span(),
         }
     }
 }
@@ -0,0 +1,61 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// An action for when a creature is dealt damage, as in "whenever ~ is dealt damage".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatureIsDealtDamageAction {
+    pub creature: crate::ability_tree::object::Creature,
+    pub damage_kind: crate::ability_tree::terminals::DamageKind,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl crate::ability_tree::AbilityTreeNode for CreatureIsDealtDamageAction {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::CreatureIsDealtDamageAction.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.creature as &dyn AbilityTreeNode);
+        children.push(&self.damage_kind as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "creature is dealt damage action:")?;
+        out.push_inter_branch()?;
+        write!(out, "creature:")?;
+        out.push_final_branch()?;
+        self.creature.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        self.damage_kind.display(out)?;
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "creature is dealt damage action"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for CreatureIsDealtDamageAction {
+    fn dummy_init() -> Self {
+        Self {
+            creature: crate::utils::dummy(),
+            damage_kind: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
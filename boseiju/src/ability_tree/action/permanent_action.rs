@@ -1,6 +1,12 @@
+mod counters_put_on_permanent_action;
+mod permanent_becomes_target_action;
 mod permanent_etb_action;
+mod permanent_tapped_for_mana_action;
 
+pub use counters_put_on_permanent_action::CountersPutOnPermanentAction;
+pub use permanent_becomes_target_action::PermanentBecomesTargetAction;
 pub use permanent_etb_action::PermanentEtbAction;
+pub use permanent_tapped_for_mana_action::PermanentTappedForManaAction;
 
 use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
@@ -9,7 +15,10 @@ use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PermanentAction {
+    BecomesTarget(PermanentBecomesTargetAction),
+    CountersPutOn(CountersPutOnPermanentAction),
     EntersTheBattlefield(PermanentEtbAction),
+    TappedForMana(PermanentTappedForManaAction),
 }
 
 impl crate::ability_tree::AbilityTreeNode for PermanentAction {
@@ -21,30 +30,39 @@ impl crate::ability_tree::AbilityTreeNode for PermanentAction {
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
         let mut children = arrayvec::ArrayVec::new_const();
         match self {
+            Self::BecomesTarget(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::CountersPutOn(child) => children.push(child as &dyn AbilityTreeNode),
             Self::EntersTheBattlefield(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::TappedForMana(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
     }
 
     fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
         use std::io::Write;
-        write!(out, "creature action:")?;
+        write!(out, "permanent action:")?;
         out.push_final_branch()?;
         match self {
+            Self::BecomesTarget(event) => event.display(out)?,
+            Self::CountersPutOn(event) => event.display(out)?,
             Self::EntersTheBattlefield(event) => event.display(out)?,
+            Self::TappedForMana(event) => event.display(out)?,
         }
         out.pop_branch();
         Ok(())
     }
 
     fn node_tag(&self) -> &'static str {
-        "creature action"
+        "permanent action"
     }
 
     #[cfg(feature = "spanned_tree")]
     fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
         match self {
+            Self::BecomesTarget(child) => child.node_span(),
+            Self::CountersPutOn(child) => child.node_span(),
             Self::EntersTheBattlefield(child) => child.node_span(),
+            Self::TappedForMana(child) => child.node_span(),
         }
     }
 }
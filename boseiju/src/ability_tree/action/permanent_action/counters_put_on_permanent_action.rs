@@ -0,0 +1,88 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// An action for when counters are put on a permanent.
+///
+/// For instance, "whenever one or more +1/+1 counters are put on ~".
+/// When the kind of counter is not given, any counter triggers the event, as in
+/// "whenever a counter is put on a creature you control".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountersPutOnPermanentAction {
+    pub permanent: crate::ability_tree::object::Permanent,
+    pub amount: crate::ability_tree::number::Number,
+    /// The kind of counter, none for any kind of counter.
+    pub counter: Option<crate::ability_tree::terminals::Counter>,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl crate::ability_tree::AbilityTreeNode for CountersPutOnPermanentAction {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::CountersPutOnPermanentAction.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.permanent as &dyn AbilityTreeNode);
+        children.push(&self.amount as &dyn AbilityTreeNode);
+        match self.counter.as_ref() {
+            Some(counter) => children.push(counter as &dyn AbilityTreeNode),
+            None => {
+                let none_node = crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::none_node();
+                children.push(none_node as &dyn AbilityTreeNode);
+            }
+        }
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "counters put on permanent action:")?;
+        out.push_inter_branch()?;
+        write!(out, "permanent:")?;
+        out.push_final_branch()?;
+        self.permanent.display(out)?;
+        out.pop_branch();
+        out.next_inter_branch()?;
+        write!(out, "amount:")?;
+        out.push_final_branch()?;
+        self.amount.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        match self.counter.as_ref() {
+            Some(counter) => {
+                write!(out, "counter:")?;
+                out.push_final_branch()?;
+                counter.display(out)?;
+                out.pop_branch();
+            }
+            None => write!(out, "any kind of counter")?,
+        }
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "counters put on permanent action"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for CountersPutOnPermanentAction {
+    fn dummy_init() -> Self {
+        Self {
+            permanent: crate::utils::dummy(),
+            amount: crate::utils::dummy(),
+            counter: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// An action for when a permanent becomes the target of a spell or ability.
+///
+/// The source is the spell targeting the permanent, as in "becomes the target of a spell".
+/// When there is no source, any spell or ability targeting the permanent triggers the event,
+/// as in "becomes the target of a spell or ability".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermanentBecomesTargetAction {
+    pub permanent: crate::ability_tree::object::Permanent,
+    pub source: Option<Box<crate::ability_tree::object::Spell>>,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl crate::ability_tree::AbilityTreeNode for PermanentBecomesTargetAction {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::PermanentBecomesTargetAction.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.permanent as &dyn AbilityTreeNode);
+        match self.source.as_ref() {
+            Some(source) => children.push(source.as_ref() as &dyn AbilityTreeNode),
+            None => {
+                let none_node = crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::none_node();
+                children.push(none_node as &dyn AbilityTreeNode);
+            }
+        }
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "permanent becomes target action:")?;
+        out.push_inter_branch()?;
+        write!(out, "permanent:")?;
+        out.push_final_branch()?;
+        self.permanent.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        match self.source.as_ref() {
+            Some(source) => {
+                write!(out, "targeted by:")?;
+                out.push_final_branch()?;
+                source.display(out)?;
+                out.pop_branch();
+            }
+            None => write!(out, "targeted by any spell or ability")?,
+        }
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "permanent becomes target action"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for PermanentBecomesTargetAction {
+    fn dummy_init() -> Self {
+        Self {
+            permanent: crate::utils::dummy(),
+            source: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -1,8 +1,18 @@
 mod player_attacks_action;
 mod player_casts_spell_action;
+mod player_creates_tokens_action;
+mod player_draws_card_action;
+mod player_gains_life_action;
+mod player_loses_life_action;
+mod player_sacrifices_action;
 
 pub use player_attacks_action::PlayerAttacksAction;
 pub use player_casts_spell_action::PlayerCastsSpellAction;
+pub use player_creates_tokens_action::PlayerCreatesTokensAction;
+pub use player_draws_card_action::PlayerDrawsCardAction;
+pub use player_gains_life_action::PlayerGainsLifeAction;
+pub use player_loses_life_action::PlayerLosesLifeAction;
+pub use player_sacrifices_action::PlayerSacrificesAction;
 
 use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
@@ -13,6 +23,11 @@ use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 pub enum PlayerAction {
     Attacks(PlayerAttacksAction),
     CastsSpell(PlayerCastsSpellAction),
+    CreatesTokens(PlayerCreatesTokensAction),
+    DrawsCard(PlayerDrawsCardAction),
+    GainsLife(PlayerGainsLifeAction),
+    LosesLife(PlayerLosesLifeAction),
+    Sacrifices(PlayerSacrificesAction),
 }
 
 impl crate::ability_tree::AbilityTreeNode for PlayerAction {
@@ -26,6 +41,11 @@ impl crate::ability_tree::AbilityTreeNode for PlayerAction {
         match self {
             Self::Attacks(child) => children.push(child as &dyn AbilityTreeNode),
             Self::CastsSpell(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::CreatesTokens(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::DrawsCard(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::GainsLife(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::LosesLife(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Sacrifices(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
     }
@@ -37,6 +57,11 @@ impl crate::ability_tree::AbilityTreeNode for PlayerAction {
         match self {
             Self::Attacks(event) => event.display(out)?,
             Self::CastsSpell(event) => event.display(out)?,
+            Self::CreatesTokens(event) => event.display(out)?,
+            Self::DrawsCard(event) => event.display(out)?,
+            Self::GainsLife(event) => event.display(out)?,
+            Self::LosesLife(event) => event.display(out)?,
+            Self::Sacrifices(event) => event.display(out)?,
         }
         out.pop_branch();
         Ok(())
@@ -51,6 +76,11 @@ impl crate::ability_tree::AbilityTreeNode for PlayerAction {
         match self {
             Self::Attacks(child) => child.node_span(),
             Self::CastsSpell(child) => child.node_span(),
+            Self::CreatesTokens(child) => child.node_span(),
+            Self::DrawsCard(child) => child.node_span(),
+            Self::GainsLife(child) => child.node_span(),
+            Self::LosesLife(child) => child.node_span(),
+            Self::Sacrifices(child) => child.node_span(),
         }
     }
 }
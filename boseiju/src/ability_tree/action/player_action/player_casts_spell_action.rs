@@ -1,12 +1,15 @@
 use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 
-/// An action for when a creature attacks.
+/// An action for when a player casts a spell.
+///
+/// The zone the spell is cast from can be specified, as in "whenever you cast a spell from exile".
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PlayerCastsSpellAction {
     pub player: crate::ability_tree::player::PlayerSpecifier,
     pub spell: crate::ability_tree::object::Spell,
+    pub from_zone: Option<crate::ability_tree::zone::ZoneReference>,
     #[cfg(feature = "spanned_tree")]
     pub span: crate::ability_tree::span::TreeSpan,
 }
@@ -21,6 +24,13 @@ impl crate::ability_tree::AbilityTreeNode for PlayerCastsSpellAction {
         let mut children = arrayvec::ArrayVec::new_const();
         children.push(&self.player as &dyn AbilityTreeNode);
         children.push(&self.spell as &dyn AbilityTreeNode);
+        match self.from_zone.as_ref() {
+            Some(zone) => children.push(zone as &dyn AbilityTreeNode),
+            None => {
+                let none_node = crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::none_node();
+                children.push(none_node as &dyn AbilityTreeNode);
+            }
+        }
         children
     }
 
@@ -32,11 +42,21 @@ impl crate::ability_tree::AbilityTreeNode for PlayerCastsSpellAction {
         out.push_final_branch()?;
         self.player.display(out)?;
         out.pop_branch();
-        out.next_final_branch()?;
+        out.next_inter_branch()?;
         write!(out, "spell:")?;
         out.push_final_branch()?;
         self.spell.display(out)?;
         out.pop_branch();
+        out.next_final_branch()?;
+        match self.from_zone.as_ref() {
+            Some(zone) => {
+                write!(out, "cast from:")?;
+                out.push_final_branch()?;
+                zone.display(out)?;
+                out.pop_branch();
+            }
+            None => write!(out, "cast from anywhere")?,
+        }
         out.pop_branch();
         Ok(())
     }
@@ -57,6 +77,7 @@ impl crate::utils::DummyInit for PlayerCastsSpellAction {
         Self {
             player: crate::utils::dummy(),
             spell: crate::utils::dummy(),
+            from_zone: crate::utils::dummy(),
             #[cfg(feature = "spanned_tree")]
             span: Default::default(),
         }
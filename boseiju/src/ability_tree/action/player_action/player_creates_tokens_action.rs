@@ -0,0 +1,66 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// An action for when tokens are created under the control of a player.
+///
+/// For instance, "if an effect would create one or more tokens under your control".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerCreatesTokensAction {
+    pub player: crate::ability_tree::player::PlayerSpecifier,
+    pub amount: crate::ability_tree::number::Number,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl crate::ability_tree::AbilityTreeNode for PlayerCreatesTokensAction {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::PlayerCreatesTokensAction.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.player as &dyn AbilityTreeNode);
+        children.push(&self.amount as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "player creates tokens action:")?;
+        out.push_inter_branch()?;
+        write!(out, "player:")?;
+        out.push_final_branch()?;
+        self.player.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "amount:")?;
+        out.push_final_branch()?;
+        self.amount.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "player creates tokens action"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for PlayerCreatesTokensAction {
+    fn dummy_init() -> Self {
+        Self {
+            player: crate::utils::dummy(),
+            amount: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
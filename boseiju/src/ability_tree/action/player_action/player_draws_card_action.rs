@@ -0,0 +1,67 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// An action for when a player draws a card.
+///
+/// Some triggers only look at a specific card drawn during the turn,
+/// as in "whenever you draw your second card each turn".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerDrawsCardAction {
+    pub player: crate::ability_tree::player::PlayerSpecifier,
+    /// The rank of the drawn card among the cards drawn this turn, if specified.
+    pub card_rank_in_turn: Option<u32>,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl crate::ability_tree::AbilityTreeNode for PlayerDrawsCardAction {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::PlayerDrawsCardAction.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.player as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "player draws card action:")?;
+        out.push_inter_branch()?;
+        write!(out, "player:")?;
+        out.push_final_branch()?;
+        self.player.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        match self.card_rank_in_turn {
+            Some(rank) => write!(out, "card drawn this turn: {rank}")?,
+            None => write!(out, "any card drawn")?,
+        }
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "player draws card action"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for PlayerDrawsCardAction {
+    fn dummy_init() -> Self {
+        Self {
+            player: crate::utils::dummy(),
+            card_rank_in_turn: None,
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Anaphora resolution for ability trees.
+//!
+//! Oracle texts often refer back to objects or players mentionned earlier in the ability,
+//! with words like "it", "that creature" or "that player". In the tree, these are parsed as
+//! [`PreviouslyMentionned`](crate::ability_tree::object::PreviouslyMentionned) objects and
+//! [`PerviouslyMentionnedPlayer`](crate::ability_tree::player::PlayerSpecifier::PerviouslyMentionnedPlayer)
+//! players, which carry no information on what they refer to.
+//!
+//! The resolution pass walks a parsed tree and links each of these referential nodes to its antecedent,
+//! using [`NodePath`]s as stable node identifiers.
+//!
+//! The resolution works per ability: an antecedent can only be found in the same ability as the reference.
+//! For each reference, the most recently mentionned compatible node is picked, with the following precedence:
+//! - object references ("target creature", "a creature an opponent controls", "enchanted creature"),
+//!   either from the event of the trigger condition or from the effect;
+//! - self references ("~", "this creature"), which are only used when nothing else was mentionned.
+//!
+//! References that can't be linked to anything are reported as warnings.
+
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::NodeKind;
+use crate::ability_tree::NodePath;
+
+/// What kind of node an anaphora was resolved to.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AntecedentKind {
+    /// An object that was referenced with a specified object, e.g. "target creature you control".
+    SpecifiedObject,
+    /// An object or player that is the subject of the trigger condition event,
+    /// e.g. "a creature" in "whenever a creature dies".
+    EventSubject,
+    /// The object carrying the ability, e.g. "~" or "this creature".
+    SelfReferencing,
+    /// An object that is attached to the object carrying the ability, e.g. "enchanted creature".
+    AttachedObject,
+    /// A player, e.g. "target opponent" or "that creature's controller".
+    Player,
+}
+
+/// A resolved anaphora: a link from a referential node to the node it refers to.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnaphoraLink {
+    /// Path to the referential node ("it", "that creature", "that player").
+    pub reference: NodePath,
+    /// Path to the node that the reference points to.
+    pub antecedent: NodePath,
+    /// The kind of antecedent.
+    pub kind: AntecedentKind,
+}
+
+/// A warning emitted by the anaphora resolution pass.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnaphoraWarning {
+    /// No antecedent was found for a previously mentionned object.
+    UnresolvedObject {
+        reference: NodePath,
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+    /// No antecedent was found for a previously mentionned player.
+    UnresolvedPlayer {
+        reference: NodePath,
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+}
+
+impl std::fmt::Display for AnaphoraWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnresolvedObject { reference, .. } => {
+                write!(f, "No antecedent found for the previously mentionned object at {reference}")
+            }
+            Self::UnresolvedPlayer { reference, .. } => {
+                write!(f, "No antecedent found for the previously mentionned player at {reference}")
+            }
+        }
+    }
+}
+
+/// Result of the anaphora resolution pass over a tree.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnaphoraResolution {
+    pub links: Vec<AnaphoraLink>,
+    pub warnings: Vec<AnaphoraWarning>,
+}
+
+impl AnaphoraResolution {
+    /// Run the anaphora resolution on the tree starting at the given root.
+    ///
+    /// Each child of the root is considered a separate ability, and references
+    /// are only resolved within the ability they are in.
+    pub fn resolve(root: &dyn AbilityTreeNode) -> Self {
+        let mut resolution = Self::default();
+        let root_path = NodePath::root();
+        for (i, ability) in root.children().into_iter().enumerate() {
+            let mut scope = AnaphoraScope::default();
+            scope.visit(ability, root_path.child(i), false, &mut resolution);
+        }
+        resolution
+    }
+
+    /// Get the link for the referential node at the given path, if it was resolved.
+    pub fn antecedent_of(&self, reference: &NodePath) -> Option<&AnaphoraLink> {
+        self.links.iter().find(|link| &link.reference == reference)
+    }
+
+    /// Whether all the references of the tree were resolved.
+    pub fn is_complete(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+/// A node that can be pointed to by an anaphora, as encountered during the tree walk.
+struct Antecedent {
+    path: NodePath,
+    kind: AntecedentKind,
+}
+
+/// The antecedents that have been encountered so far in a single ability.
+#[derive(Default)]
+struct AnaphoraScope {
+    objects: Vec<Antecedent>,
+    players: Vec<Antecedent>,
+}
+
+impl AnaphoraScope {
+    fn visit(&mut self, node: &dyn AbilityTreeNode, path: NodePath, in_event: bool, resolution: &mut AnaphoraResolution) {
+        let node_id = node.node_id();
+        let in_event = in_event || node_id == node_ids::trigger_condition();
+
+        if node_id == node_ids::previously_mentionned() {
+            match self.find_object_antecedent(&path) {
+                Some(antecedent) => resolution.links.push(AnaphoraLink {
+                    reference: path.clone(),
+                    antecedent: antecedent.path.clone(),
+                    kind: antecedent.kind,
+                }),
+                None => resolution.warnings.push(AnaphoraWarning::UnresolvedObject {
+                    reference: path.clone(),
+                    #[cfg(feature = "spanned_tree")]
+                    span: node.node_span(),
+                }),
+            }
+        } else if node_id == node_ids::player_specifier() && Self::is_previously_mentionned_player(node) {
+            match self
+                .players
+                .iter()
+                .rev()
+                .find(|antecedent| !antecedent.path.is_ancestor_of(&path))
+            {
+                Some(antecedent) => resolution.links.push(AnaphoraLink {
+                    reference: path.clone(),
+                    antecedent: antecedent.path.clone(),
+                    kind: antecedent.kind,
+                }),
+                None => resolution.warnings.push(AnaphoraWarning::UnresolvedPlayer {
+                    reference: path.clone(),
+                    #[cfg(feature = "spanned_tree")]
+                    span: node.node_span(),
+                }),
+            }
+        } else if node_id == node_ids::player_specifier() {
+            let kind = if in_event {
+                AntecedentKind::EventSubject
+            } else {
+                AntecedentKind::Player
+            };
+            self.players.push(Antecedent {
+                path: path.clone(),
+                kind,
+            });
+        } else if node_ids::object_references().contains(&node_id) {
+            let kind = if in_event {
+                AntecedentKind::EventSubject
+            } else {
+                AntecedentKind::SpecifiedObject
+            };
+            self.objects.push(Antecedent {
+                path: path.clone(),
+                kind,
+            });
+        } else if node_id == node_ids::attached_object() {
+            self.objects.push(Antecedent {
+                path: path.clone(),
+                kind: AntecedentKind::AttachedObject,
+            });
+        } else if node_id == node_ids::self_referencing() {
+            self.objects.push(Antecedent {
+                path: path.clone(),
+                kind: AntecedentKind::SelfReferencing,
+            });
+        }
+
+        for (i, child) in node.children().into_iter().enumerate() {
+            self.visit(child, path.child(i), in_event, resolution);
+        }
+    }
+
+    /// Find the antecedent of a previously mentionned object.
+    ///
+    /// Self references are only used if no other antecedent is available,
+    /// since oracle texts name the card ("~" or "this creature") rather than using "it" when
+    /// referring to the object carrying the ability, unless it was the subject of the trigger.
+    fn find_object_antecedent(&self, reference: &NodePath) -> Option<&Antecedent> {
+        let candidates = || {
+            self.objects
+                .iter()
+                .rev()
+                .filter(|antecedent| !antecedent.path.is_ancestor_of(reference))
+        };
+        candidates()
+            .find(|antecedent| antecedent.kind != AntecedentKind::SelfReferencing)
+            .or_else(|| candidates().next())
+    }
+
+    /// Player specifiers are enum nodes, that carry their variant as a single dummy terminal child.
+    fn is_previously_mentionned_player(node: &dyn AbilityTreeNode) -> bool {
+        node.children()
+            .first()
+            .is_some_and(|child| child.node_id() == node_ids::previously_mentionned_player())
+    }
+}
+
+/// Node ids of the nodes of interest for the anaphora resolution.
+mod node_ids {
+    use super::NodeKind;
+    use crate::ability_tree::tree_node::TerminalNodeKind;
+    use idris::Idris;
+
+    pub fn previously_mentionned() -> usize {
+        NodeKind::PreviouslyMentionned.id()
+    }
+
+    pub fn previously_mentionned_player() -> usize {
+        NodeKind::PlayerSpecifier(crate::ability_tree::player::PlayerSpecifier::PerviouslyMentionnedPlayer {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        })
+        .id()
+    }
+
+    pub fn player_specifier() -> usize {
+        NodeKind::PlayerSpecifierIdMarker.id()
+    }
+
+    pub fn trigger_condition() -> usize {
+        NodeKind::TriggerCondition.id()
+    }
+
+    pub fn attached_object() -> usize {
+        NodeKind::AttachedObject.id()
+    }
+
+    pub fn self_referencing() -> usize {
+        NodeKind::Terminal(TerminalNodeKind::SelfReferencing).id()
+    }
+
+    /// The "<kind> reference" nodes, found from the names of the node kinds so new kinds of objects are included.
+    pub fn object_references() -> &'static [usize] {
+        lazy_static::lazy_static!(
+            static ref object_references: Vec<usize> = (0..NodeKind::COUNT)
+                .filter(|id| NodeKind::name_from_id(*id).ends_with("Reference"))
+                .chain(std::iter::once(NodeKind::TopCardsOfLibrary.id()))
+                .collect();
+        );
+        &object_references
+    }
+}
@@ -1,7 +1,11 @@
+mod battle_layout;
+mod characteristics;
 mod normal_layout;
 mod saga_layout;
 mod token_layout;
 
+pub use battle_layout::BattleLayout;
+pub use characteristics::*;
 pub use normal_layout::NormalLayout;
 pub use saga_layout::SagaLayout;
 pub use token_layout::TokenLayout;
@@ -9,6 +13,9 @@ pub use token_layout::TokenLayout;
 pub trait LayoutImpl: Sized {
     fn card_types(&self) -> crate::ability_tree::type_line::SimplifiedCardTypes;
     fn mana_value(&self) -> usize;
+    fn characteristics(&self) -> Option<&PrintedCharacteristics>;
+    fn mana_cost(&self) -> Option<&crate::ability_tree::terminals::ManaCost>;
+    fn abilities(&self) -> Option<&crate::AbilityTree>;
     #[cfg(feature = "parser")]
     fn from_raw_card(raw_card: &mtg_cardbase::Card) -> Result<Self, String>;
     fn layout_debug_display<W: std::io::Write>(&self, output: &mut W) -> std::io::Result<()>;
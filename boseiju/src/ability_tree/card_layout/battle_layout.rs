@@ -0,0 +1,116 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// Layout of a battle: the front face is a battle with a printed defense.
+#[derive(Debug, Clone)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct BattleLayout {
+    pub mana_cost: Option<crate::ability_tree::terminals::ManaCost>,
+    pub card_type: crate::ability_tree::type_line::TypeLine,
+    pub abilities: crate::AbilityTree,
+    pub characteristics: super::PrintedCharacteristics,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl super::LayoutImpl for BattleLayout {
+    fn card_types(&self) -> crate::ability_tree::type_line::SimplifiedCardTypes {
+        (&self.card_type).into()
+    }
+
+    fn mana_value(&self) -> usize {
+        self.mana_cost.as_ref().map(|cost| cost.mana_value()).unwrap_or(0)
+    }
+
+    fn characteristics(&self) -> Option<&super::PrintedCharacteristics> {
+        Some(&self.characteristics)
+    }
+
+    fn mana_cost(&self) -> Option<&crate::ability_tree::terminals::ManaCost> {
+        self.mana_cost.as_ref()
+    }
+
+    fn abilities(&self) -> Option<&crate::AbilityTree> {
+        Some(&self.abilities)
+    }
+
+    #[cfg(feature = "parser")]
+    fn from_raw_card(raw_card: &mtg_cardbase::Card) -> Result<Self, String> {
+        /* The front face of a battle reads like a normal card, with a defense instead of a power and toughness */
+        let super::NormalLayout {
+            mana_cost,
+            card_type,
+            abilities,
+            characteristics,
+            ..
+        } = super::NormalLayout::from_raw_card(raw_card)?;
+
+        Ok(BattleLayout {
+            mana_cost,
+            card_type,
+            abilities,
+            characteristics,
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        })
+    }
+
+    fn layout_debug_display<W: std::io::Write>(&self, _output: &mut W) -> std::io::Result<()> {
+        unimplemented!()
+    }
+}
+
+impl AbilityTreeNode for BattleLayout {
+    fn node_id(&self) -> usize {
+        use crate::ability_tree::tree_node::LayoutNodeKind;
+        use idris::Idris;
+
+        crate::ability_tree::NodeKind::Layout(LayoutNodeKind::Battle).id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        use crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal;
+
+        let mut children = arrayvec::ArrayVec::<&dyn AbilityTreeNode, _>::new();
+
+        /* Same children as the normal layout, for the layouts to be aligned */
+        match self.mana_cost.as_ref() {
+            Some(child) => children.push(child),
+            None => children.push(TreeNodeDummyTerminal::none_node()),
+        }
+        children.push(TreeNodeDummyTerminal::empty_node());
+        children.push(&self.card_type);
+        children.push(TreeNodeDummyTerminal::empty_node());
+        children.push(&self.abilities);
+        children.push(TreeNodeDummyTerminal::empty_node());
+
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "battle layout")?;
+        out.push_inter_branch()?;
+        match self.mana_cost.as_ref() {
+            Some(mana_cost) => mana_cost.display(out)?,
+            None => write!(out, "no mana cost")?,
+        }
+        out.next_inter_branch()?;
+        self.card_type.display(out)?;
+        out.next_inter_branch()?;
+        self.abilities.display(out)?;
+        out.next_final_branch()?;
+        self.characteristics.display(out)?;
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "battle layout"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
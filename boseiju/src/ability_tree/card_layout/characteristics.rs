@@ -0,0 +1,185 @@
+/// The numerical characteristics printed on a card face.
+///
+/// Creatures have a power and toughness, planeswalkers a loyalty and battles a defense.
+/// These are the printed values: what the object actually has during the game can be
+/// changed by effects, or defined by a characteristic-defining ability.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrintedCharacteristics {
+    pub power: Option<PrintedCharacteristic>,
+    pub toughness: Option<PrintedCharacteristic>,
+    pub loyalty: Option<PrintedCharacteristic>,
+    pub defense: Option<PrintedCharacteristic>,
+}
+
+impl PrintedCharacteristics {
+    #[cfg(feature = "parser")]
+    pub fn from_raw_card(raw_card: &mtg_cardbase::Card, abilities: &crate::AbilityTree) -> Self {
+        /* Values we can't interpret are kept as unknown, they shall not prevent the card from being parsed */
+        let parse = |raw: Option<&String>| raw.map(|raw| PrintedCharacteristic::from_raw(raw));
+
+        let mut characteristics = PrintedCharacteristics {
+            power: parse(raw_card.power.as_ref()),
+            toughness: parse(raw_card.toughness.as_ref()),
+            loyalty: parse(raw_card.loyalty.as_ref()),
+            defense: parse(raw_card.defense.as_ref()),
+        };
+        characteristics.link_defining_abilities(abilities);
+
+        characteristics
+    }
+
+    /// Whether no characteristic is printed on the card face, such as on instants and sorceries.
+    pub fn is_empty(&self) -> bool {
+        self.power.is_none() && self.toughness.is_none() && self.loyalty.is_none() && self.defense.is_none()
+    }
+
+    pub fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        let characteristics = [
+            ("power", self.power.as_ref()),
+            ("toughness", self.toughness.as_ref()),
+            ("loyalty", self.loyalty.as_ref()),
+            ("defense", self.defense.as_ref()),
+        ];
+        let characteristics: Vec<_> = characteristics
+            .into_iter()
+            .filter_map(|(name, characteristic)| Some((name, characteristic?)))
+            .collect();
+        if characteristics.is_empty() {
+            return write!(out, "characteristics: none");
+        }
+        write!(out, "characteristics:")?;
+        for (i, (name, characteristic)) in characteristics.iter().enumerate() {
+            if i + 1 == characteristics.len() {
+                out.push_final_branch()?;
+            } else {
+                out.push_inter_branch()?;
+            }
+            match characteristic.defined_by.as_ref() {
+                Some(path) => write!(out, "{name}: {characteristic} (defined by ability at {path})")?,
+                None => write!(out, "{name}: {characteristic}")?,
+            }
+            out.pop_branch();
+        }
+        Ok(())
+    }
+
+    /// Link the "*" power and toughness to the characteristic-defining abilities that give them a value.
+    pub fn link_defining_abilities(&mut self, abilities: &crate::AbilityTree) {
+        use crate::ability_tree::ability::statik::StaticAbilityKind;
+        use crate::ability_tree::ability::{Ability, WrittenAbility};
+
+        for (index, ability) in abilities.abilities.iter().enumerate() {
+            let defining_ability = match ability {
+                Ability::Written(WrittenAbility::Static(statik)) => match &statik.kind {
+                    StaticAbilityKind::CharacteristicDefining(defining_ability) => defining_ability,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            let path = crate::ability_tree::NodePath::root().child(index);
+            if defining_ability.characteristic.defines_power()
+                && let Some(power) = self.power.as_mut().filter(|power| power.is_star())
+            {
+                power.defined_by = Some(path.clone());
+            }
+            if defining_ability.characteristic.defines_toughness()
+                && let Some(toughness) = self.toughness.as_mut().filter(|toughness| toughness.is_star())
+            {
+                toughness.defined_by = Some(path);
+            }
+        }
+    }
+}
+
+/// A single numerical characteristic printed on a card, such as "3", "*" or "1+*".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintedCharacteristic {
+    pub value: PrintedCharacteristicValue,
+    /// For "*" values, path to the characteristic-defining ability that defines the value,
+    /// from the root of the card's ability tree.
+    pub defined_by: Option<crate::ability_tree::NodePath>,
+}
+
+impl PrintedCharacteristic {
+    /// Parse a printed characteristic, as found in the card database.
+    pub fn from_raw(raw: &str) -> Self {
+        Self {
+            value: PrintedCharacteristicValue::from_raw(raw),
+            defined_by: None,
+        }
+    }
+
+    /// Whether this characteristic is defined by a characteristic-defining ability.
+    pub fn is_star(&self) -> bool {
+        matches!(self.value, PrintedCharacteristicValue::Star { .. })
+    }
+
+    /// The printed value, if it is known without looking at the game state.
+    pub fn fixed_value(&self) -> Option<i32> {
+        match self.value {
+            PrintedCharacteristicValue::Fixed { value } => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PrintedCharacteristic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// The value of a printed characteristic.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrintedCharacteristicValue {
+    /// A fixed value, such as "3". Some cards have negative values, such as "-1".
+    Fixed { value: i32 },
+    /// A value defined by a characteristic-defining ability, "*".
+    /// Some cards add a fixed amount to it, such as "1+*": the offset is then 1.
+    Star { offset: i32 },
+    /// A value of X, where X is defined by the card (usually chosen when it is cast).
+    X,
+    /// An unknown value, "?". This only appears on some unusual cards.
+    ///
+    /// Printed values we can't interpret, such as "*²", "7-*", "∞" or "1.5", are also unknown.
+    Unknown,
+}
+
+impl PrintedCharacteristicValue {
+    /// Parse a printed characteristic value, as found in the card database.
+    pub fn from_raw(raw: &str) -> Self {
+        match raw.trim() {
+            "*" => Self::Star { offset: 0 },
+            "X" | "x" => Self::X,
+            other => {
+                let star_offset = other.strip_suffix("+*").or_else(|| other.strip_prefix("*+"));
+                match star_offset {
+                    Some(offset) => match offset.parse() {
+                        Ok(offset) => Self::Star { offset },
+                        Err(_) => Self::Unknown,
+                    },
+                    None => match other.parse() {
+                        Ok(value) => Self::Fixed { value },
+                        Err(_) => Self::Unknown,
+                    },
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for PrintedCharacteristicValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fixed { value } => write!(f, "{value}"),
+            Self::Star { offset: 0 } => write!(f, "*"),
+            Self::Star { offset } => write!(f, "{offset}+*"),
+            Self::X => write!(f, "X"),
+            Self::Unknown => write!(f, "?"),
+        }
+    }
+}
@@ -7,6 +7,7 @@ pub struct NormalLayout {
     pub mana_cost: Option<crate::ability_tree::terminals::ManaCost>,
     pub card_type: crate::ability_tree::type_line::TypeLine,
     pub abilities: crate::AbilityTree,
+    pub characteristics: super::PrintedCharacteristics,
     #[cfg(feature = "spanned_tree")]
     pub span: crate::ability_tree::span::TreeSpan,
 }
@@ -20,6 +21,18 @@ impl super::LayoutImpl for NormalLayout {
         self.mana_cost.as_ref().map(|cost| cost.mana_value()).unwrap_or(0)
     }
 
+    fn characteristics(&self) -> Option<&super::PrintedCharacteristics> {
+        Some(&self.characteristics)
+    }
+
+    fn mana_cost(&self) -> Option<&crate::ability_tree::terminals::ManaCost> {
+        self.mana_cost.as_ref()
+    }
+
+    fn abilities(&self) -> Option<&crate::AbilityTree> {
+        Some(&self.abilities)
+    }
+
     #[cfg(feature = "parser")]
     fn from_raw_card(raw_card: &mtg_cardbase::Card) -> Result<Self, String> {
         use crate::lexer::IntoToken;
@@ -31,6 +44,14 @@ impl super::LayoutImpl for NormalLayout {
             text: type_line_text.as_str(),
         };
 
+        let abilities = match raw_card.oracle_text.as_ref() {
+            Some(oracle_text) => {
+                crate::AbilityTree::from_card_oracle_text(oracle_text, &crate::lexer::CardName::from_raw_card(raw_card))
+                    .map_err(|e| format!("Failed to parse oracle text to ability tree: {e}"))?
+            }
+            None => crate::AbilityTree::empty(),
+        };
+
         Ok(NormalLayout {
             mana_cost: match raw_card.mana_cost.as_ref() {
                 Some(mana_cost) => {
@@ -48,11 +69,8 @@ impl super::LayoutImpl for NormalLayout {
             },
             card_type: crate::ability_tree::type_line::TypeLine::try_from_span(&type_line_span)
                 .ok_or_else(|| format!("Failed to parse card type: {}", raw_card.type_line))?,
-            abilities: match raw_card.oracle_text.as_ref() {
-                Some(oracle_text) => crate::AbilityTree::from_oracle_text(oracle_text, &raw_card.name)
-                    .map_err(|e| format!("Failed to parse oracle text to ability tree: {e}"))?,
-                None => crate::AbilityTree::empty(),
-            },
+            characteristics: super::PrintedCharacteristics::from_raw_card(raw_card, &abilities),
+            abilities,
             #[cfg(feature = "spanned_tree")]
             span: Default::default(),
         })
@@ -107,8 +125,16 @@ impl AbilityTreeNode for NormalLayout {
         }
         out.next_inter_branch()?;
         self.card_type.display(out)?;
-        out.next_final_branch()?;
-        self.abilities.display(out)?;
+        /* Only cards with a power, toughness or loyalty show their printed characteristics */
+        if self.characteristics.is_empty() {
+            out.next_final_branch()?;
+            self.abilities.display(out)?;
+        } else {
+            out.next_inter_branch()?;
+            self.abilities.display(out)?;
+            out.next_final_branch()?;
+            self.characteristics.display(out)?;
+        }
         out.pop_branch();
         Ok(())
     }
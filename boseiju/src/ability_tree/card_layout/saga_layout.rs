@@ -24,6 +24,18 @@ impl super::LayoutImpl for SagaLayout {
         self.mana_cost.as_ref().map(|cost| cost.mana_value()).unwrap_or(0)
     }
 
+    fn characteristics(&self) -> Option<&super::PrintedCharacteristics> {
+        None
+    }
+
+    fn mana_cost(&self) -> Option<&crate::ability_tree::terminals::ManaCost> {
+        self.mana_cost.as_ref()
+    }
+
+    fn abilities(&self) -> Option<&crate::AbilityTree> {
+        None
+    }
+
     #[cfg(feature = "parser")]
     fn from_raw_card(raw_card: &mtg_cardbase::Card) -> Result<Self, String> {
         // let ability_tree = match raw_card.oracle_text.as_ref() {
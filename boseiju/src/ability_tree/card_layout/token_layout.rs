@@ -68,6 +68,18 @@ impl super::LayoutImpl for TokenLayout {
         0
     }
 
+    fn characteristics(&self) -> Option<&super::PrintedCharacteristics> {
+        None
+    }
+
+    fn mana_cost(&self) -> Option<&crate::ability_tree::terminals::ManaCost> {
+        None
+    }
+
+    fn abilities(&self) -> Option<&crate::AbilityTree> {
+        Some(&self.abilities)
+    }
+
     #[cfg(feature = "parser")]
     fn from_raw_card(raw_card: &mtg_cardbase::Card) -> Result<Self, String> {
         use crate::lexer::IntoToken;
@@ -84,8 +96,10 @@ impl super::LayoutImpl for TokenLayout {
                 .ok_or_else(|| format!("Failed to parse card type: {}", raw_card.type_line))?,
             colors: crate::ability_tree::colors::Colors::try_from(raw_card.color_identity.as_slice())?,
             abilities: match raw_card.oracle_text.as_ref() {
-                Some(oracle_text) => crate::AbilityTree::from_oracle_text(oracle_text, &raw_card.name)
-                    .map_err(|e| format!("Failed to parse oracle text to ability tree: {e}"))?,
+                Some(oracle_text) => {
+                    crate::AbilityTree::from_card_oracle_text(oracle_text, &crate::lexer::CardName::from_raw_card(raw_card))
+                        .map_err(|e| format!("Failed to parse oracle text to ability tree: {e}"))?
+                }
                 None => crate::AbilityTree::empty(),
             },
             #[cfg(feature = "spanned_tree")]
@@ -1,14 +1,25 @@
+mod cards_in_zone;
 mod event_occured;
+mod number_comparison;
 mod number_of_resolution;
 mod object_is_of_kind;
+mod permanent_has_state;
 mod player_controls_permanent;
+mod player_life_total;
+mod spells_cast_this_turn;
 mod stack_object_has_state;
 mod this_is_your_turn;
 
+pub use cards_in_zone::ConditionCardsInZone;
 pub use event_occured::ConditionEventOccured;
+pub use number_comparison::ConditionNumberComparison;
+pub use number_comparison::NumberComparison;
 pub use number_of_resolution::ConditionNumberOfResolutions;
 pub use object_is_of_kind::ConditionCreatureMatchSpecifier;
+pub use permanent_has_state::ConditionPermanentHasState;
 pub use player_controls_permanent::ConditionPlayerControlsPermanent;
+pub use player_life_total::ConditionPlayerLifeTotal;
+pub use spells_cast_this_turn::ConditionSpellsCastThisTurn;
 pub use stack_object_has_state::ConditionStackObjectHasState;
 pub use this_is_your_turn::ConditionThisIsYourTurn;
 
@@ -180,10 +191,15 @@ impl crate::utils::DummyInit for ConditionalUnless {
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Condition {
+    CardsInZone(ConditionCardsInZone),
     EventOccured(ConditionEventOccured),
+    NumberComparison(ConditionNumberComparison),
     NumberOfResolutions(ConditionNumberOfResolutions),
     ObjectMatchSpecifiers(ConditionCreatureMatchSpecifier),
+    PermanentHasState(ConditionPermanentHasState),
     PlayerControlsObject(ConditionPlayerControlsPermanent),
+    PlayerLifeTotal(ConditionPlayerLifeTotal),
+    SpellsCastThisTurn(ConditionSpellsCastThisTurn),
     StackObjectHasState(ConditionStackObjectHasState),
     ThisIsYourTurn(ConditionThisIsYourTurn),
 }
@@ -197,10 +213,15 @@ impl AbilityTreeNode for Condition {
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
         let mut children = arrayvec::ArrayVec::new_const();
         match self {
+            Self::CardsInZone(child) => children.push(child as &dyn AbilityTreeNode),
             Self::EventOccured(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::NumberComparison(child) => children.push(child as &dyn AbilityTreeNode),
             Self::NumberOfResolutions(child) => children.push(child as &dyn AbilityTreeNode),
             Self::ObjectMatchSpecifiers(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::PermanentHasState(child) => children.push(child as &dyn AbilityTreeNode),
             Self::PlayerControlsObject(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::PlayerLifeTotal(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::SpellsCastThisTurn(child) => children.push(child as &dyn AbilityTreeNode),
             Self::StackObjectHasState(child) => children.push(child as &dyn AbilityTreeNode),
             Self::ThisIsYourTurn(child) => children.push(child as &dyn AbilityTreeNode),
         }
@@ -212,10 +233,15 @@ impl AbilityTreeNode for Condition {
         write!(out, "condition:")?;
         out.push_final_branch()?;
         match self {
+            Self::CardsInZone(child) => child.display(out)?,
             Self::EventOccured(child) => child.display(out)?,
+            Self::NumberComparison(child) => child.display(out)?,
             Self::NumberOfResolutions(child) => child.display(out)?,
             Self::ObjectMatchSpecifiers(child) => child.display(out)?,
+            Self::PermanentHasState(child) => child.display(out)?,
             Self::PlayerControlsObject(child) => child.display(out)?,
+            Self::PlayerLifeTotal(child) => child.display(out)?,
+            Self::SpellsCastThisTurn(child) => child.display(out)?,
             Self::StackObjectHasState(child) => child.display(out)?,
             Self::ThisIsYourTurn(child) => child.display(out)?,
         }
@@ -230,10 +256,15 @@ impl AbilityTreeNode for Condition {
     #[cfg(feature = "spanned_tree")]
     fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
         match self {
+            Self::CardsInZone(child) => child.node_span(),
             Self::EventOccured(child) => child.node_span(),
+            Self::NumberComparison(child) => child.node_span(),
             Self::NumberOfResolutions(child) => child.node_span(),
             Self::ObjectMatchSpecifiers(child) => child.node_span(),
+            Self::PermanentHasState(child) => child.node_span(),
             Self::PlayerControlsObject(child) => child.node_span(),
+            Self::PlayerLifeTotal(child) => child.node_span(),
+            Self::SpellsCastThisTurn(child) => child.node_span(),
             Self::StackObjectHasState(child) => child.node_span(),
             Self::ThisIsYourTurn(child) => child.node_span(),
         }
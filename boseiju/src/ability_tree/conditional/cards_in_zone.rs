@@ -0,0 +1,66 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A condition on the number of cards in a zone.
+///
+/// Examples are "as long as seven or more cards are in your graveyard", for threshold.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConditionCardsInZone {
+    pub amount: crate::ability_tree::number::Number,
+    pub zone: crate::ability_tree::zone::ZoneReference,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl crate::ability_tree::AbilityTreeNode for ConditionCardsInZone {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::ConditionCardsInZone.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.amount as &dyn AbilityTreeNode);
+        children.push(&self.zone as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "cards in zone:")?;
+        out.push_inter_branch()?;
+        write!(out, "amount:")?;
+        out.push_final_branch()?;
+        self.amount.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "zone:")?;
+        out.push_final_branch()?;
+        self.zone.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "cards in zone"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for ConditionCardsInZone {
+    fn dummy_init() -> Self {
+        Self {
+            amount: crate::utils::dummy(),
+            zone: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
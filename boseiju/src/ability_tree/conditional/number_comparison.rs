@@ -0,0 +1,128 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A condition comparing two numbers of the game state.
+///
+/// Examples are "if you have more life than an opponent", comparing two life totals.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConditionNumberComparison {
+    pub number: crate::ability_tree::number::GameStateNumber,
+    pub comparison: NumberComparison,
+    pub other: crate::ability_tree::number::GameStateNumber,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl crate::ability_tree::AbilityTreeNode for ConditionNumberComparison {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::ConditionNumberComparison.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.number as &dyn AbilityTreeNode);
+        children.push(&self.comparison as &dyn AbilityTreeNode);
+        children.push(&self.other as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "number comparison:")?;
+        out.push_inter_branch()?;
+        self.number.display(out)?;
+        out.next_inter_branch()?;
+        self.comparison.display(out)?;
+        out.next_final_branch()?;
+        self.other.display(out)?;
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "number comparison"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for ConditionNumberComparison {
+    fn dummy_init() -> Self {
+        Self {
+            number: crate::utils::dummy(),
+            comparison: crate::utils::dummy(),
+            other: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
+
+/// How the first number of a [`ConditionNumberComparison`] relates to the second one.
+#[derive(idris_derive::Idris)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumberComparison {
+    MoreThan {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+    LessThan {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+}
+
+impl crate::ability_tree::AbilityTreeNode for NumberComparison {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::NumberComparisonIdMarker.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        use crate::ability_tree::NodeKind;
+        use idris::Idris;
+
+        let mut children = arrayvec::ArrayVec::new_const();
+        let child_id = NodeKind::NumberComparison(self.clone()).id();
+        let child = crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::new(child_id);
+        children.push(child as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        match self {
+            Self::MoreThan { .. } => write!(out, "more than"),
+            Self::LessThan { .. } => write!(out, "less than"),
+        }
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "number comparison"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        match self {
+            Self::MoreThan { span } => *span,
+            Self::LessThan { span } => *span,
+        }
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for NumberComparison {
+    fn dummy_init() -> Self {
+        Self::MoreThan {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A condition that is met when a given permanent has a given state.
+///
+/// For example, "as long as ~ is enchanted" or "if it's untapped".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConditionPermanentHasState {
+    pub permanent: crate::ability_tree::object::Permanent,
+    pub state: crate::ability_tree::state::PermanentState,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl crate::ability_tree::AbilityTreeNode for ConditionPermanentHasState {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::ConditionPermanentHasState.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.permanent as &dyn AbilityTreeNode);
+        children.push(&self.state as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "permanent has state:")?;
+        out.push_inter_branch()?;
+        self.permanent.display(out)?;
+        out.next_final_branch()?;
+        self.state.display(out)?;
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "permanent has state"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for ConditionPermanentHasState {
+    fn dummy_init() -> Self {
+        Self {
+            permanent: crate::utils::dummy(),
+            state: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -2,11 +2,13 @@ mod creature_performs_action;
 mod object_gains_state;
 mod permanent_performs_action;
 mod player_performs_action;
+mod zone_change_event;
 
 pub use creature_performs_action::*;
 pub use object_gains_state::*;
 pub use permanent_performs_action::*;
 pub use player_performs_action::*;
+pub use zone_change_event::*;
 
 use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
@@ -27,6 +29,7 @@ pub enum Event {
     ObjectGainsState(ObjectGainsStateEvent),
     PermanentPerformsAction(PermanentPerformsActionEvent),
     PlayerPerformsAction(PlayerPerformsActionEvent),
+    ZoneChange(ZoneChangeEvent),
 }
 
 impl crate::ability_tree::AbilityTreeNode for Event {
@@ -42,6 +45,7 @@ impl crate::ability_tree::AbilityTreeNode for Event {
             Self::ObjectGainsState(child) => children.push(child as &dyn AbilityTreeNode),
             Self::PermanentPerformsAction(child) => children.push(child as &dyn AbilityTreeNode),
             Self::PlayerPerformsAction(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::ZoneChange(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
     }
@@ -55,6 +59,7 @@ impl crate::ability_tree::AbilityTreeNode for Event {
             Self::ObjectGainsState(event) => event.display(out)?,
             Self::PermanentPerformsAction(event) => event.display(out)?,
             Self::PlayerPerformsAction(event) => event.display(out)?,
+            Self::ZoneChange(event) => event.display(out)?,
         }
         out.pop_branch();
         Ok(())
@@ -71,6 +76,7 @@ impl crate::ability_tree::AbilityTreeNode for Event {
             Self::ObjectGainsState(child) => child.node_span(),
             Self::PermanentPerformsAction(child) => child.node_span(),
             Self::PlayerPerformsAction(child) => child.node_span(),
+            Self::ZoneChange(child) => child.node_span(),
         }
     }
 }
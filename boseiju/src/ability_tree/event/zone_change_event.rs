@@ -0,0 +1,96 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// An event for when a card moves from one zone to another.
+///
+/// Either zone can be left unspecified: "~ leaves the battlefield" only knows
+/// the zone the card comes from, while "~ is put into a graveyard" only knows
+/// the zone the card goes to.
+///
+/// See also <https://mtg.fandom.com/wiki/Zone_change>
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneChangeEvent {
+    pub card: crate::ability_tree::object::Card,
+    pub from: Option<crate::ability_tree::zone::ZoneReference>,
+    pub to: Option<crate::ability_tree::zone::ZoneReference>,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl crate::ability_tree::AbilityTreeNode for ZoneChangeEvent {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::ZoneChangeEvent.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.card as &dyn AbilityTreeNode);
+        match self.from.as_ref() {
+            Some(from) => children.push(from as &dyn AbilityTreeNode),
+            None => {
+                let none_node = crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::none_node();
+                children.push(none_node as &dyn AbilityTreeNode);
+            }
+        }
+        match self.to.as_ref() {
+            Some(to) => children.push(to as &dyn AbilityTreeNode),
+            None => {
+                let none_node = crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::none_node();
+                children.push(none_node as &dyn AbilityTreeNode);
+            }
+        }
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "zone change event:")?;
+        out.push_inter_branch()?;
+        write!(out, "card:")?;
+        out.push_final_branch()?;
+        self.card.display(out)?;
+        out.pop_branch();
+        out.next_inter_branch()?;
+        write!(out, "from:")?;
+        out.push_final_branch()?;
+        match self.from.as_ref() {
+            Some(from) => from.display(out)?,
+            None => write!(out, "any zone")?,
+        }
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "to:")?;
+        out.push_final_branch()?;
+        match self.to.as_ref() {
+            Some(to) => to.display(out)?,
+            None => write!(out, "any zone")?,
+        }
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "zone change event"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for ZoneChangeEvent {
+    fn dummy_init() -> Self {
+        Self {
+            card: crate::utils::dummy(),
+            from: crate::utils::dummy(),
+            to: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
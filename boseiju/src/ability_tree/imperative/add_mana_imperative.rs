@@ -1,11 +1,15 @@
 use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 
-/// An imperative for "destroying" an object.
+/// An imperative for adding mana to a player's mana pool.
+///
+/// The added mana can come with a restriction on how it can be spent,
+/// as in "Spend this mana only to cast creature spells."
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AddManaImperative {
     pub possibilities: crate::utils::HeapArrayVec<ManaToAdd, MAX_CHILDREN_PER_NODE>,
+    pub spend_restriction: Option<ManaSpendRestriction>,
     #[cfg(feature = "spanned_tree")]
     pub span: crate::ability_tree::span::TreeSpan,
 }
@@ -17,17 +21,23 @@ impl crate::ability_tree::AbilityTreeNode for AddManaImperative {
     }
 
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        use crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal;
+
         let mut children = arrayvec::ArrayVec::new_const();
-        for mana in self.possibilities.iter() {
+        for mana in self.possibilities.iter().take(MAX_CHILDREN_PER_NODE - 1) {
             children.push(mana as &dyn AbilityTreeNode);
         }
+        match self.spend_restriction.as_ref() {
+            Some(restriction) => children.push(restriction as &dyn AbilityTreeNode),
+            None => children.push(TreeNodeDummyTerminal::none_node() as &dyn AbilityTreeNode),
+        }
         children
     }
 
     fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
         use std::io::Write;
         write!(out, "add mana:")?;
-        out.push_final_branch()?;
+        out.push_inter_branch()?;
         write!(out, "possiblities:")?;
         for (i, possibility) in self.possibilities.iter().enumerate() {
             if i == self.possibilities.len() - 1 {
@@ -38,6 +48,14 @@ impl crate::ability_tree::AbilityTreeNode for AddManaImperative {
             possibility.display(out)?;
             out.pop_branch();
         }
+        out.next_final_branch()?;
+        write!(out, "spend restriction:")?;
+        out.push_final_branch()?;
+        match self.spend_restriction.as_ref() {
+            Some(restriction) => restriction.display(out)?,
+            None => write!(out, "none")?,
+        }
+        out.pop_branch();
         out.pop_branch();
         Ok(())
     }
@@ -57,6 +75,62 @@ impl crate::utils::DummyInit for AddManaImperative {
     fn dummy_init() -> Self {
         Self {
             possibilities: crate::utils::dummy(),
+            spend_restriction: None,
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
+
+/// Restriction on how some added mana can be spent.
+///
+/// For instance:
+/// - Spend this mana only to cast creature spells.
+/// - Spend this mana only to cast instant or sorcery spells.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManaSpendRestriction {
+    pub only_to_cast: crate::ability_tree::object::Spell,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl crate::ability_tree::AbilityTreeNode for ManaSpendRestriction {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::ManaSpendRestriction.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.only_to_cast as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "spend only to cast:")?;
+        out.push_final_branch()?;
+        self.only_to_cast.display(out)?;
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "mana spend restriction"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for ManaSpendRestriction {
+    fn dummy_init() -> Self {
+        Self {
+            only_to_cast: crate::utils::dummy(),
             #[cfg(feature = "spanned_tree")]
             span: Default::default(),
         }
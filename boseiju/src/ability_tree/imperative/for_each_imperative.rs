@@ -20,17 +20,23 @@ impl crate::ability_tree::AbilityTreeNode for ForEachImperative {
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
         let mut children = arrayvec::ArrayVec::new_const();
         children.push(&self.ability as &dyn AbilityTreeNode);
+        children.push(&self.for_each as &dyn AbilityTreeNode);
         children
     }
 
     fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
         use std::io::Write;
         write!(out, "for each imperative:")?;
-        out.push_final_branch()?;
-        write!(out, "amount:")?;
+        out.push_inter_branch()?;
+        write!(out, "ability:")?;
         out.push_final_branch()?;
         self.ability.display(out)?;
         out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "for each:")?;
+        out.push_final_branch()?;
+        self.for_each.display(out)?;
+        out.pop_branch();
         out.pop_branch();
         Ok(())
     }
@@ -5,7 +5,7 @@ use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AttachKeywordAction {
     pub object: crate::ability_tree::object::Permanent, /* Fixme: equipment, aura or fortification only */
-    pub to: crate::ability_tree::object::Permanent,     /* Fixme: permanent or player */
+    pub to: crate::ability_tree::object::PlayerOrPermanent,
     #[cfg(feature = "spanned_tree")]
     pub span: crate::ability_tree::span::TreeSpan,
 }
@@ -76,7 +76,7 @@ impl crate::utils::DummyInit for AttachKeywordAction {
 
 pub fn ability(
     _object: &crate::ability_tree::object::Permanent,
-    _to: &crate::ability_tree::object::Permanent,
+    _to: &crate::ability_tree::object::PlayerOrPermanent,
     #[cfg(feature = "spanned_tree")] span: crate::ability_tree::span::TreeSpan,
 ) -> crate::ability_tree::ability::spell::SpellAbility {
     /* Fixme: unimplemented */
@@ -5,13 +5,17 @@ const MAX_CHOICES: usize = MAX_CHILDREN_PER_NODE - 1;
 
 /// An imperative that requires a player to choose between different clauses.
 ///
-/// This is common in modal effects.
+/// This is common in modal effects. The player choosing the modes is the executing player
+/// of the imperative, so "an opponent chooses one" is a modal imperative executed by an opponent.
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ModalImperative {
     pub mode_count: crate::ability_tree::number::Number,
     pub can_choose_same_mode: bool,
-    pub modes: crate::utils::HeapArrayVec<crate::ability_tree::ability::spell::SpellAbility, MAX_CHOICES>,
+    /// Whether only modes that haven't been chosen before can be chosen,
+    /// as in "choose one that hasn't been chosen".
+    pub only_unchosen_modes: bool,
+    pub modes: crate::utils::HeapArrayVec<ModalMode, MAX_CHOICES>,
     #[cfg(feature = "spanned_tree")]
     pub span: crate::ability_tree::span::TreeSpan,
 }
@@ -32,7 +36,9 @@ impl AbilityTreeNode for ModalImperative {
     }
 
     fn data(&self) -> Option<crate::ability_tree::AbTreeNodeData> {
-        Some(crate::ability_tree::AbTreeNodeData::Boolean { value: self.can_choose_same_mode })
+        Some(crate::ability_tree::AbTreeNodeData::Boolean {
+            value: self.can_choose_same_mode,
+        })
     }
 
     fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
@@ -45,6 +51,8 @@ impl AbilityTreeNode for ModalImperative {
         out.pop_branch();
         out.next_inter_branch()?;
         write!(out, "can choose the same mode multiple times: {}", self.can_choose_same_mode)?;
+        out.next_inter_branch()?;
+        write!(out, "only modes that haven't been chosen: {}", self.only_unchosen_modes)?;
         out.next_final_branch()?;
         write!(out, "choices:")?;
         for choice in self.modes.iter().take(self.modes.len().saturating_sub(1)) {
@@ -57,6 +65,7 @@ impl AbilityTreeNode for ModalImperative {
             choice.display(out)?;
             out.pop_branch();
         }
+        out.pop_branch();
         Ok(())
     }
 
@@ -76,9 +85,83 @@ impl crate::utils::DummyInit for ModalImperative {
         Self {
             mode_count: crate::utils::dummy(),
             can_choose_same_mode: false,
+            only_unchosen_modes: false,
             modes: crate::utils::dummy(),
             #[cfg(feature = "spanned_tree")]
             span: Default::default(),
         }
     }
 }
+
+/// A single mode of a modal imperative.
+///
+/// Some modes require an additional cost to be paid when they are chosen,
+/// such as the modes of spells with spree: "+ {1} — Draw a card."
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModalMode {
+    pub additional_cost: Option<crate::ability_tree::cost::Cost>,
+    pub effect: crate::ability_tree::ability::spell::SpellAbility,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for ModalMode {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::ModalMode.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        use crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal;
+
+        let mut children = arrayvec::ArrayVec::new_const();
+        match self.additional_cost.as_ref() {
+            Some(cost) => children.push(cost as &dyn AbilityTreeNode),
+            None => children.push(TreeNodeDummyTerminal::none_node() as &dyn AbilityTreeNode),
+        }
+        children.push(&self.effect as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "mode:")?;
+        out.push_inter_branch()?;
+        write!(out, "additional cost:")?;
+        out.push_final_branch()?;
+        match self.additional_cost.as_ref() {
+            Some(cost) => cost.display(out)?,
+            None => write!(out, "none")?,
+        }
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "effect:")?;
+        out.push_final_branch()?;
+        self.effect.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "modal mode"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for ModalMode {
+    fn dummy_init() -> Self {
+        Self {
+            additional_cost: None,
+            effect: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::ability_tree::AbilityTreeNode;
+
+/// A path to a node in an ability tree.
+///
+/// The path is the list of child indices to follow from the root to reach the node,
+/// using the [`AbilityTreeNode::children`] ordering. Since the children of a node are
+/// always returned in the same order, the path is a stable identifier for a node
+/// as long as the tree is not modified.
+///
+/// The empty path refers to the root itself.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodePath {
+    pub indices: Vec<usize>,
+}
+
+impl NodePath {
+    /// The path to the root node.
+    pub fn root() -> Self {
+        Self { indices: Vec::new() }
+    }
+
+    /// Create the path of the child at the given index of the node at this path.
+    pub fn child(&self, index: usize) -> Self {
+        let mut indices = self.indices.clone();
+        indices.push(index);
+        Self { indices }
+    }
+
+    /// Number of edges between the root and the node.
+    pub fn depth(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Whether this path points to a node that is an ancestor of (or is) the node at the other path.
+    pub fn is_ancestor_of(&self, other: &Self) -> bool {
+        other.indices.starts_with(&self.indices)
+    }
+
+    /// Follow the path from the given root, and get the node it points to.
+    ///
+    /// Returns None if the path does not exist in the given tree.
+    pub fn resolve<'a>(&self, root: &'a dyn AbilityTreeNode) -> Option<&'a dyn AbilityTreeNode> {
+        let mut current = root;
+        for index in self.indices.iter() {
+            current = *current.children().get(*index)?;
+        }
+        Some(current)
+    }
+}
+
+impl std::fmt::Display for NodePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "/")?;
+        for (i, index) in self.indices.iter().enumerate() {
+            if i == 0 {
+                write!(f, "{index}")?;
+            } else {
+                write!(f, "/{index}")?;
+            }
+        }
+        Ok(())
+    }
+}
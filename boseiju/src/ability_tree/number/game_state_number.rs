@@ -1,6 +1,20 @@
+mod devotion;
+mod life_total;
+mod number_of_cards_in_zone;
+mod number_of_counters;
 mod number_of_permanents;
+mod number_of_times_kicked;
+mod permanents_aggregate;
 
+pub use devotion::Devotion;
+pub use life_total::LifeTotal;
+pub use number_of_cards_in_zone::NumberOfCardsInZone;
+pub use number_of_counters::NumberOfCounters;
 pub use number_of_permanents::NumberOfPermanents;
+pub use number_of_times_kicked::NumberOfTimesKicked;
+pub use permanents_aggregate::AggregatedProperty;
+pub use permanents_aggregate::Aggregation;
+pub use permanents_aggregate::PermanentsAggregate;
 
 use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
@@ -9,7 +23,13 @@ use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GameStateNumber {
+    Devotion(Devotion),
+    LifeTotal(LifeTotal),
+    NumberOfCardsInZone(NumberOfCardsInZone),
+    NumberOfCounters(NumberOfCounters),
     NumberOfPermanents(NumberOfPermanents),
+    NumberOfTimesKicked(NumberOfTimesKicked),
+    PermanentsAggregate(PermanentsAggregate),
 }
 
 impl AbilityTreeNode for GameStateNumber {
@@ -21,7 +41,13 @@ impl AbilityTreeNode for GameStateNumber {
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
         let mut children = arrayvec::ArrayVec::new_const();
         match self {
+            Self::Devotion(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::LifeTotal(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::NumberOfCardsInZone(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::NumberOfCounters(child) => children.push(child as &dyn AbilityTreeNode),
             Self::NumberOfPermanents(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::NumberOfTimesKicked(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::PermanentsAggregate(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
     }
@@ -31,7 +57,13 @@ impl AbilityTreeNode for GameStateNumber {
         write!(out, "game state number")?;
         out.push_final_branch()?;
         match self {
+            Self::Devotion(child) => child.display(out)?,
+            Self::LifeTotal(child) => child.display(out)?,
+            Self::NumberOfCardsInZone(child) => child.display(out)?,
+            Self::NumberOfCounters(child) => child.display(out)?,
             Self::NumberOfPermanents(child) => child.display(out)?,
+            Self::NumberOfTimesKicked(child) => child.display(out)?,
+            Self::PermanentsAggregate(child) => child.display(out)?,
         }
         out.pop_branch();
         Ok(())
@@ -44,7 +76,13 @@ impl AbilityTreeNode for GameStateNumber {
     #[cfg(feature = "spanned_tree")]
     fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
         match self {
+            Self::Devotion(child) => child.node_span(),
+            Self::LifeTotal(child) => child.node_span(),
+            Self::NumberOfCardsInZone(child) => child.node_span(),
+            Self::NumberOfCounters(child) => child.node_span(),
             Self::NumberOfPermanents(child) => child.node_span(),
+            Self::NumberOfTimesKicked(child) => child.node_span(),
+            Self::PermanentsAggregate(child) => child.node_span(),
         }
     }
 }
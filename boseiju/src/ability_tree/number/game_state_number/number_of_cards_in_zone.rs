@@ -0,0 +1,64 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// The number of cards matching a reference in a given zone, such as "creature cards in your graveyard".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberOfCardsInZone {
+    pub card: crate::ability_tree::object::specified_object::SpecifiedCard,
+    pub zone: crate::ability_tree::zone::ZoneReference,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for NumberOfCardsInZone {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::NumberOfCardsInZone.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.card as &dyn AbilityTreeNode);
+        children.push(&self.zone as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "number of cards in zone:")?;
+        out.push_inter_branch()?;
+        write!(out, "cards:")?;
+        out.push_final_branch()?;
+        self.card.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "in zone:")?;
+        out.push_final_branch()?;
+        self.zone.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "number of cards in zone"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for NumberOfCardsInZone {
+    fn dummy_init() -> Self {
+        Self {
+            card: crate::utils::dummy(),
+            zone: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
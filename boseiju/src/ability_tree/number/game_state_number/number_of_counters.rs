@@ -0,0 +1,64 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// The number of counters of a given kind on permanents, such as "+1/+1 counters on ~".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberOfCounters {
+    pub counter: crate::ability_tree::terminals::Counter,
+    pub permanent: crate::ability_tree::object::Permanent,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for NumberOfCounters {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::NumberOfCounters.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.counter as &dyn AbilityTreeNode);
+        children.push(&self.permanent as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "number of counters:")?;
+        out.push_inter_branch()?;
+        write!(out, "counter:")?;
+        out.push_final_branch()?;
+        self.counter.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "on permanent:")?;
+        out.push_final_branch()?;
+        self.permanent.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "number of counters"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for NumberOfCounters {
+    fn dummy_init() -> Self {
+        Self {
+            counter: crate::utils::dummy(),
+            permanent: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
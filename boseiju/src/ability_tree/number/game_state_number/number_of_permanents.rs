@@ -1,7 +1,7 @@
 use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 
-/// Imperative to remove counters on permanents.
+/// The number of permanents matching a reference, such as "creatures you control".
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NumberOfPermanents {
@@ -0,0 +1,207 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// An aggregate of a numerical property over a group of permanents,
+/// such as "the greatest power among creatures you control" or "the total power of creatures you control".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermanentsAggregate {
+    pub aggregation: Aggregation,
+    pub property: AggregatedProperty,
+    pub permanents: crate::ability_tree::object::Permanent,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for PermanentsAggregate {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::PermanentsAggregate.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.aggregation as &dyn AbilityTreeNode);
+        children.push(&self.property as &dyn AbilityTreeNode);
+        children.push(&self.permanents as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "permanents aggregate:")?;
+        out.push_inter_branch()?;
+        self.aggregation.display(out)?;
+        out.next_inter_branch()?;
+        self.property.display(out)?;
+        out.next_final_branch()?;
+        write!(out, "among permanents:")?;
+        out.push_final_branch()?;
+        self.permanents.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "permanents aggregate"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for PermanentsAggregate {
+    fn dummy_init() -> Self {
+        Self {
+            aggregation: crate::utils::dummy(),
+            property: crate::utils::dummy(),
+            permanents: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
+
+/// How the values of the aggregated permanents are combined.
+#[derive(idris_derive::Idris)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Aggregation {
+    Greatest {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+    Total {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+}
+
+impl AbilityTreeNode for Aggregation {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::AggregationIdMarker.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        use idris::Idris;
+
+        let child_id = crate::ability_tree::NodeKind::Aggregation(self.clone()).id();
+        let child = crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::new(child_id);
+
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(child as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "aggregation:")?;
+        out.push_final_branch()?;
+        match self {
+            Self::Greatest { .. } => write!(out, "greatest")?,
+            Self::Total { .. } => write!(out, "total")?,
+        }
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "aggregation"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        match self {
+            Self::Greatest { span } => *span,
+            Self::Total { span } => *span,
+        }
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for Aggregation {
+    fn dummy_init() -> Self {
+        Self::Greatest {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
+
+/// The numerical properties of permanents that can be aggregated.
+#[derive(idris_derive::Idris)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AggregatedProperty {
+    ManaValue {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+    Power {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+    Toughness {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+}
+
+impl AbilityTreeNode for AggregatedProperty {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::AggregatedPropertyIdMarker.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        use idris::Idris;
+
+        let child_id = crate::ability_tree::NodeKind::AggregatedProperty(self.clone()).id();
+        let child = crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::new(child_id);
+
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(child as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "aggregated property:")?;
+        out.push_final_branch()?;
+        match self {
+            Self::ManaValue { .. } => write!(out, "mana value")?,
+            Self::Power { .. } => write!(out, "power")?,
+            Self::Toughness { .. } => write!(out, "toughness")?,
+        }
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "aggregated property"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        match self {
+            Self::ManaValue { span } => *span,
+            Self::Power { span } => *span,
+            Self::Toughness { span } => *span,
+        }
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for AggregatedProperty {
+    fn dummy_init() -> Self {
+        Self::Power {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 mod artifact;
 mod attached_object;
+mod battle;
 mod card;
 mod count_specifier;
 mod creature;
@@ -8,6 +9,8 @@ mod enchantment;
 mod land;
 mod one_among;
 mod permanent;
+mod planeswalker;
+mod player_or_permanent;
 mod previously_mentionned;
 mod self_referencing;
 mod spell;
@@ -18,6 +21,7 @@ pub mod specified_object;
 
 pub use artifact::Artifact;
 pub use attached_object::AttachedObject;
+pub use battle::Battle;
 pub use card::Card;
 pub use card::TopCardsOfLibrary;
 pub use count_specifier::CountSpecifier;
@@ -28,6 +32,8 @@ pub use enchantment::Enchantment;
 pub use land::Land;
 pub use one_among::OneAmong;
 pub use permanent::Permanent;
+pub use planeswalker::Planeswalker;
+pub use player_or_permanent::PlayerOrPermanent;
 pub use previously_mentionned::PreviouslyMentionned;
 pub use self_referencing::SelfReferencing;
 pub use spell::Spell;
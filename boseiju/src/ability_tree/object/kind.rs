@@ -1,4 +1,5 @@
 mod artifact_kind;
+mod battle_kind;
 mod card_kind;
 mod creature_kind;
 mod damage_receiver_kind;
@@ -6,9 +7,11 @@ mod enchantment_kind;
 mod land_kind;
 mod permanent_kind;
 mod planeswalker_kind;
+mod player_or_permanent_kind;
 mod spell_kind;
 
 pub use artifact_kind::ArtifactKind;
+pub use battle_kind::BattleKind;
 pub use card_kind::CardKind;
 pub use creature_kind::CreatureKind;
 pub use damage_receiver_kind::DamageReceiverKind;
@@ -16,4 +19,5 @@ pub use enchantment_kind::EnchantmentKind;
 pub use land_kind::LandKind;
 pub use permanent_kind::PermanentKind;
 pub use planeswalker_kind::PlaneswalkerKind;
+pub use player_or_permanent_kind::PlayerOrPermanentKind;
 pub use spell_kind::SpellKind;
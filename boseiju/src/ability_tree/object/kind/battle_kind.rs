@@ -0,0 +1,67 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// The kind of object of battle references.
+///
+/// Oracle texts only refer to battles as "battle", so this has a single variant.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BattleKind {
+    Battle {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
+}
+
+impl crate::ability_tree::AbilityTreeNode for BattleKind {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::BattleKind.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        use idris::Idris;
+
+        let mut children = arrayvec::ArrayVec::new_const();
+        match self {
+            Self::Battle { .. } => {
+                let node_id = crate::ability_tree::NodeKind::BattleBasicKind.id();
+                let child = crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::new(node_id);
+                children.push(child as &dyn AbilityTreeNode)
+            }
+        }
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "battle kind:")?;
+        out.push_final_branch()?;
+        match self {
+            Self::Battle { .. } => write!(out, "battle")?,
+        }
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "battle kind"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        match self {
+            Self::Battle { span } => *span,
+        }
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for BattleKind {
+    fn dummy_init() -> Self {
+        Self::Battle {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -1,16 +1,18 @@
 use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 use crate::ability_tree::object::OneAmong;
+use crate::ability_tree::object::specified_object::SpecifiedBattle;
 use crate::ability_tree::object::specified_object::SpecifiedCreature;
 use crate::ability_tree::object::specified_object::SpecifiedPlaneswalker;
 use crate::ability_tree::player::PlayerSpecifier;
 
 /// Any object that can receive damages.
 ///
-/// For now, this is creatures, planewalkers, battles, players ?
+/// This is creatures, planeswalkers, battles and players.
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DamageReceiverKind {
+    Battle(SpecifiedBattle),
     Creature(SpecifiedCreature),
     OneAmong(OneAmong<Self>),
     Planeswalker(SpecifiedPlaneswalker),
@@ -26,6 +28,7 @@ impl crate::ability_tree::AbilityTreeNode for DamageReceiverKind {
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
         let mut children = arrayvec::ArrayVec::new_const();
         match self {
+            Self::Battle(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Creature(child) => children.push(child as &dyn AbilityTreeNode),
             Self::OneAmong(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Planeswalker(child) => children.push(child as &dyn AbilityTreeNode),
@@ -39,6 +42,7 @@ impl crate::ability_tree::AbilityTreeNode for DamageReceiverKind {
         write!(out, "damage receiver reference:")?;
         out.push_final_branch()?;
         match self {
+            Self::Battle(child) => child.display(out)?,
             Self::Creature(child) => child.display(out)?,
             Self::OneAmong(child) => child.display(out)?,
             Self::Planeswalker(child) => child.display(out)?,
@@ -55,6 +59,7 @@ impl crate::ability_tree::AbilityTreeNode for DamageReceiverKind {
     #[cfg(feature = "spanned_tree")]
     fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
         match self {
+            Self::Battle(child) => child.node_span(),
             Self::Creature(child) => child.node_span(),
             Self::OneAmong(child) => child.node_span(),
             Self::Planeswalker(child) => child.node_span(),
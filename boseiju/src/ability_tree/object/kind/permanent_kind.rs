@@ -2,6 +2,7 @@ use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 use crate::ability_tree::object::OneAmong;
 use crate::ability_tree::object::specified_object::SpecifiedArtifact;
+use crate::ability_tree::object::specified_object::SpecifiedBattle;
 use crate::ability_tree::object::specified_object::SpecifiedCreature;
 use crate::ability_tree::object::specified_object::SpecifiedEnchantment;
 use crate::ability_tree::object::specified_object::SpecifiedLand;
@@ -16,6 +17,7 @@ use crate::ability_tree::object::specified_object::SpecifiedPlaneswalker;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PermanentKind {
     Artifact(SpecifiedArtifact),
+    Battle(SpecifiedBattle),
     Creature(SpecifiedCreature),
     Enchantment(SpecifiedEnchantment),
     Land(SpecifiedLand),
@@ -39,6 +41,7 @@ impl crate::ability_tree::AbilityTreeNode for PermanentKind {
         let mut children = arrayvec::ArrayVec::new_const();
         match self {
             Self::Artifact(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Battle(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Creature(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Enchantment(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Land(child) => children.push(child as &dyn AbilityTreeNode),
@@ -59,6 +62,7 @@ impl crate::ability_tree::AbilityTreeNode for PermanentKind {
         out.push_final_branch()?;
         match self {
             Self::Artifact(child) => child.display(out)?,
+            Self::Battle(child) => child.display(out)?,
             Self::Creature(child) => child.display(out)?,
             Self::Enchantment(child) => child.display(out)?,
             Self::Land(child) => child.display(out)?,
@@ -78,6 +82,7 @@ impl crate::ability_tree::AbilityTreeNode for PermanentKind {
     fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
         match self {
             Self::Artifact(child) => child.node_span(),
+            Self::Battle(child) => child.node_span(),
             Self::Creature(child) => child.node_span(),
             Self::Enchantment(child) => child.node_span(),
             Self::Land(child) => child.node_span(),
@@ -0,0 +1,67 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+use crate::ability_tree::object::OneAmong;
+use crate::ability_tree::object::kind::PermanentKind;
+use crate::ability_tree::player::PlayerSpecifier;
+
+/// Any object that is either a player or a permanent.
+///
+/// Some effects can apply both to players and permanents,
+/// like auras that can enchant players, or "target player or planeswalker".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlayerOrPermanentKind {
+    OneAmong(OneAmong<Self>),
+    Permanent(PermanentKind),
+    Player(PlayerSpecifier),
+}
+
+impl crate::ability_tree::AbilityTreeNode for PlayerOrPermanentKind {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::PlayerOrPermanentKind.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        match self {
+            Self::OneAmong(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Permanent(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Player(child) => children.push(child as &dyn AbilityTreeNode),
+        }
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "player or permanent kind:")?;
+        out.push_final_branch()?;
+        match self {
+            Self::OneAmong(child) => child.display(out)?,
+            Self::Permanent(child) => child.display(out)?,
+            Self::Player(child) => child.display(out)?,
+        }
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "player or permanent kind"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        match self {
+            Self::OneAmong(child) => child.node_span(),
+            Self::Permanent(child) => child.node_span(),
+            Self::Player(child) => child.node_span(),
+        }
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for PlayerOrPermanentKind {
+    fn dummy_init() -> Self {
+        Self::Permanent(crate::utils::dummy())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+use crate::ability_tree::object::AttachedObject;
+use crate::ability_tree::object::OneAmong;
+use crate::ability_tree::object::PreviouslyMentionned;
+use crate::ability_tree::object::SelfReferencing;
+use crate::ability_tree::object::reference::PlaneswalkerReference;
+
+/// A reference to one or more planeswalkers.
+///
+/// Planeswalkers can be referred to by the card itself ("~"), as the permanent it is attached to,
+/// as previously mentionned planeswalkers, or through a reference such as "target planeswalker".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Planeswalker {
+    Attached(AttachedObject),
+    OneAmong(OneAmong<Self>),
+    PreviouslyMentionned(PreviouslyMentionned),
+    SelfReferencing(SelfReferencing),
+    Reference(PlaneswalkerReference),
+}
+
+impl crate::ability_tree::AbilityTreeNode for Planeswalker {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::Planeswalker.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        match self {
+            Self::Attached(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::OneAmong(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::PreviouslyMentionned(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::SelfReferencing(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Reference(child) => children.push(child as &dyn AbilityTreeNode),
+        }
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "planeswalker:")?;
+        out.push_final_branch()?;
+        match self {
+            Self::Attached(child) => child.display(out)?,
+            Self::OneAmong(child) => child.display(out)?,
+            Self::PreviouslyMentionned(child) => child.display(out)?,
+            Self::SelfReferencing(child) => child.display(out)?,
+            Self::Reference(child) => child.display(out)?,
+        }
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "planeswalker"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        match self {
+            Self::Attached(child) => child.node_span(),
+            Self::OneAmong(child) => child.node_span(),
+            Self::PreviouslyMentionned(child) => child.node_span(),
+            Self::SelfReferencing(child) => child.node_span(),
+            Self::Reference(child) => child.node_span(),
+        }
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for Planeswalker {
+    fn dummy_init() -> Self {
+        Self::Reference(crate::utils::dummy())
+    }
+}
@@ -0,0 +1,72 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+use crate::ability_tree::object::OneAmong;
+use crate::ability_tree::object::PreviouslyMentionned;
+use crate::ability_tree::object::SelfReferencing;
+use crate::ability_tree::object::reference::PlayerOrPermanentReference;
+
+/// A reference to either players or permanents.
+///
+/// This is used for effects that can apply to both,
+/// like "target player or planeswalker" or auras that can enchant players.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlayerOrPermanent {
+    OneAmong(OneAmong<Self>),
+    PreviouslyMentionned(PreviouslyMentionned),
+    SelfReferencing(SelfReferencing),
+    Reference(PlayerOrPermanentReference),
+}
+
+impl crate::ability_tree::AbilityTreeNode for PlayerOrPermanent {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::PlayerOrPermanent.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        match self {
+            Self::OneAmong(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::PreviouslyMentionned(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::SelfReferencing(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Reference(child) => children.push(child as &dyn AbilityTreeNode),
+        }
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "player or permanent:")?;
+        out.push_final_branch()?;
+        match self {
+            Self::OneAmong(child) => child.display(out)?,
+            Self::PreviouslyMentionned(child) => child.display(out)?,
+            Self::SelfReferencing(child) => child.display(out)?,
+            Self::Reference(child) => child.display(out)?,
+        }
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "player or permanent"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        match self {
+            Self::OneAmong(child) => child.node_span(),
+            Self::PreviouslyMentionned(child) => child.node_span(),
+            Self::SelfReferencing(child) => child.node_span(),
+            Self::Reference(child) => child.node_span(),
+        }
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for PlayerOrPermanent {
+    fn dummy_init() -> Self {
+        Self::Reference(crate::utils::dummy())
+    }
+}
@@ -1,17 +1,23 @@
 mod artifact_reference;
+mod battle_reference;
 mod card_reference;
 mod creature_reference;
 mod damage_receiver_reference;
 mod enchantment_reference;
 mod land_reference;
 mod permanent_reference;
+mod planeswalker_reference;
+mod player_or_permanent_reference;
 mod spell_reference;
 
 pub use artifact_reference::ArtifactReference;
+pub use battle_reference::BattleReference;
 pub use card_reference::CardReference;
 pub use creature_reference::CreatureReference;
 pub use damage_receiver_reference::DamageReceiverReference;
 pub use enchantment_reference::EnchantmentReference;
 pub use land_reference::LandReference;
 pub use permanent_reference::PermanentReference;
+pub use planeswalker_reference::PlaneswalkerReference;
+pub use player_or_permanent_reference::PlayerOrPermanentReference;
 pub use spell_reference::SpellReference;
@@ -0,0 +1,64 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A Battle reference.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BattleReference {
+    pub count: crate::ability_tree::object::CountSpecifier,
+    pub battle: crate::ability_tree::object::specified_object::SpecifiedBattle,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for BattleReference {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::BattleReference.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.count as &dyn AbilityTreeNode);
+        children.push(&self.battle as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "Battle reference:")?;
+        out.push_inter_branch()?;
+        write!(out, "count:")?;
+        out.push_final_branch()?;
+        self.count.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "battle:")?;
+        out.push_final_branch()?;
+        self.battle.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "Battle reference"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for BattleReference {
+    fn dummy_init() -> Self {
+        Self {
+            count: crate::utils::dummy(),
+            battle: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
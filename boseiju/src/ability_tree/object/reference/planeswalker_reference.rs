@@ -0,0 +1,64 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A Planeswalker reference.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaneswalkerReference {
+    pub count: crate::ability_tree::object::CountSpecifier,
+    pub planeswalker: crate::ability_tree::object::specified_object::SpecifiedPlaneswalker,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for PlaneswalkerReference {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::PlaneswalkerReference.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.count as &dyn AbilityTreeNode);
+        children.push(&self.planeswalker as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "Planeswalker reference:")?;
+        out.push_inter_branch()?;
+        write!(out, "count:")?;
+        out.push_final_branch()?;
+        self.count.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "planeswalker:")?;
+        out.push_final_branch()?;
+        self.planeswalker.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "Planeswalker reference"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for PlaneswalkerReference {
+    fn dummy_init() -> Self {
+        Self {
+            count: crate::utils::dummy(),
+            planeswalker: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
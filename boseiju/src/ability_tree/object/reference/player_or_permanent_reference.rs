@@ -0,0 +1,64 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A Player or permanent reference.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerOrPermanentReference {
+    pub count: crate::ability_tree::object::CountSpecifier,
+    pub kind: crate::ability_tree::object::kind::PlayerOrPermanentKind,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for PlayerOrPermanentReference {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::PlayerOrPermanentReference.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.count as &dyn AbilityTreeNode);
+        children.push(&self.kind as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "player or permanent reference:")?;
+        out.push_inter_branch()?;
+        write!(out, "count:")?;
+        out.push_final_branch()?;
+        self.count.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "kind:")?;
+        out.push_final_branch()?;
+        self.kind.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "player or permanent reference"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for PlayerOrPermanentReference {
+    fn dummy_init() -> Self {
+        Self {
+            count: crate::utils::dummy(),
+            kind: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
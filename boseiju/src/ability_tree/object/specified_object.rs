@@ -1,4 +1,5 @@
 mod specified_artifact;
+mod specified_battle;
 mod specified_card;
 mod specified_creature;
 mod specified_enchantment;
@@ -9,6 +10,7 @@ mod specified_spell;
 mod specifiers;
 
 pub use specified_artifact::*;
+pub use specified_battle::*;
 pub use specified_card::*;
 pub use specified_creature::*;
 pub use specified_enchantment::*;
@@ -0,0 +1,99 @@
+mod battle_specifier;
+
+pub use battle_specifier::*;
+
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+use crate::ability_tree::object::kind::BattleKind;
+use crate::ability_tree::object::specified_object::Specifiers;
+
+/// A specified battle.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecifiedBattle {
+    pub kind: BattleKind,
+    pub specifiers: Option<Specifiers<BattleSpecifier>>,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl SpecifiedBattle {
+    pub fn add_factor_specifier(&self, factor_specifier: BattleSpecifier) -> Self {
+        #[cfg(feature = "spanned_tree")]
+        let factor_specifier_span = factor_specifier.node_span();
+        match &self.specifiers {
+            Some(prev_specifiers) => SpecifiedBattle {
+                kind: self.kind.clone(),
+                specifiers: Some(prev_specifiers.add_factor_specifier(factor_specifier)),
+                #[cfg(feature = "spanned_tree")]
+                span: factor_specifier_span.merge(&self.span),
+            },
+            None => SpecifiedBattle {
+                kind: self.kind.clone(),
+                specifiers: Some(Specifiers::Single(factor_specifier)),
+                #[cfg(feature = "spanned_tree")]
+                span: factor_specifier_span.merge(&self.span),
+            },
+        }
+    }
+}
+
+impl AbilityTreeNode for SpecifiedBattle {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::SpecifiedBattle.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        use crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal;
+
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.kind as &dyn AbilityTreeNode);
+        match self.specifiers.as_ref() {
+            Some(specifiers) => children.push(specifiers as &dyn AbilityTreeNode),
+            None => children.push(TreeNodeDummyTerminal::none_node() as &dyn AbilityTreeNode),
+        }
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "specified battle:")?;
+        out.push_inter_branch()?;
+        write!(out, "kind:")?;
+        out.push_final_branch()?;
+        self.kind.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "specifier(s):")?;
+        out.push_final_branch()?;
+        match self.specifiers.as_ref() {
+            Some(specifiers) => specifiers.display(out)?,
+            None => write!(out, "none")?,
+        }
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "specified battle"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for SpecifiedBattle {
+    fn dummy_init() -> Self {
+        Self {
+            kind: crate::utils::dummy(),
+            specifiers: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+use crate::ability_tree::object::specified_object::AnotherObjectSpecifier;
+use crate::ability_tree::object::specified_object::ColorSpecifier;
+use crate::ability_tree::object::specified_object::ControlSpecifier;
+use crate::ability_tree::object::specified_object::CountersOnObjectSpecifier;
+use crate::ability_tree::object::specified_object::OwnerSpecifier;
+use crate::ability_tree::object::specified_object::ProtectorSpecifier;
+use crate::ability_tree::object::specified_object::Specifier;
+
+/// Specifiers for battle.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BattleSpecifier {
+    Another(AnotherObjectSpecifier),
+    Color(ColorSpecifier),
+    Control(ControlSpecifier),
+    Owner(OwnerSpecifier),
+    Protector(ProtectorSpecifier),
+    Subtype(BattleSubtypeSpecifier),
+    WithCounters(CountersOnObjectSpecifier),
+}
+
+impl Specifier for BattleSpecifier {}
+
+impl crate::ability_tree::AbilityTreeNode for BattleSpecifier {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::BattleSpecifier.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        match self {
+            Self::Another(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Color(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Control(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Owner(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Protector(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Subtype(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::WithCounters(child) => children.push(child as &dyn AbilityTreeNode),
+        }
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "battle specifier:")?;
+        out.push_final_branch()?;
+        match self {
+            Self::Another(child) => child.display(out)?,
+            Self::Color(child) => child.display(out)?,
+            Self::Control(child) => child.display(out)?,
+            Self::Owner(child) => child.display(out)?,
+            Self::Protector(child) => child.display(out)?,
+            Self::Subtype(child) => child.display(out)?,
+            Self::WithCounters(child) => child.display(out)?,
+        }
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "battle specifier"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        match self {
+            Self::Another(child) => child.node_span(),
+            Self::Color(child) => child.node_span(),
+            Self::Control(child) => child.node_span(),
+            Self::Owner(child) => child.node_span(),
+            Self::Protector(child) => child.node_span(),
+            Self::Subtype(child) => child.node_span(),
+            Self::WithCounters(child) => child.node_span(),
+        }
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for BattleSpecifier {
+    fn dummy_init() -> Self {
+        Self::Subtype(crate::utils::dummy())
+    }
+}
+
+/// The battle has subtype specifiers.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BattleSubtypeSpecifier {
+    pub subtype: crate::ability_tree::terminals::BattleSubtype,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl crate::ability_tree::AbilityTreeNode for BattleSubtypeSpecifier {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::BattleSubtypeSpecifier.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.subtype as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "battle subtype specifier:")?;
+        out.push_final_branch()?;
+        self.subtype.display(out)?;
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "battle subtype specifier"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.subtype.node_span()
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for BattleSubtypeSpecifier {
+    fn dummy_init() -> Self {
+        Self {
+            subtype: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
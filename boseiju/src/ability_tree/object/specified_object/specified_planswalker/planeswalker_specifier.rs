@@ -3,6 +3,7 @@ use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 use crate::ability_tree::object::specified_object::AnotherObjectSpecifier;
 use crate::ability_tree::object::specified_object::ColorSpecifier;
 use crate::ability_tree::object::specified_object::ControlSpecifier;
+use crate::ability_tree::object::specified_object::CountersOnObjectSpecifier;
 use crate::ability_tree::object::specified_object::OwnerSpecifier;
 use crate::ability_tree::object::specified_object::Specifier;
 
@@ -15,6 +16,7 @@ pub enum PlaneswalkerSpecifier {
     Control(ControlSpecifier),
     Owner(OwnerSpecifier),
     Subtype(PlaneswalkerSubtypeSpecifier),
+    WithCounters(CountersOnObjectSpecifier),
 }
 
 impl Specifier for PlaneswalkerSpecifier {}
@@ -33,6 +35,7 @@ impl crate::ability_tree::AbilityTreeNode for PlaneswalkerSpecifier {
             Self::Control(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Owner(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Subtype(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::WithCounters(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
     }
@@ -47,6 +50,7 @@ impl crate::ability_tree::AbilityTreeNode for PlaneswalkerSpecifier {
             Self::Control(child) => child.display(out)?,
             Self::Owner(child) => child.display(out)?,
             Self::Subtype(child) => child.display(out)?,
+            Self::WithCounters(child) => child.display(out)?,
         }
         out.pop_branch();
         Ok(())
@@ -64,6 +68,7 @@ impl crate::ability_tree::AbilityTreeNode for PlaneswalkerSpecifier {
             Self::Control(child) => child.node_span(),
             Self::Owner(child) => child.node_span(),
             Self::Subtype(child) => child.node_span(),
+            Self::WithCounters(child) => child.node_span(),
         }
     }
 }
@@ -75,7 +80,7 @@ impl crate::utils::DummyInit for PlaneswalkerSpecifier {
     }
 }
 
-/// The  creature has subtype specifiers.
+/// The planeswalker has subtype specifiers.
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PlaneswalkerSubtypeSpecifier {
@@ -2,10 +2,14 @@ mod another_specifier;
 mod characteristic_specifiers;
 mod color_specifier;
 mod control_specifier;
+mod counters_on_object_specifier;
 mod owner_specifier;
+mod protector_specifier;
 
 pub use another_specifier::*;
 pub use characteristic_specifiers::*;
 pub use color_specifier::*;
 pub use control_specifier::*;
+pub use counters_on_object_specifier::*;
 pub use owner_specifier::*;
+pub use protector_specifier::*;
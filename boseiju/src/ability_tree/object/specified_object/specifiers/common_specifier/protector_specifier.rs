@@ -0,0 +1,61 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A specifier for who protects a battle.
+///
+/// Each battle has a protector, chosen as it enters the battlefield (see rule 310.8).
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtectorSpecifier {
+    pub protector: crate::ability_tree::player::PlayerSpecifier,
+    pub protected: bool,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for ProtectorSpecifier {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::ProtectorSpecifier.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.protector as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn data(&self) -> Option<crate::ability_tree::AbTreeNodeData> {
+        Some(crate::ability_tree::AbTreeNodeData::Boolean { value: self.protected })
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "protector specifier:")?;
+        out.push_final_branch()?;
+        self.protector.display(out)?;
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "protector specifier"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for ProtectorSpecifier {
+    fn dummy_init() -> Self {
+        Self {
+            protector: crate::utils::dummy(),
+            protected: false,
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -32,6 +32,10 @@ pub enum PlayerSpecifier {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
     },
+    TargetPlayer {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
     ToYourLeft {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
@@ -87,6 +91,7 @@ impl AbilityTreeNode for PlayerSpecifier {
                 out.pop_branch();
             }
             Self::TargetOpponent { .. } => write!(out, "target opponent")?,
+            Self::TargetPlayer { .. } => write!(out, "target player")?,
             Self::PerviouslyMentionnedPlayer { .. } => write!(out, "target opponent")?,
             Self::ToYourLeft { .. } => write!(out, "the player to your left")?,
             Self::ToYourRight { .. } => write!(out, "the player to your right")?,
@@ -109,6 +114,7 @@ impl AbilityTreeNode for PlayerSpecifier {
             Self::ObjectController(child) => child.span,
             Self::ObjectOwner(child) => child.span,
             Self::TargetOpponent { span } => *span,
+            Self::TargetPlayer { span } => *span,
             Self::PerviouslyMentionnedPlayer { span } => *span,
             Self::ToYourLeft { span } => *span,
             Self::ToYourRight { span } => *span,
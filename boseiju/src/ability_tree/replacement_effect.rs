@@ -1,17 +1,32 @@
+mod damage_prevention_effect;
+mod damage_redirection_effect;
+mod doubling_effect;
 mod etb_replacement_effect;
+mod event_replacement_effect;
 
+pub use damage_prevention_effect::*;
+pub use damage_redirection_effect::*;
+pub use doubling_effect::*;
 pub use etb_replacement_effect::*;
+pub use event_replacement_effect::*;
 
 use crate::ability_tree::AbilityTreeNode;
 use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 
 /// A replacement effect replaces entirely one event with another.
 ///
+/// Prevention effects are not replacement effects in the comprehensive rules, but they
+/// work the same way (watching for an event that would happen) so they are kept here.
+///
 /// See also <https://mtg.fandom.com/wiki/Replacement_effect>
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ReplacementEffect {
+    DamagePrevention(DamagePreventionEffect),
+    DamageRedirection(DamageRedirectionEffect),
+    Doubling(DoublingEffect),
     Etb(EtbReplacementEffect),
+    Event(EventReplacementEffect),
 }
 
 impl AbilityTreeNode for ReplacementEffect {
@@ -23,7 +38,11 @@ impl AbilityTreeNode for ReplacementEffect {
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
         let mut children = arrayvec::ArrayVec::new_const();
         match self {
+            Self::DamagePrevention(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::DamageRedirection(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Doubling(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Etb(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Event(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
     }
@@ -33,7 +52,11 @@ impl AbilityTreeNode for ReplacementEffect {
         write!(out, "replacement effect:")?;
         out.push_inter_branch()?;
         match self {
+            Self::DamagePrevention(child) => child.display(out)?,
+            Self::DamageRedirection(child) => child.display(out)?,
+            Self::Doubling(child) => child.display(out)?,
             Self::Etb(child) => child.display(out)?,
+            Self::Event(child) => child.display(out)?,
         }
         out.pop_branch();
         Ok(())
@@ -46,7 +69,11 @@ impl AbilityTreeNode for ReplacementEffect {
     #[cfg(feature = "spanned_tree")]
     fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
         match self {
+            Self::DamagePrevention(child) => child.node_span(),
+            Self::DamageRedirection(child) => child.node_span(),
+            Self::Doubling(child) => child.node_span(),
             Self::Etb(child) => child.node_span(),
+            Self::Event(child) => child.node_span(),
         }
     }
 }
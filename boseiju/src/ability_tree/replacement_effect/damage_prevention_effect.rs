@@ -0,0 +1,96 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A prevention effect, that prevents damage from being dealt.
+///
+/// From the CR:
+/// \[615.1\] Some continuous effects are prevention effects. Like replacement effects, prevention
+/// effects apply continuously as events happen—they aren't locked in ahead of time. Such effects
+/// watch for a damage event that would happen and completely or partially prevent the damage
+/// that would be dealt.
+///
+/// \[615.1a\] Effects that use the word "prevent" are prevention effects.
+///
+/// Only prevention of all the damage is supported, such as "Prevent all combat damage that
+/// would be dealt to ~". When no receiver is given, damage dealt to anything is prevented.
+///
+/// The prevented damage can be restricted to a source chosen on resolution, and the effect
+/// can only apply to the next time such damage would be dealt, as in "The next time a source
+/// of your choice would deal damage to you this turn, prevent that damage".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DamagePreventionEffect {
+    pub damage_kind: crate::ability_tree::terminals::DamageKind,
+    pub receiver: Option<crate::ability_tree::object::DamageReceiver>,
+    pub source_of_choice: bool,
+    pub next_time_only: bool,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for DamagePreventionEffect {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::DamagePreventionEffect.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.damage_kind as &dyn AbilityTreeNode);
+        match self.receiver.as_ref() {
+            Some(receiver) => children.push(receiver as &dyn AbilityTreeNode),
+            None => {
+                let none_node = crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::none_node();
+                children.push(none_node as &dyn AbilityTreeNode);
+            }
+        }
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "damage prevention effect:")?;
+        out.push_inter_branch()?;
+        write!(out, "prevented damage:")?;
+        out.push_final_branch()?;
+        self.damage_kind.display(out)?;
+        out.pop_branch();
+        out.next_inter_branch()?;
+        write!(out, "dealt to:")?;
+        out.push_final_branch()?;
+        match self.receiver.as_ref() {
+            Some(receiver) => receiver.display(out)?,
+            None => write!(out, "anything")?,
+        }
+        out.pop_branch();
+        out.next_inter_branch()?;
+        write!(out, "source of choice: {}", self.source_of_choice)?;
+        out.next_final_branch()?;
+        write!(out, "next time only: {}", self.next_time_only)?;
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "damage prevention effect"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for DamagePreventionEffect {
+    fn dummy_init() -> Self {
+        Self {
+            damage_kind: crate::utils::dummy(),
+            receiver: None,
+            source_of_choice: false,
+            next_time_only: false,
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A redirection effect, that makes damage be dealt to another object or player.
+///
+/// From the CR:
+/// \[614.9\] Some effects replace damage dealt to one object or player with the same damage
+/// dealt to another object or player; such effects are called redirection effects.
+///
+/// For instance, "All damage that would be dealt to you is dealt to ~ instead."
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DamageRedirectionEffect {
+    pub damage_kind: crate::ability_tree::terminals::DamageKind,
+    pub original_receiver: crate::ability_tree::object::DamageReceiver,
+    pub new_receiver: crate::ability_tree::object::DamageReceiver,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for DamageRedirectionEffect {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::DamageRedirectionEffect.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(&self.damage_kind as &dyn AbilityTreeNode);
+        children.push(&self.original_receiver as &dyn AbilityTreeNode);
+        children.push(&self.new_receiver as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "damage redirection effect:")?;
+        out.push_inter_branch()?;
+        write!(out, "redirected damage:")?;
+        out.push_final_branch()?;
+        self.damage_kind.display(out)?;
+        out.pop_branch();
+        out.next_inter_branch()?;
+        write!(out, "would be dealt to:")?;
+        out.push_final_branch()?;
+        self.original_receiver.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "is dealt to:")?;
+        out.push_final_branch()?;
+        self.new_receiver.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "damage redirection effect"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for DamageRedirectionEffect {
+    fn dummy_init() -> Self {
+        Self {
+            damage_kind: crate::utils::dummy(),
+            original_receiver: crate::utils::dummy(),
+            new_receiver: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
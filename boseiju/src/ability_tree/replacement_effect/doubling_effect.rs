@@ -0,0 +1,64 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A replacement effect that replaces an event with the same event, happening twice as much.
+///
+/// From the CR:
+/// \[614.1a\] Effects that use the word "instead" are replacement effects.
+///
+/// For instance, "If an effect would create one or more tokens under your control, it creates
+/// twice that many of those tokens instead." doubles the amount of created tokens, and
+/// "If one or more +1/+1 counters would be put on a creature you control, twice that many
+/// +1/+1 counters are put on it instead." doubles the amount of put counters.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoublingEffect {
+    pub replaced_event: Box<crate::ability_tree::event::Event>,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for DoublingEffect {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::DoublingEffect.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(self.replaced_event.as_ref() as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "doubling effect:")?;
+        out.push_final_branch()?;
+        write!(out, "doubled event:")?;
+        out.push_final_branch()?;
+        self.replaced_event.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "doubling effect"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for DoublingEffect {
+    fn dummy_init() -> Self {
+        Self {
+            replaced_event: Box::new(crate::utils::dummy()),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
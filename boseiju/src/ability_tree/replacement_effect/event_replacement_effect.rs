@@ -0,0 +1,71 @@
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::MAX_CHILDREN_PER_NODE;
+
+/// A replacement effect that replaces an event with a list of imperatives.
+///
+/// From the CR:
+/// \[614.1a\] Effects that use the word "instead" are replacement effects. Most replacement effects
+/// use the word "instead" to indicate what events will be replaced with other events.
+///
+/// For instance, "If a creature would die, exile it instead." replaces the creature dies event
+/// with exiling the creature.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventReplacementEffect {
+    pub replaced_event: Box<crate::ability_tree::event::Event>,
+    pub replacement: crate::ability_tree::imperative_list::ImperativeList,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for EventReplacementEffect {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::EventReplacementEffect.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(self.replaced_event.as_ref() as &dyn AbilityTreeNode);
+        children.push(&self.replacement as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "event replacement effect:")?;
+        out.push_inter_branch()?;
+        write!(out, "replaced event:")?;
+        out.push_final_branch()?;
+        self.replaced_event.display(out)?;
+        out.pop_branch();
+        out.next_final_branch()?;
+        write!(out, "replacement:")?;
+        out.push_final_branch()?;
+        self.replacement.display(out)?;
+        out.pop_branch();
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "event replacement effect"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for EventReplacementEffect {
+    fn dummy_init() -> Self {
+        Self {
+            replaced_event: Box::new(crate::utils::dummy()),
+            replacement: crate::utils::dummy(),
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -36,10 +36,26 @@ impl AbilityTree {
         self.display(&mut tree_formatter)
     }
 
+    /// Link all the "it", "that creature" and "that player" references of the tree to what they refer to.
+    ///
+    /// See the [`crate::ability_tree::anaphora`] module for more details.
+    pub fn resolve_anaphora(&self) -> crate::ability_tree::anaphora::AnaphoraResolution {
+        crate::ability_tree::anaphora::AnaphoraResolution::resolve(self)
+    }
+
     #[cfg(feature = "parser")]
     pub fn from_oracle_text(oracle_text: &str, card_name: &str) -> Result<AbilityTree, crate::error::BoseijuError> {
-        let preprocessed = crate::lexer::preprocess(card_name, oracle_text);
-        let tokens = crate::lexer::lex(&preprocessed)?;
+        Self::from_card_oracle_text(oracle_text, &crate::lexer::CardName::new(card_name))
+    }
+
+    /// Parse an oracle text, replacing all the known forms of the card name.
+    #[cfg(feature = "parser")]
+    pub fn from_card_oracle_text(
+        oracle_text: &str,
+        card_name: &crate::lexer::CardName,
+    ) -> Result<AbilityTree, crate::error::BoseijuError> {
+        let preprocessed = crate::lexer::preprocess_card(card_name, oracle_text);
+        let tokens = crate::lexer::lex(&preprocessed.text)?;
         let result = crate::parser::parse(&tokens)?;
         Ok(result)
     }
@@ -1,7 +1,9 @@
+mod enchanted_state;
 mod tapped_state;
 mod target_state;
 mod untapped_state;
 
+pub use enchanted_state::PermanentEnchantedState;
 pub use tapped_state::PermanentTappedState;
 pub use target_state::PermanentTargetedState;
 pub use untapped_state::PermanentUntappedState;
@@ -19,6 +21,8 @@ pub enum PermanentState {
     Untapped(PermanentUntappedState),
     /// Being the target of a stack object permanent state.
     Targeted(PermanentTargetedState),
+    /// Enchanted by an aura permanent state.
+    Enchanted(PermanentEnchantedState),
 }
 
 impl AbilityTreeNode for PermanentState {
@@ -33,6 +37,7 @@ impl AbilityTreeNode for PermanentState {
             Self::Tapped(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Untapped(child) => children.push(child as &dyn AbilityTreeNode),
             Self::Targeted(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::Enchanted(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
     }
@@ -45,6 +50,7 @@ impl AbilityTreeNode for PermanentState {
             Self::Tapped(child) => child.display(out)?,
             Self::Untapped(child) => child.display(out)?,
             Self::Targeted(child) => child.display(out)?,
+            Self::Enchanted(child) => child.display(out)?,
         }
         out.pop_branch();
         Ok(())
@@ -60,6 +66,7 @@ impl AbilityTreeNode for PermanentState {
             Self::Tapped(child) => child.node_span(),
             Self::Untapped(child) => child.node_span(),
             Self::Targeted(child) => child.node_span(),
+            Self::Enchanted(child) => child.node_span(),
         }
     }
 }
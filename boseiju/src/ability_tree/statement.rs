@@ -11,6 +11,33 @@ pub enum Statement {
     ConditionalImperative(ConditionalImperative),
 }
 
+impl Statement {
+    /// All the imperatives the statement can execute, whichever branch ends up being taken.
+    pub fn imperatives(&self) -> Vec<&crate::ability_tree::imperative::Imperative> {
+        match self {
+            Self::Imperatives(list) => list.imperatives.iter().collect(),
+            Self::May(may) => may
+                .action
+                .imperatives
+                .iter()
+                .chain(may.if_it_is_done.iter().flat_map(|statement| statement.imperatives()))
+                .chain(may.if_not_done.iter().flat_map(|statement| statement.imperatives()))
+                .collect(),
+            Self::ConditionalImperative(conditional) => conditional
+                .condition_met_clause
+                .imperatives
+                .iter()
+                .chain(
+                    conditional
+                        .cond_not_met_clause
+                        .iter()
+                        .flat_map(|list| list.imperatives.iter()),
+                )
+                .collect(),
+        }
+    }
+}
+
 impl crate::ability_tree::AbilityTreeNode for Statement {
     fn node_id(&self) -> usize {
         use idris::Idris;
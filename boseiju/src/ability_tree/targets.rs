@@ -0,0 +1,315 @@
+//! Target extraction for ability trees.
+//!
+//! Targets are declared in oracle texts with the word "target", as in "destroy target creature"
+//! or "~ deals 3 damage to any target". In the tree, they are object references with a target count specifier,
+//! "any target" damage receivers and "target player" or "target opponent" player specifiers.
+//!
+//! The extraction walks a tree and lists each of these target slots, with what kind of object they target,
+//! how many targets are chosen, the constraints on the targets and the imperative acting on them.
+//! Trigger conditions are not walked, since they describe events and never declare targets.
+
+use crate::ability_tree::AbilityTreeNode;
+use crate::ability_tree::NodeKind;
+use crate::ability_tree::NodePath;
+use crate::ability_tree::number::Number;
+use crate::ability_tree::object::CountSpecifier;
+use crate::ability_tree::object::kind::PermanentKind;
+use crate::ability_tree::object::reference::PermanentReference;
+use crate::ability_tree::object::specified_object::*;
+use idris::Idris;
+
+/// The kind of object a target slot targets.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TargetedObject {
+    Artifact,
+    Battle,
+    Card,
+    Creature,
+    /// Any object that can be dealt damage, such as "target creature or planeswalker".
+    DamageReceiver,
+    Enchantment,
+    Land,
+    Permanent,
+    Planeswalker,
+    PlayerOrPermanent,
+    Spell,
+    /// "any target", a creature, player, planeswalker or battle.
+    AnyTarget,
+    Opponent,
+    Player,
+}
+
+/// A constraint on the targets of a slot, other than who controls them, as in "target black creature".
+///
+/// The specifiers are the ones of the kind of object they constrain.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TargetSpecifier {
+    Artifact(ArtifactSpecifier),
+    Battle(BattleSpecifier),
+    Card(CardSpecifier),
+    Creature(CreatureSpecifier),
+    Enchantment(EnchantmentSpecifier),
+    Land(LandSpecifier),
+    Permanent(PermanentSpecifier),
+    Planeswalker(PlaneswalkerSpecifier),
+    Spell(SpellSpecifier),
+}
+
+impl TargetSpecifier {
+    /// Find the specifier of the given node, if it is one.
+    fn from_node(node: &dyn AbilityTreeNode) -> Option<Self> {
+        let node: &dyn std::any::Any = node;
+        if let Some(specifier) = node.downcast_ref::<ArtifactSpecifier>() {
+            Some(Self::Artifact(specifier.clone()))
+        } else if let Some(specifier) = node.downcast_ref::<BattleSpecifier>() {
+            Some(Self::Battle(specifier.clone()))
+        } else if let Some(specifier) = node.downcast_ref::<CardSpecifier>() {
+            Some(Self::Card(specifier.clone()))
+        } else if let Some(specifier) = node.downcast_ref::<CreatureSpecifier>() {
+            Some(Self::Creature(specifier.clone()))
+        } else if let Some(specifier) = node.downcast_ref::<EnchantmentSpecifier>() {
+            Some(Self::Enchantment(specifier.clone()))
+        } else if let Some(specifier) = node.downcast_ref::<LandSpecifier>() {
+            Some(Self::Land(specifier.clone()))
+        } else if let Some(specifier) = node.downcast_ref::<PermanentSpecifier>() {
+            Some(Self::Permanent(specifier.clone()))
+        } else if let Some(specifier) = node.downcast_ref::<PlaneswalkerSpecifier>() {
+            Some(Self::Planeswalker(specifier.clone()))
+        } else {
+            node.downcast_ref::<SpellSpecifier>()
+                .map(|specifier| Self::Spell(specifier.clone()))
+        }
+    }
+
+    /// Control specifiers are already listed in the controllers of the slot.
+    fn is_control(&self) -> bool {
+        matches!(
+            self,
+            Self::Artifact(ArtifactSpecifier::Control(_))
+                | Self::Battle(BattleSpecifier::Control(_))
+                | Self::Creature(CreatureSpecifier::Control(_))
+                | Self::Enchantment(EnchantmentSpecifier::Control(_))
+                | Self::Land(LandSpecifier::Control(_))
+                | Self::Permanent(PermanentSpecifier::Control(_))
+                | Self::Planeswalker(PlaneswalkerSpecifier::Control(_))
+                | Self::Spell(SpellSpecifier::Control(_))
+        )
+    }
+}
+
+/// A target that must be chosen for an ability.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetSlot {
+    /// Path to the node declaring the target, from the node the targets were extracted from.
+    pub path: NodePath,
+    pub object: TargetedObject,
+    /// How many targets are chosen: "target", "two target", "up to three target", "any number of target".
+    pub count: Number,
+    /// Whether the slot can choose several targets, that must then be distinct objects.
+    ///
+    /// A single word "target" can't choose the same object twice, as in "two target creatures".
+    pub distinct: bool,
+    /// Constraints on who controls the targets, as in "target creature an opponent controls".
+    pub controllers: Vec<ControlSpecifier>,
+    /// The other constraints on the targets, such as their color or subtypes.
+    pub specifiers: Vec<TargetSpecifier>,
+    /// Path to the imperative acting on the targets, if the target is declared in one.
+    pub consumed_by: Option<NodePath>,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+/// List all the target slots in the tree starting at the given root, in the order they appear.
+pub fn targets(root: &dyn AbilityTreeNode) -> Vec<TargetSlot> {
+    let mut slots = Vec::new();
+    visit(root, NodePath::root(), None, &mut slots);
+    slots
+}
+
+fn visit(node: &dyn AbilityTreeNode, path: NodePath, imperative: Option<&NodePath>, slots: &mut Vec<TargetSlot>) {
+    let node_id = node.node_id();
+    if node_id == NodeKind::TriggerCondition.id() {
+        return;
+    }
+
+    let imperative_path;
+    let imperative = if node_id == NodeKind::Imperative.id() {
+        imperative_path = path.clone();
+        Some(&imperative_path)
+    } else {
+        imperative
+    };
+
+    if let Some(object) = node_ids::referenced_object(node_id) {
+        let target_count = node.children().into_iter().find_map(|child| {
+            let child: &dyn std::any::Any = child;
+            match child.downcast_ref::<CountSpecifier>() {
+                Some(CountSpecifier::Target(count)) => Some(count.clone()),
+                _ => None,
+            }
+        });
+        if let Some(count) = target_count {
+            let object = refined_object(node, object);
+            slots.push(new_slot(node, path.clone(), object, count, imperative));
+        }
+    } else if node_id == NodeKind::AnyTarget.id() {
+        let count = single_target();
+        slots.push(new_slot(node, path.clone(), TargetedObject::AnyTarget, count, imperative));
+    } else if node_id == NodeKind::PlayerSpecifierIdMarker.id()
+        && let Some(object) = targeted_player(node)
+    {
+        let count = single_target();
+        slots.push(new_slot(node, path.clone(), object, count, imperative));
+    }
+
+    for (i, child) in node.children().into_iter().enumerate() {
+        visit(child, path.child(i), imperative, slots);
+    }
+}
+
+fn new_slot(
+    node: &dyn AbilityTreeNode,
+    path: NodePath,
+    object: TargetedObject,
+    count: Number,
+    imperative: Option<&NodePath>,
+) -> TargetSlot {
+    let mut controllers = Vec::new();
+    collect_controllers(node, &mut controllers);
+    let mut specifiers = Vec::new();
+    collect_specifiers(node, &mut specifiers);
+    TargetSlot {
+        path,
+        object,
+        distinct: allows_several_targets(&count),
+        count,
+        controllers,
+        specifiers,
+        consumed_by: imperative.cloned(),
+        #[cfg(feature = "spanned_tree")]
+        span: node.node_span(),
+    }
+}
+
+/// Collect the control specifiers of the declaring node.
+///
+/// Nested object references and the players of the control specifiers have their own constraints,
+/// which are not the ones of the targets, so they are not walked.
+fn collect_controllers(node: &dyn AbilityTreeNode, controllers: &mut Vec<ControlSpecifier>) {
+    for child in node.children().into_iter() {
+        let any: &dyn std::any::Any = child;
+        if let Some(control) = any.downcast_ref::<ControlSpecifier>() {
+            controllers.push(control.clone());
+        } else if node_ids::referenced_object(child.node_id()).is_none() {
+            collect_controllers(child, controllers);
+        }
+    }
+}
+
+/// Collect the specifiers of the declaring node, except the control specifiers.
+///
+/// The specifiers of nested object references constrain other objects, so they are not walked.
+fn collect_specifiers(node: &dyn AbilityTreeNode, specifiers: &mut Vec<TargetSpecifier>) {
+    for child in node.children().into_iter() {
+        if let Some(specifier) = TargetSpecifier::from_node(child) {
+            if !specifier.is_control() {
+                specifiers.push(specifier);
+            }
+        } else if node_ids::referenced_object(child.node_id()).is_none() {
+            collect_specifiers(child, specifiers);
+        }
+    }
+}
+
+/// Permanent references carry the kind of permanent they refer to, as in "target creature".
+///
+/// When several kinds are allowed, as in "target artifact or enchantment", any permanent is targeted.
+fn refined_object(node: &dyn AbilityTreeNode, object: TargetedObject) -> TargetedObject {
+    let node: &dyn std::any::Any = node;
+    match node
+        .downcast_ref::<PermanentReference>()
+        .map(|reference| &reference.permanent.kind)
+    {
+        Some(PermanentKind::Artifact(_)) => TargetedObject::Artifact,
+        Some(PermanentKind::Battle(_)) => TargetedObject::Battle,
+        Some(PermanentKind::Creature(_)) => TargetedObject::Creature,
+        Some(PermanentKind::Enchantment(_)) => TargetedObject::Enchantment,
+        Some(PermanentKind::Land(_)) => TargetedObject::Land,
+        Some(PermanentKind::Planeswalker(_)) => TargetedObject::Planeswalker,
+        Some(PermanentKind::OneAmong(_) | PermanentKind::Permanent { .. }) | None => object,
+    }
+}
+
+/// Whether a count of targets can be more than a single target.
+fn allows_several_targets(count: &Number) -> bool {
+    match count {
+        Number::Number(number) => number.number > 1,
+        Number::UpTo(number) => number.maximum > 1,
+        Number::OrMore(_) | Number::AnyNumber { .. } | Number::ThatMany { .. } | Number::X(_) => true,
+    }
+}
+
+/// The count of targets for slots that always declare a single target.
+fn single_target() -> Number {
+    Number::Number(crate::ability_tree::number::FixedNumber {
+        number: 1,
+        #[cfg(feature = "spanned_tree")]
+        span: Default::default(),
+    })
+}
+
+/// Player specifiers are enum nodes, that carry their variant as a single dummy terminal child.
+fn targeted_player(node: &dyn AbilityTreeNode) -> Option<TargetedObject> {
+    let child_id = node.children().first()?.node_id();
+    if child_id == node_ids::target_opponent() {
+        Some(TargetedObject::Opponent)
+    } else if child_id == node_ids::target_player() {
+        Some(TargetedObject::Player)
+    } else {
+        None
+    }
+}
+
+/// Node ids of the nodes that can declare targets.
+mod node_ids {
+    use super::NodeKind;
+    use super::TargetedObject;
+    use idris::Idris;
+
+    pub fn target_opponent() -> usize {
+        NodeKind::PlayerSpecifier(crate::ability_tree::player::PlayerSpecifier::TargetOpponent {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        })
+        .id()
+    }
+
+    pub fn target_player() -> usize {
+        NodeKind::PlayerSpecifier(crate::ability_tree::player::PlayerSpecifier::TargetPlayer {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        })
+        .id()
+    }
+
+    pub fn referenced_object(node_id: usize) -> Option<TargetedObject> {
+        [
+            (NodeKind::ArtifactReference.id(), TargetedObject::Artifact),
+            (NodeKind::BattleReference.id(), TargetedObject::Battle),
+            (NodeKind::CardReference.id(), TargetedObject::Card),
+            (NodeKind::CreatureReference.id(), TargetedObject::Creature),
+            (NodeKind::DamageReceiverReference.id(), TargetedObject::DamageReceiver),
+            (NodeKind::EnchantmentReference.id(), TargetedObject::Enchantment),
+            (NodeKind::LandReference.id(), TargetedObject::Land),
+            (NodeKind::PermanentReference.id(), TargetedObject::Permanent),
+            (NodeKind::PlaneswalkerReference.id(), TargetedObject::Planeswalker),
+            (NodeKind::PlayerOrPermanentReference.id(), TargetedObject::PlayerOrPermanent),
+            (NodeKind::SpellReference.id(), TargetedObject::Spell),
+        ]
+        .into_iter()
+        .find_map(|(id, object)| (id == node_id).then_some(object))
+    }
+}
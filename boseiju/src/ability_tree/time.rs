@@ -78,6 +78,10 @@ pub enum ForwardDuration {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
     },
+    UntilEndOfCombat {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
     UntilEndOfTurn {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
@@ -86,6 +90,10 @@ pub enum ForwardDuration {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
     },
+    UntilYourNextTurn {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
 }
 
 impl AbilityTreeNode for ForwardDuration {
@@ -122,8 +130,10 @@ impl AbilityTreeNode for ForwardDuration {
         match self {
             Self::ForAsLongAsItsExiled { span } => *span,
             Self::Forever { span } => *span,
+            Self::UntilEndOfCombat { span } => *span,
             Self::UntilEndOfTurn { span } => *span,
             Self::UntilEndOfYourNextTurn { span } => *span,
+            Self::UntilYourNextTurn { span } => *span,
         }
     }
 }
@@ -132,6 +142,10 @@ impl AbilityTreeNode for ForwardDuration {
 impl IntoToken for ForwardDuration {
     fn try_from_span(span: &crate::lexer::Span) -> Option<Self> {
         match span.text {
+            "until end of combat" => Some(Self::UntilEndOfCombat {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }),
             "until end of turn" => Some(Self::UntilEndOfTurn {
                 #[cfg(feature = "spanned_tree")]
                 span: Default::default(),
@@ -140,6 +154,10 @@ impl IntoToken for ForwardDuration {
                 #[cfg(feature = "spanned_tree")]
                 span: Default::default(),
             }),
+            "until your next turn" => Some(Self::UntilYourNextTurn {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }),
             _ => None,
         }
     }
@@ -160,8 +178,10 @@ impl std::fmt::Display for ForwardDuration {
         match self {
             Self::ForAsLongAsItsExiled { .. } => write!(f, "for as long as it remains exiled"),
             Self::Forever { .. } => write!(f, "forever"),
+            Self::UntilEndOfCombat { .. } => write!(f, "until end of combat"),
             Self::UntilEndOfTurn { .. } => write!(f, "until end of turn"),
             Self::UntilEndOfYourNextTurn { .. } => write!(f, "until the end of your next turn"),
+            Self::UntilYourNextTurn { .. } => write!(f, "until your next turn"),
         }
     }
 }
@@ -171,6 +191,10 @@ impl std::fmt::Display for ForwardDuration {
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum BackwardDuration {
+    LastTurn {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
     /* Fixme: weird one */
     ThisTurn {
         #[cfg(feature = "spanned_tree")]
@@ -210,6 +234,7 @@ impl AbilityTreeNode for BackwardDuration {
     #[cfg(feature = "spanned_tree")]
     fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
         match self {
+            Self::LastTurn { span } => *span,
             Self::ThisTurn { span } => *span,
         }
     }
@@ -219,6 +244,10 @@ impl AbilityTreeNode for BackwardDuration {
 impl IntoToken for BackwardDuration {
     fn try_from_span(span: &crate::lexer::Span) -> Option<Self> {
         match span.text {
+            "last turn" => Some(Self::LastTurn {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }),
             "this turn" => Some(Self::ThisTurn {
                 #[cfg(feature = "spanned_tree")]
                 span: Default::default(),
@@ -241,6 +270,7 @@ impl crate::utils::DummyInit for BackwardDuration {
 impl std::fmt::Display for BackwardDuration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::LastTurn { .. } => write!(f, "last turn"),
             Self::ThisTurn { .. } => write!(f, "this turn"),
         }
     }
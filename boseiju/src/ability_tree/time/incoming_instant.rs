@@ -8,6 +8,8 @@ use crate::ability_tree::MAX_CHILDREN_PER_NODE;
 pub enum IncomingInstant {
     /// A next specific step, for instance, "your next end step"
     NextStepOrPhase(IncomingNextStepOrPhase),
+    /// The next time an event occurs, for instance, "the next time you cast a spell this turn"
+    NextTime(IncomingNextTime),
     /// A step or phase in player the next turn, "end of your next turn"
     StepInNextTurn(IncomingStepInNextTurn),
 }
@@ -22,6 +24,7 @@ impl AbilityTreeNode for IncomingInstant {
         let mut children = arrayvec::ArrayVec::new_const();
         match self {
             Self::NextStepOrPhase(child) => children.push(child as &dyn AbilityTreeNode),
+            Self::NextTime(child) => children.push(child as &dyn AbilityTreeNode),
             Self::StepInNextTurn(child) => children.push(child as &dyn AbilityTreeNode),
         }
         children
@@ -33,6 +36,7 @@ impl AbilityTreeNode for IncomingInstant {
         out.push_final_branch()?;
         match self {
             Self::NextStepOrPhase(child) => child.display(out)?,
+            Self::NextTime(child) => child.display(out)?,
             Self::StepInNextTurn(child) => child.display(out)?,
         }
         out.pop_branch();
@@ -40,13 +44,14 @@ impl AbilityTreeNode for IncomingInstant {
     }
 
     fn node_tag(&self) -> &'static str {
-        "recurrent instant"
+        "incoming instant"
     }
 
     #[cfg(feature = "spanned_tree")]
     fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
         match self {
             Self::NextStepOrPhase(child) => child.node_span(),
+            Self::NextTime(child) => child.node_span(),
             Self::StepInNextTurn(child) => child.node_span(),
         }
     }
@@ -78,14 +83,20 @@ impl AbilityTreeNode for IncomingNextStepOrPhase {
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
         let mut children = arrayvec::ArrayVec::new_const();
         children.push(&self.step_or_phase as &dyn AbilityTreeNode);
+        children.push(&self.owner as &dyn AbilityTreeNode);
         children
     }
 
     fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
         use std::io::Write;
         write!(out, "incoming step or phase:")?;
-        out.push_final_branch()?;
+        out.push_inter_branch()?;
         self.step_or_phase.display(out)?;
+        out.next_final_branch()?;
+        write!(out, "of player:")?;
+        out.push_final_branch()?;
+        self.owner.display(out)?;
+        out.pop_branch();
         out.pop_branch();
         Ok(())
     }
@@ -112,7 +123,7 @@ impl crate::utils::DummyInit for IncomingNextStepOrPhase {
     }
 }
 
-/// An instant represented by the next step or next phase.
+/// An instant represented by a step or a phase of the next turn of a player.
 #[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct IncomingStepInNextTurn {
@@ -131,20 +142,26 @@ impl AbilityTreeNode for IncomingStepInNextTurn {
     fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
         let mut children = arrayvec::ArrayVec::new_const();
         children.push(&self.step_or_phase as &dyn AbilityTreeNode);
+        children.push(&self.owner as &dyn AbilityTreeNode);
         children
     }
 
     fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
         use std::io::Write;
-        write!(out, "incoming step or phase:")?;
-        out.push_final_branch()?;
+        write!(out, "step or phase in next turn:")?;
+        out.push_inter_branch()?;
         self.step_or_phase.display(out)?;
+        out.next_final_branch()?;
+        write!(out, "of player:")?;
+        out.push_final_branch()?;
+        self.owner.display(out)?;
+        out.pop_branch();
         out.pop_branch();
         Ok(())
     }
 
     fn node_tag(&self) -> &'static str {
-        "incoming step or phase instant"
+        "incoming step in next turn instant"
     }
 
     #[cfg(feature = "spanned_tree")]
@@ -164,3 +181,66 @@ impl crate::utils::DummyInit for IncomingStepInNextTurn {
         }
     }
 }
+
+/// An instant represented by the next occurrence of an event.
+///
+/// The event can be limited to the current turn, as in "the next time you cast a spell this turn".
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncomingNextTime {
+    pub event: Box<crate::ability_tree::event::Event>,
+    pub this_turn_only: bool,
+    #[cfg(feature = "spanned_tree")]
+    pub span: crate::ability_tree::span::TreeSpan,
+}
+
+impl AbilityTreeNode for IncomingNextTime {
+    fn node_id(&self) -> usize {
+        use idris::Idris;
+        crate::ability_tree::NodeKind::IncomingNextTime.id()
+    }
+
+    fn children(&self) -> arrayvec::ArrayVec<&dyn AbilityTreeNode, MAX_CHILDREN_PER_NODE> {
+        let mut children = arrayvec::ArrayVec::new_const();
+        children.push(self.event.as_ref() as &dyn AbilityTreeNode);
+        children
+    }
+
+    fn data(&self) -> Option<crate::ability_tree::AbTreeNodeData> {
+        Some(crate::ability_tree::AbTreeNodeData::Boolean {
+            value: self.this_turn_only,
+        })
+    }
+
+    fn display(&self, out: &mut crate::utils::TreeFormatter<'_>) -> std::io::Result<()> {
+        use std::io::Write;
+        write!(out, "next time:")?;
+        out.push_inter_branch()?;
+        self.event.display(out)?;
+        out.next_final_branch()?;
+        write!(out, "this turn only: {}", self.this_turn_only)?;
+        out.pop_branch();
+        Ok(())
+    }
+
+    fn node_tag(&self) -> &'static str {
+        "incoming next time instant"
+    }
+
+    #[cfg(feature = "spanned_tree")]
+    fn node_span(&self) -> crate::ability_tree::span::TreeSpan {
+        self.span
+    }
+}
+
+#[cfg(feature = "parser")]
+impl crate::utils::DummyInit for IncomingNextTime {
+    fn dummy_init() -> Self {
+        Self {
+            event: Box::new(crate::utils::dummy()),
+            this_turn_only: false,
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        }
+    }
+}
@@ -33,6 +33,12 @@ pub enum NodeKind {
     AbilityWordMarkerId,
     ActivatedAbility,
     AddManaImperative,
+    AdditionalCastingCost,
+    AggregatedProperty(crate::ability_tree::number::AggregatedProperty),
+    AggregatedPropertyIdMarker,
+    Aggregation(crate::ability_tree::number::Aggregation),
+    AggregationIdMarker,
+    AlternativeCastingCost,
     AnyTarget,
     Artifact,
     ArtifactBasicKind,
@@ -41,6 +47,12 @@ pub enum NodeKind {
     ArtifactSpecifier,
     ArtifactSubtypeSpecifier,
     AttachedObject,
+    Battle,
+    BattleBasicKind,
+    BattleKind,
+    BattleReference,
+    BattleSpecifier,
+    BattleSubtypeSpecifier,
     Card,
     CardBasicKind,
     CardCharacteristicSpecifier,
@@ -56,6 +68,7 @@ pub enum NodeKind {
     CardToughnessPropertySpecifier,
     CardTypeSpecifier,
     CasterSpecifier,
+    ChangeControlEffect,
     ChangeZoneImperative,
     CharacteristicDefiningAbility,
     ChooseImperative,
@@ -66,8 +79,13 @@ pub enum NodeKind {
     ConditionalIf,
     ConditionalImperative,
     ConditionalUnless,
+    ConditionCardsInZone,
     ConditionCreatureMatchSpecifier,
     ConditionEventOccured,
+    ConditionNumberComparison,
+    ConditionPermanentHasState,
+    ConditionPlayerLifeTotal,
+    ConditionSpellsCastThisTurn,
     ConditionStackObjectHasState,
     ContinuousEffect,
     ContinuousEffectEventCantHappen,
@@ -88,6 +106,8 @@ pub enum NodeKind {
     CounterOnPermanent,
     /* Fixme: better name for this one */
     CounterOnPermanentReplacement,
+    CountersOnObjectSpecifier,
+    CountersPutOnPermanentAction,
     CreateTokensEvent,
     Creature,
     CreatureBasicKind,
@@ -95,11 +115,13 @@ pub enum NodeKind {
     CreatureActionEvent,
     CreatureAttacksAction,
     CreatureBlocksAction,
+    CreatureCantBeBlockedExceptBy,
     CreatureCantDoAction,
     CreatureCharacteristicSpecifier,
     CreatureDealsDamageAction,
     CreatureDiesAction,
     CreatureGainsStateEvent,
+    CreatureIsDealtDamageAction,
     CreatureKind,
     CreaturePerformsActionEvent,
     CreaturePowerSpecifier,
@@ -111,10 +133,16 @@ pub enum NodeKind {
     DamageReceiver,
     DamageReceiverKind,
     DamageReceiverReference,
+    DamagePreventionEffect,
+    DamageRedirectionEffect,
     DamagesDealt,
     DealsDamageImperative,
+    DefinedCharacteristic(crate::ability_tree::ability::statik::characteristic_defining_ability::DefinedCharacteristic),
+    DefinedCharacteristicIdMarker,
     DelayedTriggerAbility,
     DestroyImperative,
+    Devotion,
+    DoublingEffect,
     EffectEventSource,
     Enchantment,
     EnchantmentBasicKind,
@@ -130,7 +158,7 @@ pub enum NodeKind {
     EtbWithCounters,
     EtbWithState,
     Event,
-    EventReplacement,
+    EventReplacementEffect,
     EventSource,
     ExileFollowUp,
     ExileFollowUpReturn,
@@ -152,6 +180,7 @@ pub enum NodeKind {
     ImperativeList,
     IncomingInstant,
     IncomingNextStepOrPhase,
+    IncomingNextTime,
     IncomingStepInNextTurn,
     Instant,
     KeywordAbility(KeywordAbilityNodeKind),
@@ -167,31 +196,48 @@ pub enum NodeKind {
     LandSubtypeSpecifier,
     Layout(LayoutNodeKind),
     LifeGainedEvent,
+    LifeTotal,
     LoseLifeImperative,
+    LoyaltyAbility,
+    LoyaltyCost,
+    LoyaltyCostMinus,
+    LoyaltyCostPlus,
+    LoyaltyCostZero,
     ManaCost,
+    ManaSpendRestriction,
     ManaToAdd,
     ManaToAddKindAnyColor,
     ManaToAddOfAnyColor,
     ManaToAddSymbols,
     MayAbility,
+    ModalMode,
     ModifyObjectEffect,
     ModifyRuleEffect,
     MtgData(MtgDataNodeKind),
     MultipleObjectReferences,
     NextCastedSpellSpecifier,
     Number(crate::ability_tree::number::Number),
+    NumberComparison(crate::ability_tree::conditional::NumberComparison),
+    NumberComparisonIdMarker,
     NumberIdMarker,
+    NumberOfCardsInZone,
+    NumberOfCounters,
     NumberOfPermanents,
     NumberOfResolutions(crate::ability_tree::conditional::ConditionNumberOfResolutions),
     NumberOfResolutionsIdMarker,
+    NumberOfTimesKicked,
     ObjectAbilitiesModification,
     ObjectBecomesStateEvent,
     ObjectCharacteristicModification,
+    ObjectColorChange,
     ObjectGainAbility,
     ObjectGainsStateEvent,
+    ObjectIsEveryCreatureType,
+    ObjectLoseAllAbilities,
     ObjectReference,
     ObjectSpecifier,
     ObjectSpecifiers,
+    ObjectTypeChange,
     OneAmong,
     OwnedZone,
     OwnerSpecifier,
@@ -201,6 +247,7 @@ pub enum NodeKind {
     Permanent,
     PermanentAction,
     PermanentBasicKind,
+    PermanentBecomesTargetAction,
     PermanentEtbAction,
     PermanentGainsStateEvent,
     PermanentKind,
@@ -208,10 +255,14 @@ pub enum NodeKind {
     PermanentPerformsActionEvent,
     PermanentReference,
     PermanentSpecifier,
+    PermanentEnchantedState,
     PermanentState,
+    PermanentTappedForManaAction,
     PermanentTappedState,
     PermanentTargetedState,
     PermanentUntappedState,
+    PermanentsAggregate,
+    Planeswalker,
     PlaneswalkerBasicKind,
     PlaneswalkerKind,
     PlaneswalkerReference,
@@ -222,8 +273,16 @@ pub enum NodeKind {
     PlayerAttacksAction,
     PlayerCastsSpellAction,
     PlayerControlsPermanent,
+    PlayerCreatesTokensAction,
+    PlayerDrawsCardAction,
     PlayerEventSource,
+    PlayerGainsLifeAction,
+    PlayerLosesLifeAction,
+    PlayerOrPermanent,
+    PlayerOrPermanentKind,
+    PlayerOrPermanentReference,
     PlayerPerformsActionEvent,
+    PlayerSacrificesAction,
     PlayerSpecifier(crate::ability_tree::player::PlayerSpecifier),
     PlayerSpecifierIdMarker,
     PlayerSpecifierObjectController,
@@ -237,6 +296,7 @@ pub enum NodeKind {
     PreviouslyMentionned,
     PreviouslyMentionnedCounter,
     PreviouslyMentionnedToken,
+    ProtectorSpecifier,
     PutCounterOnPermanentEvent,
     PutCountersImperative,
     RecurrentInstant,
@@ -249,6 +309,7 @@ pub enum NodeKind {
     SacrificeImperative,
     SearchImperative,
     SpecifiedArtifact,
+    SpecifiedBattle,
     SpecifiedCard,
     SpecifiedCreature,
     SpecifiedEnchantment,
@@ -290,6 +351,7 @@ pub enum NodeKind {
     XDefinition,
     XFromCost,
     XFromGameState,
+    ZoneChangeEvent,
     ZoneReference(crate::ability_tree::zone::ZoneReference),
     ZoneReferenceIdMarker,
 }
@@ -345,15 +407,20 @@ pub enum KeywordAbilityNodeKind {
     Disguise,
     Echo,
     Enchant,
+    Entwine,
     Equip,
+    Escalate,
+    Evoke,
     Fabricate,
     Flashback,
     Freerunning,
     Kicker,
     Megamorph,
     Morph,
+    Multikicker,
     Ninjutsu,
     Outlast,
+    Overload,
     Prototype,
     Rampage,
     Reconfigure,
@@ -113,6 +113,23 @@ impl TypeLine {
         result
     }
 
+    pub fn creature(
+        creature_subtypes: &[mtg_data::CreatureType],
+        #[cfg(feature = "spanned_tree")] span: crate::ability_tree::span::TreeSpan,
+    ) -> Self {
+        use idris::Idris;
+        let mut result = Self::empty();
+        result.card_types[mtg_data::CardType::Creature.id()] = true;
+        for subtype in creature_subtypes.iter() {
+            result.creature[subtype.id()] = true;
+        }
+        #[cfg(feature = "spanned_tree")]
+        {
+            result.span = span;
+        }
+        result
+    }
+
     pub fn artifact_token(
         artifact_subtypes: &[mtg_data::ArtifactType],
         #[cfg(feature = "spanned_tree")] span: crate::ability_tree::span::TreeSpan,
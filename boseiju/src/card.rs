@@ -1,5 +1,7 @@
+pub mod casting_cost;
 pub mod layout;
 pub mod legalities;
+pub mod mana_production;
 
 /// A parsed card.
 ///
@@ -34,6 +36,40 @@ impl Card {
     pub fn mana_value(&self) -> usize {
         self.layout.mana_value()
     }
+
+    /// Every way the card can be cast, with what each of them costs.
+    ///
+    /// The first casting cost is always the normal one, paying the mana cost of the card.
+    pub fn casting_costs(&self) -> Vec<casting_cost::CastingCost> {
+        casting_cost::casting_costs(self.layout.mana_cost(), self.layout.abilities())
+    }
+
+    /// All the mana the card can produce, and the abilities producing it.
+    ///
+    /// Abilities are marked as mana abilities following rule 605 of the comprehensive rules.
+    pub fn mana_production(&self) -> mana_production::ManaProduction {
+        mana_production::mana_production(self.layout.abilities())
+    }
+
+    /// The printed power of the card, if it has one.
+    pub fn power(&self) -> Option<&crate::ability_tree::card_layout::PrintedCharacteristic> {
+        self.layout.characteristics()?.power.as_ref()
+    }
+
+    /// The printed toughness of the card, if it has one.
+    pub fn toughness(&self) -> Option<&crate::ability_tree::card_layout::PrintedCharacteristic> {
+        self.layout.characteristics()?.toughness.as_ref()
+    }
+
+    /// The printed loyalty of the card, if it has one.
+    pub fn loyalty(&self) -> Option<&crate::ability_tree::card_layout::PrintedCharacteristic> {
+        self.layout.characteristics()?.loyalty.as_ref()
+    }
+
+    /// The printed defense of the card, if it has one.
+    pub fn defense(&self) -> Option<&crate::ability_tree::card_layout::PrintedCharacteristic> {
+        self.layout.characteristics()?.defense.as_ref()
+    }
 }
 
 #[cfg(feature = "parser")]
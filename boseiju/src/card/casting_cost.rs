@@ -0,0 +1,277 @@
+use crate::ability_tree::ability::Ability;
+use crate::ability_tree::ability::WrittenAbility;
+use crate::ability_tree::ability::keyword_ability::ExpandedKeywordAbility;
+use crate::ability_tree::ability::statik::StaticAbilityKind;
+use crate::ability_tree::cost::Cost;
+use crate::ability_tree::terminals::ManaCost;
+
+/// One way to cast a card, with everything that needs to be paid to do so.
+///
+/// Casting a spell is paying its mana cost, or an alternative cost replacing it,
+/// as well as all of its additional costs. Optional costs can be paid on top of that,
+/// and the total is then increased or reduced by the cost modifications.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CastingCost {
+    pub method: CastingMethod,
+    /// The mana cost to pay, if the casting method requires paying it.
+    pub mana_cost: Option<ManaCost>,
+    /// Costs that must be paid on top of the mana cost or alternative cost.
+    pub additional_costs: Vec<Cost>,
+    /// Costs that can be paid on top of the others, such as kicker.
+    pub optional_costs: Vec<OptionalCost>,
+    /// Increases and reductions the card applies to its own cost.
+    pub cost_modifications: Vec<crate::ability_tree::ability::statik::cost_modification_effect::CostModification>,
+}
+
+/// How a card is being cast.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CastingMethod {
+    /// Casting the card by paying its mana cost.
+    Normal,
+    /// Casting the card by paying an alternative cost instead of its mana cost.
+    Alternative(AlternativeCost),
+    /// Casting the card from another zone, as allowed by one of its abilities.
+    FromZone(crate::ability_tree::zone::ZoneReference),
+}
+
+/// A cost paid rather than the mana cost of a spell.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlternativeCost {
+    pub kind: AlternativeCostKind,
+    pub cost: Cost,
+    /// The zone the card is cast from when paying this cost, if not from the hand.
+    ///
+    /// Flashback lets its owner cast the card from their graveyard.
+    pub from_zone: Option<crate::ability_tree::zone::ZoneReference>,
+}
+
+/// Where an alternative cost comes from.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AlternativeCostKind {
+    Bestow,
+    Blitz,
+    Dash,
+    Evoke,
+    Flashback,
+    Freerunning,
+    Overload,
+    Prototype,
+    /// "You may <cost> rather than pay this spell's mana cost."
+    RatherThanManaCost,
+    Surge,
+    Warp,
+}
+
+/// A cost that may be paid when casting a spell, for an additional effect.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionalCost {
+    pub kind: OptionalCostKind,
+    /// The cost to pay, or none when the keyword defines it, as bargain does.
+    pub cost: Option<Cost>,
+}
+
+/// Where an optional cost comes from.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OptionalCostKind {
+    Bargain,
+    Entwine,
+    Escalate,
+    Kicker,
+    Multikicker,
+    /// The cost of a mode of a spell with spree.
+    SpreeMode,
+}
+
+/// Lists every way a card can be cast from its mana cost and abilities.
+pub fn casting_costs(mana_cost: Option<&ManaCost>, abilities: Option<&crate::AbilityTree>) -> Vec<CastingCost> {
+    let abilities = abilities.map(|tree| tree.abilities.as_slice()).unwrap_or(&[]);
+
+    let mut alternative_costs = Vec::new();
+    let mut alternative_zones = Vec::new();
+    let mut additional_costs = Vec::new();
+    let mut optional_costs = Vec::new();
+    let mut cost_modifications = Vec::new();
+
+    for ability in abilities.iter() {
+        match ability {
+            Ability::KeywordAbility(keyword_ability) => {
+                let alternative = |kind: AlternativeCostKind, cost: &Cost| AlternativeCost {
+                    kind,
+                    cost: cost.clone(),
+                    from_zone: None,
+                };
+                let optional = |kind: OptionalCostKind, cost: &Cost| OptionalCost {
+                    kind,
+                    cost: Some(cost.clone()),
+                };
+                match &keyword_ability.keyword {
+                    ExpandedKeywordAbility::Bestow(bestow) => {
+                        alternative_costs.push(alternative(AlternativeCostKind::Bestow, &bestow.cost))
+                    }
+                    ExpandedKeywordAbility::Blitz(blitz) => {
+                        alternative_costs.push(alternative(AlternativeCostKind::Blitz, &blitz.cost))
+                    }
+                    ExpandedKeywordAbility::Dash(dash) => {
+                        alternative_costs.push(alternative(AlternativeCostKind::Dash, &dash.cost))
+                    }
+                    ExpandedKeywordAbility::Evoke(evoke) => {
+                        alternative_costs.push(alternative(AlternativeCostKind::Evoke, &evoke.cost))
+                    }
+                    ExpandedKeywordAbility::Flashback(flashback) => alternative_costs.push(AlternativeCost {
+                        from_zone: Some(your_graveyard(
+                            #[cfg(feature = "spanned_tree")]
+                            flashback.span,
+                        )),
+                        ..alternative(AlternativeCostKind::Flashback, &flashback.cost)
+                    }),
+                    ExpandedKeywordAbility::Freerunning(freerunning) => {
+                        alternative_costs.push(alternative(AlternativeCostKind::Freerunning, &freerunning.cost))
+                    }
+                    ExpandedKeywordAbility::Overload(overload) => {
+                        alternative_costs.push(alternative(AlternativeCostKind::Overload, &overload.cost))
+                    }
+                    ExpandedKeywordAbility::Prototype(prototype) => {
+                        alternative_costs.push(alternative(AlternativeCostKind::Prototype, &prototype.cost))
+                    }
+                    ExpandedKeywordAbility::Surge(surge) => {
+                        alternative_costs.push(alternative(AlternativeCostKind::Surge, &surge.cost))
+                    }
+                    ExpandedKeywordAbility::Warp(warp) => {
+                        alternative_costs.push(alternative(AlternativeCostKind::Warp, &warp.cost))
+                    }
+                    ExpandedKeywordAbility::Entwine(entwine) => {
+                        optional_costs.push(optional(OptionalCostKind::Entwine, &entwine.cost))
+                    }
+                    ExpandedKeywordAbility::Escalate(escalate) => {
+                        optional_costs.push(optional(OptionalCostKind::Escalate, &escalate.cost))
+                    }
+                    ExpandedKeywordAbility::Kicker(kicker) => {
+                        optional_costs.push(optional(OptionalCostKind::Kicker, &kicker.cost))
+                    }
+                    ExpandedKeywordAbility::Multikicker(multikicker) => {
+                        optional_costs.push(optional(OptionalCostKind::Multikicker, &multikicker.cost))
+                    }
+                    ExpandedKeywordAbility::Standalone(standalone) => match standalone.keyword_ability {
+                        crate::ability_tree::terminals::StandaloneKeywordAbility::Bargain => optional_costs.push(OptionalCost {
+                            kind: OptionalCostKind::Bargain,
+                            cost: None,
+                        }),
+                        crate::ability_tree::terminals::StandaloneKeywordAbility::Spree => {
+                            optional_costs.extend(spree_mode_costs(&keyword_ability.ability))
+                        }
+                        _ => {}
+                    },
+                    _ => {}
+                }
+            }
+            Ability::Written(WrittenAbility::Static(static_ability)) => match &static_ability.kind {
+                StaticAbilityKind::AdditionalCastingCost(additional) => additional_costs.push(additional.cost.clone()),
+                StaticAbilityKind::AlternativeCastingCost(alternative) => alternative_costs.push(AlternativeCost {
+                    kind: AlternativeCostKind::RatherThanManaCost,
+                    cost: alternative.cost.clone(),
+                    from_zone: None,
+                }),
+                StaticAbilityKind::AlternativeCastingPermissions(permission) => {
+                    if let crate::ability_tree::object::Card::SelfReferencing(_) = permission.object {
+                        alternative_zones.push((permission.from_zone.clone(), permission.additional_cost.clone()));
+                    }
+                }
+                StaticAbilityKind::CostModificationEffect(modification) => {
+                    if let crate::ability_tree::object::Spell::SelfReferencing(_) = modification.applies_to {
+                        cost_modifications.push(modification.modification.clone());
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    let casting_cost = |method: CastingMethod, mana_cost: Option<&ManaCost>, extra_cost: Option<Cost>| CastingCost {
+        method,
+        mana_cost: mana_cost.cloned(),
+        additional_costs: additional_costs.iter().cloned().chain(extra_cost).collect(),
+        optional_costs: optional_costs.clone(),
+        cost_modifications: cost_modifications.clone(),
+    };
+
+    let mut result = Vec::with_capacity(1 + alternative_costs.len() + alternative_zones.len());
+    result.push(casting_cost(CastingMethod::Normal, mana_cost, None));
+    for alternative in alternative_costs.into_iter() {
+        /* The mana to pay is the one of the alternative cost, such as the {1}{R} of "flashback {1}{R}" */
+        let mana_cost = alternative_mana_cost(&alternative.cost);
+        result.push(casting_cost(
+            CastingMethod::Alternative(alternative),
+            mana_cost.as_ref(),
+            None,
+        ));
+    }
+    for (zone, extra_cost) in alternative_zones.into_iter() {
+        result.push(casting_cost(CastingMethod::FromZone(zone), mana_cost, extra_cost));
+    }
+    result
+}
+
+/// The mana paid as part of an alternative cost, if any.
+fn alternative_mana_cost(cost: &Cost) -> Option<ManaCost> {
+    use crate::ability_tree::imperative::ImperativeKind;
+
+    cost.costs.iter().find_map(|imperative| match &imperative.kind {
+        ImperativeKind::PayMana(pay_mana) => Some(pay_mana.amount.clone()),
+        _ => None,
+    })
+}
+
+/// The graveyard of the player casting the card, where flashback casts it from.
+fn your_graveyard(
+    #[cfg(feature = "spanned_tree")] span: crate::ability_tree::span::TreeSpan,
+) -> crate::ability_tree::zone::ZoneReference {
+    use crate::ability_tree::zone;
+
+    zone::ZoneReference::OwnedZone(zone::OwnedZone {
+        zone: zone::OwnableZone::Graveyard {
+            #[cfg(feature = "spanned_tree")]
+            span,
+        },
+        owner: crate::ability_tree::player::PlayerSpecifier::You {
+            #[cfg(feature = "spanned_tree")]
+            span,
+        },
+        #[cfg(feature = "spanned_tree")]
+        span,
+    })
+}
+
+/// The costs of the modes of a spell with spree, "+ {1} — <effect>".
+fn spree_mode_costs(ability: &WrittenAbility) -> Vec<OptionalCost> {
+    use crate::ability_tree::imperative::ImperativeKind;
+    use crate::ability_tree::statement::Statement;
+
+    let WrittenAbility::Spell(spell) = ability else {
+        return Vec::new();
+    };
+    spell
+        .effects
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::Imperatives(imperatives) => Some(imperatives.imperatives.iter()),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|imperative| match &imperative.kind {
+            ImperativeKind::Modal(modal) => Some(modal.modes.iter()),
+            _ => None,
+        })
+        .flatten()
+        .map(|mode| OptionalCost {
+            kind: OptionalCostKind::SpreeMode,
+            cost: mode.additional_cost.clone(),
+        })
+        .collect()
+}
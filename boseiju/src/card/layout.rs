@@ -17,7 +17,7 @@ pub enum Layout {
     Adventure {},
     Mutate {},
     Prototype {},
-    Battle {},
+    Battle { layout: BattleLayout },
     Planar {},
     Scheme {},
     Vanguard {},
@@ -54,7 +54,7 @@ impl Layout {
             Self::Adventure {} => crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::empty_node(),
             Self::Mutate {} => crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::empty_node(),
             Self::Prototype {} => crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::empty_node(),
-            Self::Battle {} => crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::empty_node(),
+            Self::Battle { layout } => layout,
             Self::Planar {} => crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::empty_node(),
             Self::Scheme {} => crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::empty_node(),
             Self::Vanguard {} => crate::ability_tree::dummy_terminal::TreeNodeDummyTerminal::empty_node(),
@@ -79,7 +79,7 @@ impl Layout {
             Self::Adventure {} => 0,
             Self::Mutate {} => 0,
             Self::Prototype {} => 0,
-            Self::Battle {} => 0,
+            Self::Battle { layout } => layout.mana_value(),
             Self::Planar {} => 0,
             Self::Scheme {} => 0,
             Self::Vanguard {} => 0,
@@ -89,6 +89,81 @@ impl Layout {
         }
     }
 
+    pub fn characteristics(&self) -> Option<&crate::ability_tree::card_layout::PrintedCharacteristics> {
+        match self {
+            Self::Normal { layout } => layout.characteristics(),
+            Self::Split {} => None,
+            Self::Flip {} => None,
+            Self::Transform {} => None,
+            Self::ModalDfc {} => None,
+            Self::Meld {} => None,
+            Self::Leveler {} => None,
+            Self::Class {} => None,
+            Self::Case {} => None,
+            Self::Saga { layout } => layout.characteristics(),
+            Self::Adventure {} => None,
+            Self::Mutate {} => None,
+            Self::Prototype {} => None,
+            Self::Battle { layout } => layout.characteristics(),
+            Self::Planar {} => None,
+            Self::Scheme {} => None,
+            Self::Vanguard {} => None,
+            Self::Token { layout } => layout.characteristics(),
+            Self::DoubleFaced {} => None,
+            Self::Emblem {} => None,
+        }
+    }
+
+    pub fn mana_cost(&self) -> Option<&crate::ability_tree::terminals::ManaCost> {
+        match self {
+            Self::Normal { layout } => layout.mana_cost(),
+            Self::Split {} => None,
+            Self::Flip {} => None,
+            Self::Transform {} => None,
+            Self::ModalDfc {} => None,
+            Self::Meld {} => None,
+            Self::Leveler {} => None,
+            Self::Class {} => None,
+            Self::Case {} => None,
+            Self::Saga { layout } => layout.mana_cost(),
+            Self::Adventure {} => None,
+            Self::Mutate {} => None,
+            Self::Prototype {} => None,
+            Self::Battle { layout } => layout.mana_cost(),
+            Self::Planar {} => None,
+            Self::Scheme {} => None,
+            Self::Vanguard {} => None,
+            Self::Token { layout } => layout.mana_cost(),
+            Self::DoubleFaced {} => None,
+            Self::Emblem {} => None,
+        }
+    }
+
+    pub fn abilities(&self) -> Option<&crate::AbilityTree> {
+        match self {
+            Self::Normal { layout } => layout.abilities(),
+            Self::Split {} => None,
+            Self::Flip {} => None,
+            Self::Transform {} => None,
+            Self::ModalDfc {} => None,
+            Self::Meld {} => None,
+            Self::Leveler {} => None,
+            Self::Class {} => None,
+            Self::Case {} => None,
+            Self::Saga { layout } => layout.abilities(),
+            Self::Adventure {} => None,
+            Self::Mutate {} => None,
+            Self::Prototype {} => None,
+            Self::Battle { layout } => layout.abilities(),
+            Self::Planar {} => None,
+            Self::Scheme {} => None,
+            Self::Vanguard {} => None,
+            Self::Token { layout } => layout.abilities(),
+            Self::DoubleFaced {} => None,
+            Self::Emblem {} => None,
+        }
+    }
+
     pub fn card_types(&self) -> crate::ability_tree::type_line::SimplifiedCardTypes {
         match self {
             Self::Normal { layout } => layout.card_types(),
@@ -104,7 +179,7 @@ impl Layout {
             Self::Adventure {} => Default::default(),
             Self::Mutate {} => Default::default(),
             Self::Prototype {} => Default::default(),
-            Self::Battle {} => Default::default(),
+            Self::Battle { layout } => layout.card_types(),
             Self::Planar {} => Default::default(),
             Self::Scheme {} => Default::default(),
             Self::Vanguard {} => Default::default(),
@@ -129,6 +204,9 @@ impl TryFrom<&mtg_cardbase::Card> for Layout {
             "saga" => Ok(Self::Saga {
                 layout: SagaLayout::from_raw_card(raw_card)?,
             }),
+            "battle" => Ok(Self::Battle {
+                layout: BattleLayout::from_raw_card(raw_card)?,
+            }),
             other => Err(format!("Invalid layout in card: {other}")),
         }
     }
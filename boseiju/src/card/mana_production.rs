@@ -0,0 +1,165 @@
+use crate::ability_tree::ability::Ability;
+use crate::ability_tree::ability::WrittenAbility;
+use crate::ability_tree::ability::spell::SpellAbility;
+use crate::ability_tree::cost::Cost;
+use crate::ability_tree::imperative::AddManaImperative;
+use crate::ability_tree::imperative::ManaSpendRestriction;
+use crate::ability_tree::imperative::ManaToAdd;
+
+/// Everything a card can add to a mana pool, through its abilities.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManaProduction {
+    pub sources: Vec<ManaSource>,
+}
+
+impl ManaProduction {
+    /// Whether the card has at least one mana ability.
+    pub fn has_mana_ability(&self) -> bool {
+        self.sources.iter().any(|source| source.kind != ManaSourceKind::Other)
+    }
+
+    /// All the kinds of mana the card can produce, sorted and without duplicates.
+    ///
+    /// This matches the "produced_mana" field of Scryfall, where colorless mana is a kind of its own.
+    pub fn produced_colors(&self) -> Vec<mtg_data::Color> {
+        let mut colors: Vec<_> = self
+            .sources
+            .iter()
+            .flat_map(|source| source.possibilities.iter())
+            .flat_map(|possibility| possibility.colors.iter().copied())
+            .collect();
+        colors.sort();
+        colors.dedup();
+        colors
+    }
+}
+
+/// A single ability of a card that adds mana.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManaSource {
+    pub kind: ManaSourceKind,
+    /// The cost to activate the ability, for activated abilities.
+    pub cost: Option<Cost>,
+    /// The different mana the ability can add, only one of them is added.
+    pub possibilities: Vec<ProducedMana>,
+    /// The restriction on how the added mana can be spent, if any.
+    pub spend_restriction: Option<ManaSpendRestriction>,
+}
+
+/// What kind of ability adds the mana.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ManaSourceKind {
+    /// An activated mana ability, see [CR 605.1a].
+    ActivatedManaAbility,
+    /// A triggered mana ability, see [CR 605.1b].
+    TriggeredManaAbility,
+    /// Any other ability that adds mana, such as spell abilities or abilities with targets.
+    Other,
+}
+
+/// Some mana added by an ability.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProducedMana {
+    /// The kinds of mana that can be added, colorless included.
+    pub colors: Vec<mtg_data::Color>,
+    /// Whether the mana can be of any color, in which case all five colors are listed.
+    pub any_color: bool,
+    /// The amount of mana added, if it is a fixed amount.
+    pub amount: Option<usize>,
+}
+
+impl From<&ManaToAdd> for ProducedMana {
+    fn from(mana: &ManaToAdd) -> Self {
+        use crate::ability_tree::number::Number;
+        use crate::ability_tree::terminals::Mana;
+
+        match mana {
+            ManaToAdd::AnyColor(any_color) => ProducedMana {
+                colors: vec![
+                    mtg_data::Color::White,
+                    mtg_data::Color::Blue,
+                    mtg_data::Color::Black,
+                    mtg_data::Color::Red,
+                    mtg_data::Color::Green,
+                ],
+                any_color: true,
+                amount: match &any_color.amount {
+                    Number::Number(number) => Some(number.number as usize),
+                    _ => None,
+                },
+            },
+            ManaToAdd::Symbols(symbols) => {
+                let mut colors = Vec::new();
+                let mut amount = Some(0);
+                for symbol in symbols.symbols.iter() {
+                    match symbol {
+                        Mana::Colored(colored) => colors.push(colored.mana.color),
+                        Mana::X { .. } => amount = None,
+                        _ => {}
+                    }
+                    amount = amount.map(|amount| amount + symbol.mana_value());
+                }
+                colors.sort();
+                colors.dedup();
+                ProducedMana {
+                    colors,
+                    any_color: false,
+                    amount,
+                }
+            }
+        }
+    }
+}
+
+/// Lists all the abilities of a card that add mana.
+pub fn mana_production(abilities: Option<&crate::AbilityTree>) -> ManaProduction {
+    let abilities = abilities.map(|tree| tree.abilities.as_slice()).unwrap_or(&[]);
+
+    let mut sources = Vec::new();
+    for ability in abilities.iter() {
+        let written = match ability {
+            Ability::Written(written) => written,
+            Ability::KeywordAbility(keyword_ability) => &keyword_ability.ability,
+            Ability::AbilityWord(ability_word) => &ability_word.ability,
+        };
+        match written {
+            WrittenAbility::Activated(activated) => {
+                let kind = match activated.is_mana_ability() {
+                    true => ManaSourceKind::ActivatedManaAbility,
+                    false => ManaSourceKind::Other,
+                };
+                push_sources(&mut sources, kind, Some(&activated.cost), &activated.effect);
+            }
+            WrittenAbility::Triggered(triggered) => {
+                let kind = match triggered.is_mana_ability() {
+                    true => ManaSourceKind::TriggeredManaAbility,
+                    false => ManaSourceKind::Other,
+                };
+                push_sources(&mut sources, kind, None, &triggered.effect);
+            }
+            WrittenAbility::Spell(spell) => push_sources(&mut sources, ManaSourceKind::Other, None, spell),
+            WrittenAbility::Loyalty(loyalty) => push_sources(&mut sources, ManaSourceKind::Other, None, &loyalty.effect),
+            WrittenAbility::Static(_) => {}
+        }
+    }
+
+    ManaProduction { sources }
+}
+
+fn push_sources(sources: &mut Vec<ManaSource>, kind: ManaSourceKind, cost: Option<&Cost>, effect: &SpellAbility) {
+    sources.extend(
+        effect
+            .added_mana()
+            .into_iter()
+            .map(|add_mana: &AddManaImperative| ManaSource {
+                kind,
+                cost: cost.cloned(),
+                possibilities: add_mana.possibilities.iter().map(ProducedMana::from).collect(),
+                spend_restriction: add_mana.spend_restriction.clone(),
+            }),
+    );
+}
@@ -1,14 +1,28 @@
+mod card_name;
 mod error;
 mod span;
 pub mod tokens;
 
+pub use card_name::{CardName, NameForm, NameSubstitution};
 pub use error::LexerError;
 pub use span::Span;
 pub use tokens::IntoToken;
 
 /// Preprocess a card oracle text to properly lex it.
 pub fn preprocess(card_name: &str, oracle_text: &str) -> String {
-    let card_name = card_name.to_lowercase();
+    preprocess_card(&CardName::new(card_name), oracle_text).text
+}
+
+/// An oracle text that was preprocessed, ready to be lexed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreprocessedText {
+    pub text: String,
+    /// The card names that were replaced by "~" in the text.
+    pub name_substitutions: Vec<NameSubstitution>,
+}
+
+/// Preprocess a card oracle text to properly lex it, using all the known names of the card.
+pub fn preprocess_card(card_name: &CardName, oracle_text: &str) -> PreprocessedText {
     let result = oracle_text.to_lowercase();
 
     /* replace all raw unicode char points by they values */
@@ -29,11 +43,14 @@ pub fn preprocess(card_name: &str, oracle_text: &str) -> String {
 
     /* Actual text modifications preprocessing */
     let result = remove_comments(&result);
-    let result = replace_name(&card_name, &result);
     let result = result.replace("\\n", "\n");
+    let (result, name_substitutions) = card_name.replace_in(&result);
     let result = result.trim().to_string();
 
-    result
+    PreprocessedText {
+        text: result,
+        name_substitutions,
+    }
 }
 
 /// Remove all text within parenthesis in the given source, and returns the newly built string.
@@ -63,27 +80,25 @@ fn remove_parens<I: Iterator<Item = char>>(chars: &mut I) {
     }
 }
 
-fn replace_name(card_name: &str, lowercase_oracle_text: &str) -> String {
-    let card_name_lowercase = card_name.to_ascii_lowercase();
-    let card_name_without_epithet = card_name_lowercase.split(',').next();
-
-    let result = lowercase_oracle_text;
-    let result = result.replace(&card_name_lowercase, "~");
-    let result = match card_name_without_epithet {
-        Some(card_name) => result.replace(card_name, "~"),
-        None => result,
-    };
-
-    result
-}
-
 /// Create a vec of Terminals from a string. Can fail, and will return an error if it does.
 pub fn lex(input: &str) -> Result<Vec<tokens::Token>, error::LexerError> {
+    let tokens = lex_range(input, 0..input.len())?;
+    Ok(tokens.into_iter().map(|(token, _)| token).collect())
+}
+
+/// Lex only the given byte range of the input, along with the byte range of each token.
+///
+/// Spans and errors are positions in the whole input, so the range can be lexed on its own
+/// and its tokens used along the tokens of the rest of the input.
+pub(crate) fn lex_range(
+    input: &str,
+    range: std::ops::Range<usize>,
+) -> Result<Vec<(tokens::Token, std::ops::Range<usize>)>, error::LexerError> {
     lazy_static::lazy_static!(
         static ref raw_token_regex: regex::Regex = {
             /* List of non words token we also want to match */
             const MATCHABLE_NON_WORDS: &[&'static str] = &[
-                "\\.", ",", "'", "{", "}", "~", "\\/", ":", "+", "\\-", "—", "•", "\n",
+                "\\.", ",", "'", "{", "}", "~", "\\/", ":", "+", "\\-", "−", "—", "•", "\n",
             ];
             let matchable_non_words: String = MATCHABLE_NON_WORDS.iter().cloned().collect();
             let raw_token_pattern = format!("(\\b\\w+\\b)|([{}])", matchable_non_words);
@@ -91,7 +106,10 @@ pub fn lex(input: &str) -> Result<Vec<tokens::Token>, error::LexerError> {
         };
     );
 
-    let mut raw_tokens: std::collections::VecDeque<_> = raw_token_regex.find_iter(input).collect();
+    let mut raw_tokens: std::collections::VecDeque<_> = raw_token_regex
+        .find_iter(&input[..range.end])
+        .skip_while(|raw_token| raw_token.start() < range.start)
+        .collect();
 
     let mut result = Vec::new();
 
@@ -108,7 +126,7 @@ pub fn lex(input: &str) -> Result<Vec<tokens::Token>, error::LexerError> {
             /* Fix me: this is byte index, not character index ? may cause issues with the webdemo */
             if let Some(token) = tokens::Token::try_from_span(span) {
                 raw_tokens.drain(0..token_count + 1);
-                result.push(token);
+                result.push((token, start..end));
                 continue 'outer;
             }
         }
@@ -0,0 +1,164 @@
+/// The different names under which a card can refer to itself in its oracle text.
+///
+/// Oracle texts use "~" for the card name in the rules, but the raw texts from the card
+/// database contain the actual name. Depending on the card, the name can appear as:
+/// - the full name, "Gimli, Counter of Kills";
+/// - a shortened name for legendary cards, "Gimli" (see rule 201.5);
+/// - the name of one of the faces for split cards, "Fire" for "Fire // Ice";
+/// - the flavor or printed name, for cards printed under an other name.
+///
+/// All of these forms are only replaced on whole words, so that a card named "Ash"
+/// does not have its "flash" or "ashes" replaced.
+///
+/// Tokens and emblems can also mention the card creating them, but that is an other object:
+/// these names are not replaced, since "~" would make the token refer to itself.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardName {
+    pub name: String,
+    pub flavor_name: Option<String>,
+    pub printed_name: Option<String>,
+    /// Whether the card is legendary, allowing more shortened forms of the name.
+    pub legendary: bool,
+}
+
+/// Which form of the card name a substitution was made for.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameForm {
+    /// The full name of the card, "Gimli, Counter of Kills".
+    FullName,
+    /// The name of a single face of a multi faced card, "Fire" in "Fire // Ice".
+    FaceName,
+    /// The part of the name before the first comma, "Gimli" in "Gimli, Counter of Kills".
+    ShortName,
+    /// For legendary cards, the part of the name before "the", "Borborygmos" in "Borborygmos the Great".
+    ShortNameBeforeThe,
+    /// The name of the planeswalker an emblem was created from, "Ajani Steadfast" for "Ajani Steadfast Emblem".
+    EmblemSource,
+    /// The flavor name of the card, for cards printed in universes beyond.
+    FlavorName,
+    /// The printed name of the card, for cards printed in an other language.
+    PrintedName,
+}
+
+/// A name that was replaced by "~" in an oracle text.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameSubstitution {
+    pub form: NameForm,
+    /// The replaced text, lowercased.
+    pub name: String,
+    /// How many times that name was found and replaced in the oracle text.
+    pub occurrences: usize,
+}
+
+impl CardName {
+    /// Create a card name from the name only.
+    ///
+    /// Without any other information, the card is not considered legendary.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            flavor_name: None,
+            printed_name: None,
+            legendary: false,
+        }
+    }
+
+    /// Gather all the names of a card from the card database.
+    pub fn from_raw_card(raw_card: &mtg_cardbase::Card) -> Self {
+        Self {
+            name: raw_card.name.clone(),
+            flavor_name: raw_card.flavor_name.clone(),
+            printed_name: raw_card.printed_name.clone(),
+            legendary: raw_card.type_line.to_ascii_lowercase().contains("legendary"),
+        }
+    }
+
+    /// All the lowercased forms of the name that can be replaced, longest first.
+    ///
+    /// Longer forms are replaced first, so that "gimli, counter of kills" is replaced as a
+    /// whole before attempting to replace "gimli".
+    pub fn forms(&self) -> Vec<(NameForm, String)> {
+        let name = self.name.to_lowercase();
+        let mut forms = vec![(NameForm::FullName, name.clone())];
+
+        if name.contains("//") {
+            for face in name.split("//") {
+                forms.push((NameForm::FaceName, face.trim().to_string()));
+            }
+        }
+        if let Some(source) = name.strip_suffix(" emblem") {
+            forms.push((NameForm::EmblemSource, source.to_string()));
+        }
+        if let Some((short_name, _)) = name.split_once(',') {
+            forms.push((NameForm::ShortName, short_name.trim().to_string()));
+        }
+        if self.legendary
+            && let Some((short_name, _)) = name.split_once(" the ")
+        {
+            forms.push((NameForm::ShortNameBeforeThe, short_name.trim().to_string()));
+        }
+        if let Some(flavor_name) = self.flavor_name.as_ref() {
+            forms.push((NameForm::FlavorName, flavor_name.to_lowercase()));
+        }
+        if let Some(printed_name) = self.printed_name.as_ref() {
+            forms.push((NameForm::PrintedName, printed_name.to_lowercase()));
+        }
+
+        forms.retain(|(_, form)| !form.is_empty());
+        forms.sort_by_key(|(_, form)| std::cmp::Reverse(form.len()));
+        forms.dedup_by(|(_, a), (_, b)| a == b);
+        forms
+    }
+
+    /// Replace all the forms of the card name in the given lowercased text by "~".
+    ///
+    /// Returns the new text, and the list of substitutions that were made.
+    pub fn replace_in(&self, lowercase_oracle_text: &str) -> (String, Vec<NameSubstitution>) {
+        let mut result = lowercase_oracle_text.to_string();
+        let mut substitutions = Vec::new();
+
+        for (form, name) in self.forms() {
+            let (replaced, occurrences) = replace_whole_words(&result, &name, "~");
+            if occurrences > 0 {
+                substitutions.push(NameSubstitution { form, name, occurrences });
+                result = replaced;
+            }
+        }
+
+        (result, substitutions)
+    }
+}
+
+/// Replace all occurences of the pattern that are whole words in the input.
+///
+/// An occurence is a whole word if it is not directly preceded or followed by an alphanumeric character.
+/// Returns the new string and the number of replaced occurences.
+fn replace_whole_words(input: &str, pattern: &str, replacement: &str) -> (String, usize) {
+    let is_word_char = |c: char| c.is_alphanumeric();
+
+    let mut result = String::with_capacity(input.len());
+    let mut occurrences = 0;
+    let mut last_end = 0;
+
+    for (start, matched) in input.match_indices(pattern) {
+        /* Overlapping matches can't both be replaced */
+        if start < last_end {
+            continue;
+        }
+        let end = start + matched.len();
+        let word_start = !input[..start].chars().next_back().is_some_and(is_word_char);
+        let word_end = !input[end..].chars().next().is_some_and(is_word_char);
+        if word_start && word_end {
+            result.push_str(&input[last_end..start]);
+            result.push_str(replacement);
+            last_end = end;
+            occurrences += 1;
+        }
+    }
+    result.push_str(&input[last_end..]);
+
+    (result, occurrences)
+}
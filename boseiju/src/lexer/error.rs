@@ -1,4 +1,8 @@
 /// Errors that can be thrown by the lexer.
+///
+/// The error is serialized with its variant name in a "kind" field, next to the variant fields.
+#[derive(serde::Serialize)]
+#[serde(tag = "kind")]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum LexerError {
     NoTokenMatch { start: usize, end: usize, tokens: String },
@@ -132,7 +132,7 @@ impl VhyToSortLater {
 impl VhyToSortLater {
     pub fn try_from_span(span: &crate::lexer::Span) -> Option<Self> {
         match span.text {
-            "ability" => Some(Self::Ability {
+            "ability" | "abilities" => Some(Self::Ability {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             }),
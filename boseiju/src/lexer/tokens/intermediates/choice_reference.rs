@@ -10,6 +10,10 @@ pub enum ChoiceReference {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
     },
+    SourceOfYourChoice {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
 }
 
 #[cfg(feature = "spanned_tree")]
@@ -18,6 +22,7 @@ impl ChoiceReference {
         match self {
             Self::ChosenColor { span } => *span,
             Self::HasntBeenChosen { span } => *span,
+            Self::SourceOfYourChoice { span } => *span,
         }
     }
 }
@@ -33,6 +38,10 @@ impl ChoiceReference {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             }),
+            "a source of your choice" => Some(Self::SourceOfYourChoice {
+                #[cfg(feature = "spanned_tree")]
+                span: span.into(),
+            }),
             _ => None,
         }
     }
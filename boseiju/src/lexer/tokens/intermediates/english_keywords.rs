@@ -115,6 +115,10 @@ pub enum EnglishKeyword {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
     },
+    Devotion {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
     Different {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
@@ -143,6 +147,10 @@ pub enum EnglishKeyword {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
     },
+    Every {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
     Everyting {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
@@ -235,10 +243,18 @@ pub enum EnglishKeyword {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
     },
+    Mode {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
     More {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
     },
+    MoreThanOnce {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
     Named {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
@@ -287,6 +303,10 @@ pub enum EnglishKeyword {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
     },
+    Protect {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
     RatherThan {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
@@ -355,6 +375,10 @@ pub enum EnglishKeyword {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
     },
+    Time {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
     To {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
@@ -441,6 +465,7 @@ impl EnglishKeyword {
             Self::Chosen { span } => *span,
             Self::Control { span } => *span,
             Self::Copy { span } => *span,
+            Self::Devotion { span } => *span,
             Self::Different { span } => *span,
             Self::Divided { span } => *span,
             Self::During { span } => *span,
@@ -448,6 +473,7 @@ impl EnglishKeyword {
             Self::Dont { span } => *span,
             Self::End { span } => *span,
             Self::Equal { span } => *span,
+            Self::Every { span } => *span,
             Self::Everyting { span } => *span,
             Self::Except { span } => *span,
             Self::First { span } => *span,
@@ -471,7 +497,9 @@ impl EnglishKeyword {
             Self::Less { span } => *span,
             Self::May { span } => *span,
             Self::Must { span } => *span,
+            Self::Mode { span } => *span,
             Self::More { span } => *span,
+            Self::MoreThanOnce { span } => *span,
             Self::Named { span } => *span,
             Self::Next { span } => *span,
             Self::New { span } => *span,
@@ -484,6 +512,7 @@ impl EnglishKeyword {
             Self::Or { span } => *span,
             Self::Other { span } => *span,
             Self::Otherwise { span } => *span,
+            Self::Protect { span } => *span,
             Self::RatherThan { span } => *span,
             Self::Random { span } => *span,
             Self::Same { span } => *span,
@@ -501,6 +530,7 @@ impl EnglishKeyword {
             Self::This { span } => *span,
             Self::ThisWay { span } => *span,
             Self::Those { span } => *span,
+            Self::Time { span } => *span,
             Self::To { span } => *span,
             Self::Top { span } => *span,
             Self::Total { span } => *span,
@@ -633,6 +663,10 @@ impl EnglishKeyword {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             }),
+            "devotion" => Some(Self::Devotion {
+                #[cfg(feature = "spanned_tree")]
+                span: span.into(),
+            }),
             "different" => Some(Self::Different {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
@@ -661,6 +695,10 @@ impl EnglishKeyword {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             }),
+            "every" => Some(Self::Every {
+                #[cfg(feature = "spanned_tree")]
+                span: span.into(),
+            }),
             "everything" => Some(Self::Everyting {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
@@ -753,10 +791,18 @@ impl EnglishKeyword {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             }),
+            "mode" | "modes" => Some(Self::Mode {
+                #[cfg(feature = "spanned_tree")]
+                span: span.into(),
+            }),
             "more" => Some(Self::More {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             }),
+            "more than once" => Some(Self::MoreThanOnce {
+                #[cfg(feature = "spanned_tree")]
+                span: span.into(),
+            }),
             "named" => Some(Self::Named {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
@@ -805,6 +851,10 @@ impl EnglishKeyword {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             }),
+            "protect" | "protects" | "protected" => Some(Self::Protect {
+                #[cfg(feature = "spanned_tree")]
+                span: span.into(),
+            }),
             "rather than" => Some(Self::RatherThan {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
@@ -873,6 +923,10 @@ impl EnglishKeyword {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             }),
+            "time" | "times" => Some(Self::Time {
+                #[cfg(feature = "spanned_tree")]
+                span: span.into(),
+            }),
             "to" => Some(Self::To {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
@@ -70,6 +70,13 @@ impl Number {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             })
+        } else if let Some(stripped) = span.text.strip_suffix(" or both") {
+            let num = crate::utils::parse_num(stripped)?;
+            Some(Self::OrMore {
+                num,
+                #[cfg(feature = "spanned_tree")]
+                span: span.into(),
+            })
         } else if let Some(stripped) = span.text.strip_suffix(" or greater") {
             let num = crate::utils::parse_num(stripped)?;
             Some(Self::OrMore {
@@ -77,6 +84,20 @@ impl Number {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             })
+        } else if let Some(stripped) = span.text.strip_suffix(" or less") {
+            let num = crate::utils::parse_num(stripped)?;
+            Some(Self::UpTo {
+                num,
+                #[cfg(feature = "spanned_tree")]
+                span: span.into(),
+            })
+        } else if let Some(stripped) = span.text.strip_suffix(" or fewer") {
+            let num = crate::utils::parse_num(stripped)?;
+            Some(Self::UpTo {
+                num,
+                #[cfg(feature = "spanned_tree")]
+                span: span.into(),
+            })
         } else if let Some(stripped) = span.text.strip_prefix("up to ") {
             let num = crate::utils::parse_num(stripped)?;
             Some(Self::UpTo {
@@ -90,7 +111,7 @@ impl Number {
                     #[cfg(feature = "spanned_tree")]
                     span: span.into(),
                 }),
-                "any number of" => Some(Self::AnyNumber {
+                "any number" | "any number of" => Some(Self::AnyNumber {
                     #[cfg(feature = "spanned_tree")]
                     span: span.into(),
                 }),
@@ -33,6 +33,10 @@ pub enum PlayerSpecifier {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
     },
+    TargetPlayer {
+        #[cfg(feature = "spanned_tree")]
+        span: crate::ability_tree::span::TreeSpan,
+    },
     Owner {
         #[cfg(feature = "spanned_tree")]
         span: crate::ability_tree::span::TreeSpan,
@@ -63,6 +67,7 @@ impl PlayerSpecifier {
             Self::EachOpponent { span } => *span,
             Self::Owner { span } => *span,
             Self::TargetOpponent { span } => *span,
+            Self::TargetPlayer { span } => *span,
             Self::ToYourLeft { span } => *span,
             Self::ToYourRight { span } => *span,
             Self::You { span } => *span,
@@ -102,7 +107,11 @@ impl IntoToken for PlayerSpecifier {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             }),
-            "target opponent" | "target player" | "target players" => Some(Self::TargetOpponent {
+            "target opponent" => Some(Self::TargetOpponent {
+                #[cfg(feature = "spanned_tree")]
+                span: span.into(),
+            }),
+            "target player" | "target players" => Some(Self::TargetPlayer {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             }),
@@ -34,7 +34,8 @@ impl PowerToughnessModElements {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             }),
-            "-" => Some(Self::Minus {
+            /* Loyalty costs are written with the unicode minus sign, e.g. "−2:" */
+            "-" | "−" => Some(Self::Minus {
                 #[cfg(feature = "spanned_tree")]
                 span: span.into(),
             }),
@@ -1,22 +1,39 @@
 mod error;
+mod graph_export;
 mod node;
 mod rule_map;
 mod rules;
+mod session;
 
 pub use error::ParserError;
+pub use error::PossibleExpectedToken;
+pub use graph_export::*;
 pub use node::ParserNode;
-
-use std::sync::Arc;
+pub use rule_map::GrammarLint;
+pub use session::ParserSession;
 
 use rapidhash::HashMapExt;
 use rapidhash::HashSetExt;
 type HashSet<V> = rapidhash::RapidHashSet<V>;
 type HashMap<K, V> = rapidhash::RapidHashMap<K, V>;
 
-#[derive(Clone)]
-enum EarleyBackpointer<'r> {
+/// Id of an Earley item, that is its index in the item arena of the Earley table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct EarleyItemId(u32);
+
+impl EarleyItemId {
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// What advanced an Earley item by one node.
+#[derive(Debug, Clone, Copy)]
+enum EarleyBackpointer {
+    /// The item was advanced by scanning the token at the given index.
     Scanned(usize),
-    Complete(Arc<EarleyItem<'r>>),
+    /// The item was advanced by the completed item with the given id.
+    Complete(EarleyItemId),
 }
 
 /// Earley Item.
@@ -33,22 +50,36 @@ enum EarleyBackpointer<'r> {
 /// of the input stream, and we are expecting a token `c` from the stream.
 ///
 /// The debug implementation of the rule display it with this format.
-#[derive(Clone)]
+#[derive(Clone, Copy)]
 struct EarleyItem<'r> {
     pub rule: &'r rules::ParserRule,
     pub start_index: usize,
     pub position_index: usize,
-    pub backpointers: Vec<EarleyBackpointer<'r>>,
+    /// The item this one was advanced from, with what advanced it.
+    ///
+    /// Following the predecessors back to the predicted item gives all the backpointers of the item.
+    /// This way, items advanced from the same item share their backpointers instead of copying them.
+    pub predecessor: Option<(EarleyItemId, EarleyBackpointer)>,
 }
 
 impl<'r> EarleyItem<'r> {
-    /// Construct a new Earley item with no backpointers.
-    fn new(rule: &'r rules::ParserRule, start_index: usize, position_index: usize) -> Self {
+    /// Construct a new Earley item predicting the rule at the start index, with nothing matched yet.
+    fn new(rule: &'r rules::ParserRule, start_index: usize) -> Self {
         Self {
             rule,
             start_index,
-            position_index,
-            backpointers: Vec::with_capacity(rule.expanded.length.get()),
+            position_index: 0,
+            predecessor: None,
+        }
+    }
+
+    /// Create the item advanced by one node from this item, that has the given id.
+    fn advance(&self, id: EarleyItemId, backpointer: EarleyBackpointer) -> Self {
+        Self {
+            rule: self.rule,
+            start_index: self.start_index,
+            position_index: self.position_index + 1,
+            predecessor: Some((id, backpointer)),
         }
     }
 
@@ -65,27 +96,69 @@ impl<'r> EarleyItem<'r> {
         self.rule.expanded.length.get() == self.position_index
     }
 
+    /// The backpointers of the item, from the first matched node to the last one.
+    fn backpointers(&self, earley_table: &EarleyTable<'r>) -> Vec<EarleyBackpointer> {
+        let mut backpointers = Vec::with_capacity(self.position_index);
+        let mut item = self;
+        while let Some((predecessor, backpointer)) = item.predecessor {
+            backpointers.push(backpointer);
+            item = earley_table.item(predecessor);
+        }
+        backpointers.reverse();
+        backpointers
+    }
+
     /// Use the item rule to reduce the parser nodes.
     ///
     /// If the item contains any backpointers, they will be used to recursively
     /// call the rules required to merge everything together.
-    fn reduce(&self, nodes: &[ParserNode]) -> Result<ParserNode, ParserError> {
-        let rule_token_count = self.rule.expanded.length.get();
-
-        let mut tokens_for_reduction = Vec::with_capacity(rule_token_count);
-        for backpointer in self.backpointers.iter().take(rule_token_count) {
+    ///
+    /// With spanned trees, the tokens the nodes were created from are used to locate the rule in case of errors.
+    fn reduce(
+        &self,
+        earley_table: &EarleyTable<'r>,
+        nodes: &[ParserNode],
+        #[cfg(feature = "spanned_tree")] tokens: &[crate::lexer::tokens::Token],
+    ) -> Result<ParserNode, ParserError> {
+        let mut tokens_for_reduction = Vec::with_capacity(self.rule.expanded.length.get());
+        for backpointer in self.backpointers(earley_table) {
             match backpointer {
-                EarleyBackpointer::Scanned(token_index) => tokens_for_reduction.push(nodes[*token_index].clone()),
-                EarleyBackpointer::Complete(backpointer) => tokens_for_reduction.push(backpointer.reduce(nodes)?),
+                EarleyBackpointer::Scanned(token_index) => tokens_for_reduction.push(nodes[token_index].clone()),
+                EarleyBackpointer::Complete(completed) => {
+                    let completed = earley_table.item(completed);
+                    #[cfg(feature = "spanned_tree")]
+                    let node = completed.reduce(earley_table, nodes, tokens)?;
+                    #[cfg(not(feature = "spanned_tree"))]
+                    let node = completed.reduce(earley_table, nodes)?;
+                    tokens_for_reduction.push(node)
+                }
             }
         }
 
         match (self.rule.reduction)(&tokens_for_reduction) {
             Ok(node) => Ok(node),
-            Err(merge_error) => Err(ParserError::FailedToApplyRule {
-                merge_error,
-                for_rule: self.rule.creation_loc.clone(),
-            }),
+            Err(merge_error) => {
+                #[cfg(feature = "spanned_tree")]
+                let (position, length) = error::tokens_position(tokens, self.start_index, self.end_index(earley_table));
+                Err(ParserError::FailedToApplyRule {
+                    merge_error,
+                    for_rule: self.rule.creation_loc.clone(),
+                    #[cfg(feature = "spanned_tree")]
+                    position,
+                    #[cfg(feature = "spanned_tree")]
+                    length,
+                })
+            }
+        }
+    }
+
+    /// Index of the token right after the last token matched by this item.
+    #[cfg(feature = "spanned_tree")]
+    fn end_index(&self, earley_table: &EarleyTable<'r>) -> usize {
+        match self.predecessor {
+            Some((_, EarleyBackpointer::Scanned(token_index))) => token_index + 1,
+            Some((_, EarleyBackpointer::Complete(completed))) => earley_table.item(completed).end_index(earley_table),
+            None => self.start_index,
         }
     }
 }
@@ -106,6 +179,8 @@ impl<'r> std::fmt::Debug for EarleyItem<'r> {
     }
 }
 
+/// Items are compared without their backpointers: two items with the same rule, start and position
+/// are the same item of the Earley algorithm, only reached through different derivations.
 impl<'r> PartialEq for EarleyItem<'r> {
     fn eq(&self, other: &Self) -> bool {
         self.rule == other.rule && self.start_index == other.start_index && self.position_index == other.position_index
@@ -130,97 +205,86 @@ impl<'r> std::hash::Hash for EarleyItem<'r> {
 /// The core idea of the algorithm is that in the Earley table at index j,
 /// the Earley row j contains all the rules that are potential matches for the
 /// incoming token stream.
+///
+/// The items themselves are in the arena of the [`EarleyTable`], the row only holds their ids.
 #[derive(Clone)]
-struct EarleyRow<'r> {
+struct EarleyRow {
+    /// Id of the first item of the row.
+    ///
+    /// The items of a row are added to the arena all together, so they have contiguous ids
+    /// up to the first item of the next row.
+    pub first_item: usize,
     /// Storage for all items that are completed.
     /// That is, there is no awaiting token.
     /// They are stored separatly for quick access in the algorithm.
-    pub completed_items: HashSet<Arc<EarleyItem<'r>>>,
+    pub completed_items: Vec<EarleyItemId>,
     /// Storage for all items awaiting a node. They are stored in a
     /// HashMap where the awaited node id is the key, for easy access.
-    pub uncompleted_items: HashMap<usize, HashSet<Arc<EarleyItem<'r>>>>,
+    pub uncompleted_items: HashMap<usize, Vec<EarleyItemId>>,
+    /// The nodes whose rules were predicted in the row.
+    ///
+    /// Only the predicted rules that can start with the next token are added to the row, so the
+    /// predicted nodes are kept to know all the rules that could have started at the row.
+    pub predicted_nodes: HashSet<usize>,
 }
 
-impl<'r> EarleyRow<'r> {
-    /// Creates a new, empty row.
-    fn new() -> Self {
+impl EarleyRow {
+    /// Creates a new, empty row, whose items will start at the given id.
+    fn new(first_item: usize) -> Self {
         Self {
-            completed_items: HashSet::new(),
+            first_item,
+            completed_items: Vec::new(),
             uncompleted_items: HashMap::new(),
+            predicted_nodes: HashSet::new(),
         }
     }
 
-    /// Create the start row of the Earley Table for an algorithm that is targetting
-    /// the Ability Tree node.
-    fn start_row(rules: &'static rule_map::RuleMap) -> EarleyRow<'static> {
-        use crate::utils::dummy;
-        use idris::Idris;
-
-        let mut start_row = EarleyRow::new();
-
-        let target_node = ParserNode::AbilityTree { tree: dummy() };
-        let node_id = target_node.id();
-
-        let mut queue: Vec<Arc<_>> = Vec::new();
+    fn is_empty(&self) -> bool {
+        self.completed_items.is_empty() && self.uncompleted_items.is_empty()
+    }
+}
 
-        if let Some(rules) = rules.get_rules_for_token(node_id) {
-            for rule in rules {
-                let item = Arc::new(EarleyItem::new(rule, 0, 0));
-                start_row.insert(item.clone());
-                queue.push(item.clone());
-            }
-        }
+/// An Earley row being filled.
+///
+/// The builder keeps track of the items already in the row, so that each item is added only once:
+/// the first derivation found for an item is the one kept.
+struct EarleyRowBuilder<'r> {
+    row: EarleyRow,
+    known_items: HashSet<EarleyItem<'r>>,
+}
 
-        while let Some(item) = queue.pop() {
-            if let Some(new_items) = predictor_step(&rules, 0, &item) {
-                for new_item in new_items {
-                    let item = Arc::new(new_item.clone());
-                    if start_row.insert(item.clone()) {
-                        queue.push(item.clone());
-                    }
-                }
-            }
+impl<'r> EarleyRowBuilder<'r> {
+    /// Start a new row, after the items already in the arena.
+    fn new(items: &[EarleyItem<'r>]) -> Self {
+        Self {
+            row: EarleyRow::new(items.len()),
+            known_items: HashSet::new(),
         }
-
-        start_row
     }
 
-    fn insert(&mut self, item: Arc<EarleyItem<'r>>) -> bool {
+    /// Add the item to the arena and to the row, if the row does not hold it yet.
+    ///
+    /// Returns the id of the item if it was added.
+    fn insert(&mut self, items: &mut Vec<EarleyItem<'r>>, item: EarleyItem<'r>) -> Option<EarleyItemId> {
+        if !self.known_items.insert(item) {
+            return None;
+        }
+        let id = EarleyItemId(items.len() as u32);
+        items.push(item);
         match item.expecting_token() {
-            None => {
-                /* if no tokens are expected, the rule is complete */
-                self.completed_items.insert(item)
-            }
-            Some(expecting) => {
-                /* Otherwise, we are expecting a token */
-                let row = self.uncompleted_items.entry(expecting).or_default();
-                row.insert(item)
-            }
+            /* if no tokens are expected, the rule is complete */
+            None => self.row.completed_items.push(id),
+            /* Otherwise, we are expecting a token */
+            Some(expecting) => self.row.uncompleted_items.entry(expecting).or_default().push(id),
         }
+        Some(id)
     }
 
-    fn is_empty(&self) -> bool {
-        self.completed_items.is_empty() && self.uncompleted_items.is_empty()
-    }
-}
-
-impl<'r> std::fmt::Debug for EarleyRow<'r> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.is_empty() {
-            write!(f, "[]")?;
-        } else {
-            writeln!(f, "[")?;
-            for item in self.completed_items.iter() {
-                writeln!(f, "  {item:?}")?;
-            }
-            for items in self.uncompleted_items.values() {
-                for item in items.iter() {
-                    writeln!(f, "  {item:?}")?;
-                }
-            }
-            write!(f, "]")?;
-        }
-        Ok(())
+    /// Whether the node shall be predicted, that is it was not predicted in this row yet.
+    ///
+    /// Predicting a node twice would add the same items, since they do not depend on the item awaiting the node.
+    fn predict(&mut self, node_id: usize) -> bool {
+        self.row.predicted_nodes.insert(node_id)
     }
 }
 
@@ -229,129 +293,231 @@ impl<'r> std::fmt::Debug for EarleyRow<'r> {
 /// The Earley table is the object constructed with the Earley parsing algorithm.
 /// It contains as many rows as there are tokens (plus one) and keep track of the rules
 /// that can match the provided input stream.
+///
+/// All the items of the table are stored in a single arena, and refer to each other with their ids.
+/// Since the rows are filled one after the other, the items of a row are after the items of the previous rows.
 #[derive(Clone)]
 struct EarleyTable<'r> {
-    pub table: Vec<EarleyRow<'r>>,
+    pub items: Vec<EarleyItem<'r>>,
+    pub table: Vec<EarleyRow>,
 }
 
 impl<'r> EarleyTable<'r> {
-    pub fn new(node_count: usize, start_row: EarleyRow<'r>) -> Self {
-        let mut table = Vec::with_capacity(node_count + 1);
-        table.push(start_row);
+    /// Create the Earley Table with its start row, for an algorithm that is targetting the Ability Tree node.
+    fn start_table(rules: &'r rule_map::RuleMap) -> Self {
+        use crate::utils::dummy;
+        use idris::Idris;
+
+        let mut items = Vec::new();
+        let mut start_row = EarleyRowBuilder::new(&items);
+
+        let target_node = ParserNode::AbilityTree { tree: dummy() };
+        let node_id = target_node.id();
+
+        /* The first token is not known yet, so all the rules that can lead to the target node are predicted */
+        if let Some(rules) = rules.predictions(node_id, None) {
+            start_row.predict(node_id);
+            for rule in rules {
+                start_row.insert(&mut items, EarleyItem::new(rule, 0));
+            }
+        }
+
+        Self {
+            items,
+            table: vec![start_row.row],
+        }
+    }
 
-        Self { table }
+    fn item(&self, id: EarleyItemId) -> &EarleyItem<'r> {
+        &self.items[id.index()]
+    }
+
+    /// The items of the row at the given index, with their ids, in the order they were added.
+    fn row_items(&self, row_index: usize) -> impl Iterator<Item = (EarleyItemId, &EarleyItem<'r>)> {
+        let start = self.table[row_index].first_item;
+        let end = match self.table.get(row_index + 1) {
+            Some(next_row) => next_row.first_item,
+            None => self.items.len(),
+        };
+        (start..end).map(|index| (EarleyItemId(index as u32), &self.items[index]))
+    }
+
+    /// Keep only the given number of rows, and the items they hold.
+    fn truncate(&mut self, row_count: usize) {
+        if let Some(first_removed_row) = self.table.get(row_count) {
+            self.items.truncate(first_removed_row.first_item);
+            self.table.truncate(row_count);
+        }
     }
 }
 
 impl<'r> std::fmt::Debug for EarleyTable<'r> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (i, row) in self.table.iter().enumerate() {
-            writeln!(f, "T[{i}] = {row:?}")?;
+            if row.is_empty() {
+                writeln!(f, "T[{i}] = []")?;
+            } else {
+                writeln!(f, "T[{i}] = [")?;
+                for (_, item) in self.row_items(i) {
+                    writeln!(f, "  {item:?}")?;
+                }
+                writeln!(f, "]")?;
+            }
         }
         Ok(())
     }
 }
 
 impl<'r> std::ops::Deref for EarleyTable<'r> {
-    type Target = [EarleyRow<'r>];
+    type Target = [EarleyRow];
     fn deref(&self) -> &Self::Target {
         self.table.as_slice()
     }
 }
 
+/* Rule map, all our parsing rules in a single struct */
+lazy_static::lazy_static!(
+    /// The rule map contains all the rules to parse the MTG cards.
+    static ref parser_rules: rule_map::RuleMap = rule_map::RuleMap::default().expect("Default Rule Map shall be OK");
+
+    /// An Earley table with only the first row for the earley parsing algorithm, with the target of parsing a full ability tree.
+    ///
+    /// Since the row depends only on the rule map, we can create a static instance of
+    /// it and clone it whenever we start a new parsing, instead of rebuilding it each time.
+    ///
+    /// Fixme: I think we can construct it each time it's fine, and it gives us control over the target
+    static ref earley_start_table: EarleyTable<'static> = EarleyTable::start_table(&parser_rules);
+);
+
 /// Actual implementation of the Earley algorithm.
 fn parse_impl(tokens: &[crate::lexer::tokens::Token]) -> Result<crate::AbilityTree, error::ParserError> {
-    use crate::utils::dummy;
-    use idris::Idris;
-
     if tokens.is_empty() {
         return Ok(crate::AbilityTree::empty());
     }
 
-    /* Rule map, all our parsing rules in a single struct */
-    lazy_static::lazy_static!(
-        /// The rule map contains all the rules to parse the MTG cards.
-        static ref rules: rule_map::RuleMap = rule_map::RuleMap::default().expect("Default Rule Map shall be OK");
-
-        /// A first row for the earley parsing algorithm with the target of parsing a full ability tree.
-        ///
-        /// Since the row depends only on the rule map, we can create a static instance of
-        /// it and clone it whenever we start a new parsing, instead of rebuilding it each time.
-        ///
-        /// Fixme: I think we can construct it each time it's fine, and it gives us control over the target
-        static ref earley_start_row: EarleyRow<'static> = EarleyRow::start_row(&rules);
-    );
-
-    /* Implementation of the Earley parser */
-    let target_node_id = ParserNode::AbilityTree { tree: dummy() }.id();
     let nodes: Vec<ParserNode> = tokens.iter().cloned().map(ParserNode::from).collect();
+    let earley_table = earley_table(&nodes);
+    reduce_earley_table(&earley_table, &nodes, tokens)
+}
 
+/// Fill the Earley table for the given nodes, with the target of parsing a full ability tree.
+fn earley_table(nodes: &[ParserNode]) -> EarleyTable<'static> {
     /* First step: init the table row 0 with all rules that can create the final token */
-    let node_count = nodes.len();
-    let mut earley_table = EarleyTable::new(node_count, earley_start_row.clone());
+    let mut earley_table = empty_earley_table(nodes.len());
+    fill_earley_table(&mut earley_table, nodes);
+    earley_table
+}
+
+/// Create an Earley table with only its start row, to be filled with [`fill_earley_table`].
+fn empty_earley_table(node_count: usize) -> EarleyTable<'static> {
+    let mut earley_table = earley_start_table.clone();
+    earley_table.table.reserve(node_count);
+    earley_table
+}
+
+/// Fill the missing rows of the Earley table for the given nodes.
+///
+/// The rows already in the table are kept, and the algorithm resumes from the last one.
+/// Since a row T\[j\] only depends on the j first nodes and on the next node, the lookahead of its predictions,
+/// this allows to reuse the rows of a previous parsing as long as the nodes up to them did not change.
+/// The start row is the exception, it does not use any lookahead and is always valid.
+fn fill_earley_table(earley_table: &mut EarleyTable<'static>, nodes: &[ParserNode]) {
+    let first_node_index = earley_table.table.len() - 1;
+
+    /* Buffer for the items created by a step, before they are added to the arena */
+    let mut new_items = Vec::new();
 
-    for (node_index, node) in nodes.iter().enumerate() {
+    for (node_index, node) in nodes.iter().enumerate().skip(first_node_index) {
         use idris::Idris;
         let node_id = node.id();
         let j = node_index + 1;
+        let lookahead = nodes.get(j).map(|next_node| next_node.id());
 
         /* Create the next Earley table entry, T[j]  */
-        let mut next_table_row = EarleyRow::new();
+        let mut next_table_row = EarleyRowBuilder::new(&earley_table.items);
 
         /* The queue allows to process each item once, seeing what other items are added */
         let mut queue = Vec::new();
 
         /* Scanner step */
         /* for all items in the previous row, add them to this row if they match the current token */
-        if let Some(new_items) = scanner_step(&earley_table.table[node_index], node_id, node_index) {
-            for new_item in new_items {
-                let new_item = Arc::new(new_item);
-                queue.push(new_item.clone());
-                next_table_row.insert(new_item.clone());
+        if let Some(scanned_items) = scanner_step(earley_table, node_id, node_index) {
+            new_items.extend(scanned_items);
+        }
+        for new_item in new_items.drain(..) {
+            if let Some(id) = next_table_row.insert(&mut earley_table.items, new_item) {
+                queue.push(id);
             }
         }
 
         /* Saturate Predictor + Completor steps */
-        while let Some(item) = queue.pop() {
+        while let Some(id) = queue.pop() {
+            let item = *earley_table.item(id);
             /* Predictor step */
-            if let Some(new_items) = predictor_step(&rules, j, &item) {
-                for new_item in new_items {
-                    /* Add the new item in the queue for eploring only if it is unknown (not in the row) */
-                    if next_table_row.insert(Arc::new(new_item.clone())) {
-                        queue.push(Arc::new(new_item.clone()));
-                    }
-                }
+            if let Some(predicted_items) = predictor_step(&parser_rules, &mut next_table_row, j, &item, lookahead) {
+                new_items.extend(predicted_items);
             }
             /* Completor step */
-            if let Some(new_items) = completor_step(&earley_table, item) {
-                for new_item in new_items {
-                    /* Add the new item in the queue for eploring only if it is unknown (not in the row) */
-                    if next_table_row.insert(Arc::new(new_item.clone())) {
-                        queue.push(Arc::new(new_item.clone()));
-                    }
+            if let Some(completed_items) = completor_step(earley_table, id, &item) {
+                new_items.extend(completed_items);
+            }
+            for new_item in new_items.drain(..) {
+                /* Add the new item in the queue for eploring only if it is unknown (not in the row) */
+                if let Some(id) = next_table_row.insert(&mut earley_table.items, new_item) {
+                    queue.push(id);
                 }
             }
         }
 
-        earley_table.table.push(next_table_row);
+        earley_table.table.push(next_table_row.row);
     }
+}
+
+/// Build the ability tree from a filled Earley table, by reducing the item that completed the parsing.
+fn reduce_earley_table(
+    earley_table: &EarleyTable,
+    nodes: &[ParserNode],
+    tokens: &[crate::lexer::tokens::Token],
+) -> Result<crate::AbilityTree, error::ParserError> {
+    use crate::utils::dummy;
+    use idris::Idris;
+
+    let target_node_id = ParserNode::AbilityTree { tree: dummy() }.id();
+    let node_count = nodes.len();
 
     /* Look for an item of kind (S -> a . , 0) for parser completion */
     let completed_items = earley_table.table[node_count]
         .completed_items
         .iter()
+        .map(|id| earley_table.item(*id))
         .filter(|item| item.rule.merged == target_node_id)
         .collect::<Vec<_>>();
 
     match completed_items.as_slice() {
         /* No item completed, create a parse error from the earley table */
-        &[] => Err(error::ParserError::from_earley_table(&earley_table, tokens)),
+        &[] => Err(error::ParserError::from_earley_table(earley_table, tokens)),
         /* A single item is complete: we have a condidate for merging */
-        &[complete_item] => match complete_item.reduce(&nodes)? {
-            ParserNode::AbilityTree { tree } => return Ok(tree),
-            _ => unreachable!(),
-        },
-        _ => Err(ParserError::AmbiguousCandidates),
+        &[complete_item] => {
+            #[cfg(feature = "spanned_tree")]
+            let node = complete_item.reduce(earley_table, nodes, tokens)?;
+            #[cfg(not(feature = "spanned_tree"))]
+            let node = complete_item.reduce(earley_table, nodes)?;
+            match node {
+                ParserNode::AbilityTree { tree } => Ok(tree),
+                _ => unreachable!(),
+            }
+        }
+        candidates => {
+            #[cfg(feature = "spanned_tree")]
+            let (position, length) = error::tokens_position(tokens, 0, node_count);
+            Err(ParserError::AmbiguousCandidates {
+                candidates: candidates.iter().map(|item| item.rule.creation_loc.clone()).collect(),
+                #[cfg(feature = "spanned_tree")]
+                position,
+                #[cfg(feature = "spanned_tree")]
+                length,
+            })
+        }
     }
 }
 
@@ -364,15 +530,16 @@ fn parse_impl(tokens: &[crate::lexer::tokens::Token]) -> Result<crate::AbilityTr
 /// into T\[j\] with their position index advanced.
 ///
 /// The awaited non terminals are handled in the predictor step.
-fn scanner_step<'r>(prev_row: &EarleyRow<'r>, token: usize, token_index: usize) -> Option<impl Iterator<Item = EarleyItem<'r>>> {
-    Some(prev_row.uncompleted_items.get(&token)?.iter().map(move |prev_item| {
-        use std::ops::Deref;
-
-        let mut new_item: EarleyItem = prev_item.deref().clone();
-        new_item.position_index += 1;
-        new_item.backpointers.push(EarleyBackpointer::Scanned(token_index));
-
-        new_item
+fn scanner_step<'a, 'r>(
+    earley_table: &'a EarleyTable<'r>,
+    token: usize,
+    token_index: usize,
+) -> Option<impl Iterator<Item = EarleyItem<'r>> + 'a> {
+    let prev_row = &earley_table.table[token_index];
+    Some(prev_row.uncompleted_items.get(&token)?.iter().map(move |prev_id| {
+        earley_table
+            .item(*prev_id)
+            .advance(*prev_id, EarleyBackpointer::Scanned(token_index))
     }))
 }
 
@@ -384,18 +551,26 @@ fn scanner_step<'r>(prev_row: &EarleyRow<'r>, token: usize, token_index: usize)
 ///
 /// Then, the rule completion logic is handled in the completor step.
 ///
-/// This function returns whether new items were added to the next row.
+/// The rule map gives the whole prediction closure of the awaited node at once: the rules of the node,
+/// and of the first nodes of these rules. So items that were just predicted don't need to predict anything,
+/// and each node is predicted once per row. Of the prediction closure, only the rules that can start with
+/// the lookahead, the token following the row, are added.
 fn predictor_step<'r>(
     rules: &'r rule_map::RuleMap,
+    row: &mut EarleyRowBuilder<'r>,
     j: usize,
     item: &EarleyItem<'r>,
-) -> Option<impl Iterator<Item = EarleyItem<'r>>> {
+    lookahead: Option<usize>,
+) -> Option<impl Iterator<Item = EarleyItem<'r>> + use<'r>> {
+    if item.position_index == 0 {
+        return None;
+    }
     let next_token = item.expecting_token()?;
-    Some(
-        rules
-            .get_rules_for_token(next_token)?
-            .map(move |rule| EarleyItem::new(rule, j, 0)),
-    )
+    let predictions = rules.predictions(next_token, lookahead)?;
+    if !row.predict(next_token) {
+        return None;
+    }
+    Some(predictions.map(move |rule| EarleyItem::new(rule, j)))
 }
 
 /// Completor step of the Earley Algorithm.
@@ -406,34 +581,35 @@ fn predictor_step<'r>(
 /// For a given rule (A -> a . , i) that is compleated, we can look in T[\i\] (since that
 /// rule started at i) for any rule that was awaiting for an A non terminal, and advance
 /// it in the current row.
-///
-/// This function returns whether new items were added to the next row.
-fn completor_step<'r>(
-    earley_table: &EarleyTable<'r>,
-    completed_item: Arc<EarleyItem<'r>>,
-) -> Option<impl Iterator<Item = EarleyItem<'r>>> {
+fn completor_step<'a, 'r>(
+    earley_table: &'a EarleyTable<'r>,
+    completed_id: EarleyItemId,
+    completed_item: &EarleyItem<'r>,
+) -> Option<impl Iterator<Item = EarleyItem<'r>> + 'a> {
     if completed_item.rule_complete() {
         /* If the itme is completed, check in T[i] for all rules awaiting this item */
         let awaiting_row = &earley_table.table[completed_item.start_index];
         let awaiting_items = awaiting_row.uncompleted_items.get(&completed_item.rule.merged)?;
-        Some(awaiting_items.iter().map(move |awaiting_item| {
-            /* And we can bump these rules up */
-            use std::ops::Deref;
-
-            let mut new_item: EarleyItem = awaiting_item.deref().clone();
-            new_item.position_index += 1;
-            /* Set the backpointer, since the current row item validated this token */
-            new_item
-                .backpointers
-                .push(EarleyBackpointer::Complete(completed_item.clone()));
-
-            new_item
+        Some(awaiting_items.iter().map(move |awaiting_id| {
+            /* And we can bump these rules up, the backpointer is the current row item that validated this token */
+            earley_table
+                .item(*awaiting_id)
+                .advance(*awaiting_id, EarleyBackpointer::Complete(completed_id))
         }))
     } else {
         None
     }
 }
 
+/// Analyse the grammar formed by the parser rules, and list the rules and nodes that can never take part in a parsing,
+/// along with the duplicate rules and left recursive cycles.
+pub fn grammar_lints() -> Vec<GrammarLint> {
+    use crate::utils::dummy;
+    use idris::Idris;
+
+    parser_rules.lints(ParserNode::AbilityTree { tree: dummy() }.id())
+}
+
 /// Entry point of the parsing algorithm.
 ///
 /// Attempts to parse a sequence of nodes into an ability tree, using the Earley parsing algorithm.
@@ -2,6 +2,11 @@ use crate::parser::ParserNode;
 use idris::Idris;
 
 /// Errors that can be thrown by the parser.
+///
+/// The error is serialized with its variant name in a "kind" field, next to the variant fields.
+/// Positions and lengths are in bytes of the preprocessed oracle text.
+#[derive(serde::Serialize)]
+#[serde(tag = "kind")]
 #[derive(Debug, Clone)]
 pub enum ParserError {
     UnexpectedToken {
@@ -11,16 +16,29 @@ pub enum ParserError {
     FailedToApplyRule {
         merge_error: &'static str,
         for_rule: crate::parser::rules::ParserRuleDeclarationLocation,
+        /// Position of the tokens the rule failed to merge.
+        #[cfg(feature = "spanned_tree")]
+        position: usize,
+        #[cfg(feature = "spanned_tree")]
+        length: usize,
+    },
+    InvalidEarleyTable, /* Fixme: this shall never happen I think */
+    /* Fixme: is this ever appears, we have problems ? */
+    AmbiguousCandidates {
+        /// The rules of all the items that completed the parsing.
+        candidates: Vec<crate::parser::rules::ParserRuleDeclarationLocation>,
+        #[cfg(feature = "spanned_tree")]
+        position: usize,
+        #[cfg(feature = "spanned_tree")]
+        length: usize,
     },
-    InvalidEarleyTable,  /* Fixme: this shall never happen I think */
-    AmbiguousCandidates, /* Fixme: is this ever appears, we have problems ? */
 }
 
 impl ParserError {
     pub(super) fn from_earley_table(table: &super::EarleyTable, tokens: &[crate::lexer::tokens::Token]) -> Self {
         let error_row = table.table.iter().enumerate().rev().find(|(_, row)| !row.is_empty());
-        let (stuck_index, last_non_empty_row) = match error_row {
-            Some(error) => error,
+        let stuck_index = match error_row {
+            Some((stuck_index, _)) => stuck_index,
             None => return Self::InvalidEarleyTable,
         };
 
@@ -44,29 +62,48 @@ impl ParserError {
             },
         };
 
-        /* Build a list of possible expected tokens (use a set to avoid repetitions) */
-        let mut expecting = std::collections::HashSet::new();
-        for (expecting_token, for_nodes) in last_non_empty_row.uncompleted_items.iter() {
-            /* Only take in terminal tokens ? */
-            use idris::Idris;
-            if *expecting_token < crate::lexer::tokens::Token::COUNT {
-                expecting.insert(PossibleExpectedToken {
-                    expected: *expecting_token,
-                    for_nodes: for_nodes
-                        .iter()
-                        .map(|item| (item.rule.merged, item.rule.creation_loc.clone()))
-                        .collect(),
-                });
-            }
-        }
-
         Self::UnexpectedToken {
             found: stuck_on_token,
-            expecting: expecting.into_iter().collect(),
+            expecting: expected_tokens(table, stuck_index),
         }
     }
 }
 
+/// List the terminal tokens the items of the row at the given index are waiting for, with the nodes they would create.
+///
+/// The row only holds the predicted items that can start with the token following it, so the predicted rules
+/// are taken from the prediction closures of the nodes predicted in the row instead.
+pub(super) fn expected_tokens(table: &super::EarleyTable, row_index: usize) -> Vec<PossibleExpectedToken> {
+    let row = &table.table[row_index];
+    let mut for_nodes: std::collections::HashMap<usize, Vec<_>> = std::collections::HashMap::new();
+
+    /* Items that already matched nodes */
+    for item in row.uncompleted_items.values().flatten().map(|id| table.item(*id)) {
+        if let Some(expecting_token) = item.expecting_token().filter(|_| item.position_index > 0) {
+            let for_node = (item.rule.merged, item.rule.creation_loc.clone());
+            for_nodes.entry(expecting_token).or_default().push(for_node);
+        }
+    }
+
+    /* Predicted rules, each of them once even if several predicted nodes lead to it */
+    let mut predicted_rules = std::collections::HashSet::new();
+    for node in row.predicted_nodes.iter() {
+        for rule in super::parser_rules.prediction_closure(*node).into_iter().flatten() {
+            if predicted_rules.insert(rule) {
+                let for_node = (rule.merged, rule.creation_loc.clone());
+                for_nodes.entry(rule.expanded[0]).or_default().push(for_node);
+            }
+        }
+    }
+
+    /* Only take in terminal tokens */
+    for_nodes
+        .into_iter()
+        .filter(|(expecting_token, _)| ParserNode::is_lexer_token_id(*expecting_token))
+        .map(|(expected, for_nodes)| PossibleExpectedToken { expected, for_nodes })
+        .collect()
+}
+
 impl std::fmt::Display for ParserError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -104,11 +141,18 @@ impl std::fmt::Display for ParserError {
                     write!(f, "\nNo tokens were expected !")?;
                 }
             }
-            Self::FailedToApplyRule { merge_error, for_rule } => {
+            Self::FailedToApplyRule {
+                merge_error, for_rule, ..
+            } => {
                 write!(f, "Failed to use rule (declared at: {}): {}", for_rule, merge_error)?;
             }
             Self::InvalidEarleyTable => write!(f, "Empty Earley table !")?,
-            Self::AmbiguousCandidates => write!(f, "Multiple candidates for rule completion !")?,
+            Self::AmbiguousCandidates { candidates, .. } => {
+                write!(f, "Multiple candidates for rule completion !")?;
+                for candidate in candidates.iter() {
+                    write!(f, "\n - rule at {candidate}")?;
+                }
+            }
         }
 
         Ok(())
@@ -123,6 +167,35 @@ pub struct PossibleExpectedToken {
     pub for_nodes: Vec<(usize, crate::parser::rules::ParserRuleDeclarationLocation)>,
 }
 
+/// Expected tokens are serialized with the names of the nodes instead of their ids,
+/// since the ids are meaningless outside of the parser.
+impl serde::Serialize for PossibleExpectedToken {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        #[derive(serde::Serialize)]
+        struct ForNode<'a> {
+            node: &'static str,
+            rule: &'a crate::parser::rules::ParserRuleDeclarationLocation,
+        }
+
+        let for_nodes: Vec<_> = self
+            .for_nodes
+            .iter()
+            .map(|(node, rule)| ForNode {
+                node: ParserNode::name_from_id(*node),
+                rule,
+            })
+            .collect();
+
+        let mut state = serializer.serialize_struct("PossibleExpectedToken", 2)?;
+        state.serialize_field("expected", ParserNode::name_from_id(self.expected))?;
+        state.serialize_field("for_nodes", &for_nodes)?;
+        state.end()
+    }
+}
+
+#[derive(serde::Serialize)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FoundToken {
     pub name: &'static str,
@@ -131,3 +204,17 @@ pub struct FoundToken {
     #[cfg(feature = "spanned_tree")]
     pub length: usize,
 }
+
+/// Position and length of the tokens between the start and end indices, end excluded.
+#[cfg(feature = "spanned_tree")]
+pub(super) fn tokens_position(tokens: &[crate::lexer::tokens::Token], start: usize, end: usize) -> (usize, usize) {
+    let position = match tokens.get(start) {
+        Some(token) => token.span().start,
+        None => tokens.last().map(|token| token.span().end).unwrap_or(0),
+    };
+    let end_position = match end.checked_sub(1).and_then(|last| tokens.get(last)) {
+        Some(token) if end > start => token.span().end,
+        _ => position,
+    };
+    (position, end_position.saturating_sub(position))
+}
@@ -0,0 +1,119 @@
+//! Export of the Earley algorithm internals as graphs, for grammar debugging.
+
+use super::EarleyBackpointer;
+use super::EarleyItem;
+use super::EarleyItemId;
+use super::EarleyTable;
+use super::ParserNode;
+use crate::utils::Graph;
+use idris::Idris;
+
+use rapidhash::HashMapExt;
+type HashMap<K, V> = rapidhash::RapidHashMap<K, V>;
+
+/// Build the graph of the Earley chart filled when parsing the given tokens.
+///
+/// Each row T\[j\] of the chart is a cluster, holding the items of the row. Items are labeled
+/// with their dotted rule, and the rule declaration location as tooltip. Edges are the backpointers
+/// of the items, to the items that completed them or to the scanned tokens.
+///
+/// Items that only are predictions, where nothing was matched yet, are left out:
+/// they are all the rules that could start at the row, and would hide everything else.
+pub fn earley_chart_graph(tokens: &[crate::lexer::tokens::Token]) -> Graph {
+    let nodes: Vec<ParserNode> = tokens.iter().cloned().map(ParserNode::from).collect();
+    let earley_table = super::earley_table(&nodes);
+
+    let mut graph = Graph::new();
+    let token_ids = add_tokens(&mut graph, &nodes);
+
+    /* Items are identified by their id in the arena of the table, that is unique across the rows */
+    let mut item_ids: HashMap<EarleyItemId, usize> = HashMap::new();
+    for j in 0..earley_table.len() {
+        let cluster = graph.add_cluster(format!("T[{j}]"));
+        for (item_id, item) in earley_table.row_items(j).filter(|(_, item)| item.position_index > 0) {
+            let label = format!("{item:?}");
+            let tooltip = Some(item.rule.creation_loc.to_string());
+            let id = graph.add_node_in(label, tooltip, Some(cluster));
+            item_ids.insert(item_id, id);
+        }
+    }
+
+    for (item_id, item) in earley_table.items.iter().enumerate() {
+        let Some(id) = item_ids.get(&EarleyItemId(item_id as u32)) else {
+            continue;
+        };
+        for backpointer in item.backpointers(&earley_table) {
+            match backpointer {
+                EarleyBackpointer::Scanned(token_index) => graph.add_edge(*id, token_ids[token_index], None),
+                EarleyBackpointer::Complete(completed) => {
+                    if let Some(completed_id) = item_ids.get(&completed) {
+                        graph.add_edge(*id, *completed_id, None);
+                    }
+                }
+            }
+        }
+    }
+
+    graph
+}
+
+/// Build the graph of the derivation chosen by the parser for the given tokens.
+///
+/// Each reduction is a node labeled with the node it creates and the location of the rule declaration,
+/// linked to the reductions and tokens it merges. If the tokens can be parsed in several ways,
+/// each derivation is added to the graph. If they can't be parsed at all, the parser error is returned.
+pub fn derivation_graph(tokens: &[crate::lexer::tokens::Token]) -> Result<Graph, super::ParserError> {
+    use crate::utils::dummy;
+
+    let target_node_id = ParserNode::AbilityTree { tree: dummy() }.id();
+    let nodes: Vec<ParserNode> = tokens.iter().cloned().map(ParserNode::from).collect();
+    let earley_table = super::earley_table(&nodes);
+
+    let mut completed_items = earley_table[nodes.len()]
+        .completed_items
+        .iter()
+        .map(|id| earley_table.item(*id))
+        .filter(|item| item.rule.merged == target_node_id)
+        .peekable();
+    if completed_items.peek().is_none() {
+        return Err(super::ParserError::from_earley_table(&earley_table, tokens));
+    }
+
+    let mut graph = Graph::new();
+    for item in completed_items {
+        add_reduction(&mut graph, &earley_table, item, &nodes);
+    }
+    Ok(graph)
+}
+
+fn add_reduction(graph: &mut Graph, earley_table: &EarleyTable, item: &EarleyItem, nodes: &[ParserNode]) -> usize {
+    let label = format!("{}\n{}", ParserNode::name_from_id(item.rule.merged), item.rule.creation_loc);
+    let id = graph.add_node(label, Some(format!("{item:?}")));
+    for backpointer in item.backpointers(earley_table) {
+        let child_id = match backpointer {
+            EarleyBackpointer::Scanned(token_index) => add_token(graph, &nodes[token_index]),
+            EarleyBackpointer::Complete(completed) => add_reduction(graph, earley_table, earley_table.item(completed), nodes),
+        };
+        graph.add_edge(id, child_id, None);
+    }
+    id
+}
+
+fn add_tokens(graph: &mut Graph, nodes: &[ParserNode]) -> Vec<usize> {
+    let cluster = graph.add_cluster("tokens".to_string());
+    nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| {
+            graph.add_node_in(
+                format!("{i}: {}", ParserNode::name_from_id(node.id())),
+                Some(format!("{node:?}")),
+                Some(cluster),
+            )
+        })
+        .collect()
+}
+
+fn add_token(graph: &mut Graph, node: &ParserNode) -> usize {
+    graph.add_node(ParserNode::name_from_id(node.id()).to_string(), Some(format!("{node:?}")))
+}
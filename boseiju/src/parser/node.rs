@@ -1,5 +1,6 @@
 use crate::ability_tree::AbilityTree;
 use crate::ability_tree::ability::ability_word::ExpandedAbilityWord;
+use crate::ability_tree::ability::loyalty::LoyaltyCost;
 use crate::ability_tree::ability::spell::SpellAbility;
 use crate::ability_tree::ability::statik::StaticAbilityKind;
 use crate::ability_tree::ability::statik::continuous_effect::{ContinuousEffect, PowerToughnessModifiers};
@@ -12,7 +13,7 @@ use crate::ability_tree::colors::Colors;
 use crate::ability_tree::conditional::Condition;
 use crate::ability_tree::cost::Cost;
 use crate::ability_tree::event::Event;
-use crate::ability_tree::imperative::{CreatedTokenKind, Imperative, ImperativeKind, ManaToAdd};
+use crate::ability_tree::imperative::{CreatedTokenKind, Imperative, ImperativeKind, ManaToAdd, ModalMode};
 use crate::ability_tree::imperative_list::ImperativeList;
 use crate::ability_tree::number::{GameStateNumber, Number, XDefinition};
 use crate::ability_tree::object::kind::*;
@@ -21,7 +22,7 @@ use crate::ability_tree::object::*;
 use crate::ability_tree::player::PlayerSpecifier;
 use crate::ability_tree::statement::Statement;
 use crate::ability_tree::terminals::{CounterKind, CreatureSubtype, ManaCost};
-use crate::ability_tree::time::{ForwardDuration, IncomingInstant, RecurrentInstant};
+use crate::ability_tree::time::{ForwardDuration, IncomingInstant, RecurrentInstant, StepOrPhase};
 use crate::ability_tree::type_line::TypeLine;
 use crate::ability_tree::zone::ZoneReference;
 
@@ -39,6 +40,10 @@ pub enum ParserNode {
     ArtifactKind { artifact: ArtifactKind },
     ArtifactSpecifier { specifier: ArtifactSpecifier },
     ArtifactSpecifiers { specifiers: Specifiers<ArtifactSpecifier> },
+    Battle { battle: Battle },
+    BattleKind { battle: BattleKind },
+    BattleSpecifier { specifier: BattleSpecifier },
+    BattleSpecifiers { specifiers: Specifiers<BattleSpecifier> },
     Card { card: Card },
     CardKind { card: CardKind },
     CardSpecifier { specifier: CardSpecifier },
@@ -81,20 +86,30 @@ pub enum ParserNode {
     LandSpecifier { specifier: LandSpecifier },
     LandSpecifiers { specifiers: Specifiers<LandSpecifier> },
     LexerToken(crate::lexer::tokens::Token),
+    LoyaltyCost { cost: LoyaltyCost },
     ManaCost { mana_cost: ManaCost },
     ManaToAdd { mana: ManaToAdd },
+    ModalMode { mode: ModalMode },
     MultipleKeywordAbilities { abilities: MultipleKeywordAbilities },
     Number { number: Number },
     Permanent { permanent: Permanent },
     PermanentKind { permanent: PermanentKind },
     PermanentSpecifier { specifier: PermanentSpecifier },
     PermanentSpecifiers { specifiers: Specifiers<PermanentSpecifier> },
+    Planeswalker { planeswalker: Planeswalker },
     PlaneswalkerKind { planeswalker: PlaneswalkerKind },
+    PlaneswalkerSpecifier { specifier: PlaneswalkerSpecifier },
+    PlaneswalkerSpecifiers { specifiers: Specifiers<PlaneswalkerSpecifier> },
     Player { player: PlayerSpecifier },
+    PlayerOrPermanent { object: PlayerOrPermanent },
+    PlayerOrPermanentKind { kind: PlayerOrPermanentKind },
     PowerToughnessModifiers { modifiers: PowerToughnessModifiers },
+    ProtectorSpecifier { specifier: ProtectorSpecifier },
     PutCounterKind { kind: CounterKind },
     RecurrentInstant { instant: RecurrentInstant },
+    ReplacedEvent { event: Event },
     SpecifiedArtifact { artifact: SpecifiedArtifact },
+    SpecifiedBattle { battle: SpecifiedBattle },
     SpecifiedCard { card: SpecifiedCard },
     SpecifiedCreature { creature: SpecifiedCreature },
     SpecifiedEnchantment { enchantment: SpecifiedEnchantment },
@@ -108,6 +123,7 @@ pub enum ParserNode {
     SpellSpecifier { specifier: SpellSpecifier },
     SpellSpecifiers { specifiers: Specifiers<SpellSpecifier> },
     Statement { statement: Statement },
+    StepOrPhase { step_or_phase: StepOrPhase },
     StaticAbilityKind { kind: StaticAbilityKind },
     TokenDefinition { token: TokenLayout },
     TypeLine { type_line: TypeLine },
@@ -123,9 +139,28 @@ impl From<crate::lexer::tokens::Token> for ParserNode {
     }
 }
 
+impl ParserNode {
+    /// Whether the given node id is the id of a lexer token, that is a terminal of the grammar.
+    ///
+    /// The ids of the lexer tokens come right after the ids of the nodes declared before them.
+    pub fn is_lexer_token_id(id: usize) -> bool {
+        use crate::lexer::tokens::Token;
+        use idris::Idris;
+
+        lazy_static::lazy_static!(
+            static ref first_token_id: usize = {
+                let span = crate::lexer::Span { start: 0, length: 1, text: "," };
+                let token = Token::try_from_span(span).expect("A comma shall be a token");
+                ParserNode::from(token.clone()).id() - token.id()
+            };
+        );
+        (*first_token_id..*first_token_id + Token::COUNT).contains(&id)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ImperativeChoices {
-    pub choices: crate::utils::HeapArrayVec<SpellAbility, 11 /* Fixme */>,
+    pub choices: crate::utils::HeapArrayVec<ModalMode, 11 /* Fixme */>,
     #[cfg(feature = "spanned_tree")]
     pub span: crate::ability_tree::span::TreeSpan,
 }
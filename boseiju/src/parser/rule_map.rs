@@ -1,12 +1,22 @@
 mod error;
+mod first_sets;
+mod lints;
+
+pub use lints::GrammarLint;
 
 use crate::parser::rules::ParserRule;
 use error::RuleMapCreationError;
+use first_sets::PredictionClosure;
 
 /// A given map of rules to merge tokens.
+///
+/// Along with the rules, the map holds an analysis of the grammar they form: the prediction closures
+/// of the non terminals, indexed with the FIRST sets of the predicted rules. This allows the Earley algorithm
+/// to only predict the rules that can start with the next token.
 pub struct RuleMap {
     rules: Vec<ParserRule>,
     merged_to_rules: std::collections::HashMap<usize, Vec<usize>>,
+    prediction_closures: std::collections::HashMap<usize, PredictionClosure>,
 }
 
 impl RuleMap {
@@ -25,7 +35,8 @@ impl RuleMap {
         let mut merged_to_rules: std::collections::HashMap<_, Vec<_>> = std::collections::HashMap::new();
 
         for (rule_index, rule) in rules.iter().enumerate() {
-            /* This check forces the grammar to be unambiguous, maybe we want it later on ?
+            /* Duplicate rules are reported by the grammar lints instead, see RuleMap::lints.
+            This check forces the grammar to be unambiguous, maybe we want it later on ?
             if let Some(prev) = lhs_to_rule.insert(rule.expanded, rule_index) {
                 return Err(RuleMapCreationError::DuplicateRule {
                     rule1_loc: rules[prev].creation_loc.clone(),
@@ -36,15 +47,51 @@ impl RuleMap {
             merged_to_rules.entry(rule.merged).or_default().push(rule_index);
         }
 
-        Ok(Self { rules, merged_to_rules })
+        /* Grammar analysis, to speed up the predictor step */
+        let first_sets = first_sets::first_sets(&rules, &merged_to_rules);
+        let prediction_closures = merged_to_rules
+            .keys()
+            .map(|node| {
+                let closure = PredictionClosure::new(*node, &rules, &merged_to_rules, &first_sets);
+                (*node, closure)
+            })
+            .collect();
+
+        Ok(Self {
+            rules,
+            merged_to_rules,
+            prediction_closures,
+        })
     }
 
-    pub fn get_rules_for_token(&self, node_id: usize) -> Option<impl Iterator<Item = &ParserRule>> {
+    /// All the rules to predict when the given node is awaited, if the node is created by any rule.
+    ///
+    /// These are the rules creating the node, and recursively the rules creating the first node of the predicted rules.
+    pub fn prediction_closure(&self, node_id: usize) -> Option<impl Iterator<Item = &ParserRule>> {
         Some(
-            self.merged_to_rules
+            self.prediction_closures
                 .get(&node_id)?
+                .rules
                 .iter()
                 .map(|rule_index| &self.rules[*rule_index]),
         )
     }
+
+    /// The rules to predict when the given node is awaited, if the node is created by any rule.
+    ///
+    /// If the next token is known, only the predicted rules that can start with it are returned,
+    /// since the others will never be scanned.
+    pub fn predictions(&self, node_id: usize, next_token: Option<usize>) -> Option<impl Iterator<Item = &ParserRule>> {
+        let closure = self.prediction_closures.get(&node_id)?;
+        let rule_indices = match next_token {
+            Some(next_token) => closure.by_first_token.get(&next_token).map(Vec::as_slice).unwrap_or(&[]),
+            None => closure.rules.as_slice(),
+        };
+        Some(rule_indices.iter().map(|rule_index| &self.rules[*rule_index]))
+    }
+
+    /// Analyse the grammar formed by the rules, and list the suspicious rules and nodes.
+    pub fn lints(&self, target_node: usize) -> Vec<GrammarLint> {
+        lints::lints(&self.rules, &self.merged_to_rules, target_node)
+    }
 }
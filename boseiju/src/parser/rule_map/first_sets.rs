@@ -0,0 +1,101 @@
+//! Analysis of the rules ahead of the parsing: FIRST sets of the nodes and prediction closures.
+
+use crate::parser::rules::ParserRule;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Compute the FIRST set of all the nodes created by rules.
+///
+/// The FIRST set of a node is the set of lexer tokens a sequence of nodes merging into it can start with.
+/// Rules can't be empty, so no node is nullable: the FIRST set of a rule is the FIRST set of its first node,
+/// and the FIRST set of a node is the union of the FIRST sets of the rules creating it.
+///
+/// Nodes that are not created by any rule are the lexer tokens, and their FIRST set is themselves.
+pub fn first_sets(rules: &[ParserRule], merged_to_rules: &HashMap<usize, Vec<usize>>) -> HashMap<usize, HashSet<usize>> {
+    let mut first_sets: HashMap<usize, HashSet<usize>> = merged_to_rules.keys().map(|node| (*node, HashSet::new())).collect();
+
+    /* Lexer tokens directly starting a rule are in the FIRST set of the rule node */
+    for rule in rules.iter() {
+        let first_node = rule.expanded[0];
+        if !merged_to_rules.contains_key(&first_node) {
+            first_sets.entry(rule.merged).or_default().insert(first_node);
+        }
+    }
+
+    /* Then propagate the FIRST sets through the rules starting with nodes, until nothing changes */
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for rule in rules.iter() {
+            let first_node = rule.expanded[0];
+            if first_node == rule.merged || !merged_to_rules.contains_key(&first_node) {
+                continue;
+            }
+            let missing: Vec<usize> = first_sets[&first_node]
+                .difference(&first_sets[&rule.merged])
+                .cloned()
+                .collect();
+            if !missing.is_empty() {
+                first_sets.entry(rule.merged).or_default().extend(missing);
+                changed = true;
+            }
+        }
+    }
+
+    first_sets
+}
+
+/// The rules the predictor step of the Earley algorithm adds when a node is awaited.
+///
+/// These are the rules creating the node, and recursively the rules creating the first node of the predicted rules,
+/// since those are awaited as soon as the rule is predicted.
+#[derive(Debug, Clone)]
+pub struct PredictionClosure {
+    /// The index of all the predicted rules.
+    pub rules: Vec<usize>,
+    /// The index of the predicted rules, by the lexer tokens they can start with.
+    pub by_first_token: HashMap<usize, Vec<usize>>,
+}
+
+impl PredictionClosure {
+    pub fn new(
+        node: usize,
+        rules: &[ParserRule],
+        merged_to_rules: &HashMap<usize, Vec<usize>>,
+        first_sets: &HashMap<usize, HashSet<usize>>,
+    ) -> Self {
+        let mut predicted_nodes = HashSet::from([node]);
+        let mut queue = vec![node];
+        let mut predicted_rules = Vec::new();
+
+        while let Some(node) = queue.pop() {
+            for rule_index in merged_to_rules.get(&node).into_iter().flatten() {
+                predicted_rules.push(*rule_index);
+                let first_node = rules[*rule_index].expanded[0];
+                if merged_to_rules.contains_key(&first_node) && predicted_nodes.insert(first_node) {
+                    queue.push(first_node);
+                }
+            }
+        }
+        /* Keep the order of the rule declarations, for the items to be predicted in a consistent order */
+        predicted_rules.sort();
+
+        let mut by_first_token: HashMap<usize, Vec<usize>> = HashMap::new();
+        for rule_index in predicted_rules.iter() {
+            let first_node = rules[*rule_index].expanded[0];
+            match first_sets.get(&first_node) {
+                Some(first_set) => {
+                    for token in first_set.iter() {
+                        by_first_token.entry(*token).or_default().push(*rule_index);
+                    }
+                }
+                None => by_first_token.entry(first_node).or_default().push(*rule_index),
+            }
+        }
+
+        Self {
+            rules: predicted_rules,
+            by_first_token,
+        }
+    }
+}
@@ -0,0 +1,270 @@
+//! Grammar lints, to find the rules and nodes that can never take part in a parsing,
+//! and the rules that make the grammar harder to maintain.
+
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use idris::Idris;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// A suspicious construct in the grammar formed by the parser rules.
+///
+/// Nodes are given by their id, and rules by the location of their declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GrammarLint {
+    /// No rule creates the node, so rules awaiting it can never complete.
+    UnproducedNode {
+        node: usize,
+        /// The rules awaiting the node, if any.
+        awaited_by: Vec<ParserRuleDeclarationLocation>,
+    },
+    /// The node is created by rules, but no sequence of tokens can ever be merged into it.
+    UnproducibleNode {
+        node: usize,
+        /// The rules awaiting the node, that can never complete.
+        awaited_by: Vec<ParserRuleDeclarationLocation>,
+    },
+    /// The rule creates a node that is never awaited when parsing the target node, so it is never predicted.
+    UnreachableRule {
+        rule: ParserRuleDeclarationLocation,
+        merged: usize,
+    },
+    /// Rules declared at different locations merge the same nodes into the same node, so the same tokens can be parsed in several ways.
+    DuplicateRules {
+        expanded: Vec<usize>,
+        merged: usize,
+        rules: Vec<ParserRuleDeclarationLocation>,
+    },
+    /// The nodes can start with themselves, through the first nodes of the given rules.
+    LeftRecursiveCycle {
+        nodes: Vec<usize>,
+        rules: Vec<(usize, ParserRuleDeclarationLocation)>,
+    },
+}
+
+impl GrammarLint {
+    /// Short names of all the kinds of lints.
+    pub const KINDS: [&'static str; 5] = [
+        "unproduced node",
+        "unproducible node",
+        "unreachable rule",
+        "duplicate rules",
+        "left recursive cycle",
+    ];
+
+    /// Short name of the kind of lint, to group lints in reports.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::UnproducedNode { .. } => "unproduced node",
+            Self::UnproducibleNode { .. } => "unproducible node",
+            Self::UnreachableRule { .. } => "unreachable rule",
+            Self::DuplicateRules { .. } => "duplicate rules",
+            Self::LeftRecursiveCycle { .. } => "left recursive cycle",
+        }
+    }
+}
+
+impl std::fmt::Display for GrammarLint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnproducedNode { node, awaited_by } => {
+                let node_name = ParserNode::name_from_id(*node);
+                write!(f, "Unproduced node \"{node_name}\": no rule creates it")?;
+                for rule in awaited_by.iter() {
+                    write!(f, "\n - awaited by rule at {rule}")?;
+                }
+            }
+            Self::UnproducibleNode { node, awaited_by } => {
+                let node_name = ParserNode::name_from_id(*node);
+                write!(f, "Unproducible node \"{node_name}\": no tokens can be merged into it")?;
+                for rule in awaited_by.iter() {
+                    write!(f, "\n - awaited by rule at {rule}")?;
+                }
+            }
+            Self::UnreachableRule { rule, merged } => {
+                let node_name = ParserNode::name_from_id(*merged);
+                write!(f, "Unreachable rule (declared at: {rule}): \"{node_name}\" is never awaited")?;
+            }
+            Self::DuplicateRules { expanded, merged, rules } => {
+                let expanded: Vec<_> = expanded.iter().map(|node| ParserNode::name_from_id(*node)).collect();
+                let node_name = ParserNode::name_from_id(*merged);
+                write!(f, "Duplicate rules merging \"{}\" into \"{node_name}\"", expanded.join(" "))?;
+                for rule in rules.iter() {
+                    write!(f, "\n - rule at {rule}")?;
+                }
+            }
+            Self::LeftRecursiveCycle { nodes, rules } => {
+                let nodes: Vec<_> = nodes.iter().map(|node| ParserNode::name_from_id(*node)).collect();
+                write!(f, "Left recursive cycle through \"{}\"", nodes.join("\", \""))?;
+                for (merged, rule) in rules.iter() {
+                    let node_name = ParserNode::name_from_id(*merged);
+                    write!(f, "\n - rule creating \"{node_name}\" at {rule}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// List the lints of the grammar formed by the rules, for the parsing of the target node.
+pub fn lints(rules: &[ParserRule], merged_to_rules: &HashMap<usize, Vec<usize>>, target_node: usize) -> Vec<GrammarLint> {
+    let mut lints = Vec::new();
+    lints.extend(unproduced_nodes(rules, merged_to_rules));
+    lints.extend(unproducible_nodes(rules, merged_to_rules));
+    lints.extend(unreachable_rules(rules, merged_to_rules, target_node));
+    lints.extend(duplicate_rules(rules));
+    lints.extend(left_recursive_cycles(rules, merged_to_rules));
+    lints
+}
+
+/// The rules awaiting each node.
+fn awaited_by(rules: &[ParserRule]) -> HashMap<usize, Vec<ParserRuleDeclarationLocation>> {
+    let mut awaited_by: HashMap<usize, Vec<ParserRuleDeclarationLocation>> = HashMap::new();
+    for rule in rules.iter() {
+        for node in rule.expanded.iter() {
+            awaited_by.entry(*node).or_default().push(rule.creation_loc.clone());
+        }
+    }
+    awaited_by
+}
+
+/// Parser nodes that are not lexer tokens, and that no rule creates.
+fn unproduced_nodes(rules: &[ParserRule], merged_to_rules: &HashMap<usize, Vec<usize>>) -> Vec<GrammarLint> {
+    let mut awaited_by = awaited_by(rules);
+    (0..ParserNode::COUNT)
+        .filter(|node| !ParserNode::is_lexer_token_id(*node) && !merged_to_rules.contains_key(node))
+        .map(|node| GrammarLint::UnproducedNode {
+            node,
+            awaited_by: awaited_by.remove(&node).unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// Nodes created by rules, but where all the rules await nodes that can't be produced.
+fn unproducible_nodes(rules: &[ParserRule], merged_to_rules: &HashMap<usize, Vec<usize>>) -> Vec<GrammarLint> {
+    /* Producible nodes: lexer tokens, and nodes with a rule only awaiting producible nodes */
+    let mut producible: HashSet<usize> = HashSet::new();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for rule in rules.iter() {
+            let all_producible = rule
+                .expanded
+                .iter()
+                .all(|node| producible.contains(node) || ParserNode::is_lexer_token_id(*node));
+            if all_producible && !producible.contains(&rule.merged) {
+                producible.insert(rule.merged);
+                changed = true;
+            }
+        }
+    }
+
+    let mut awaited_by = awaited_by(rules);
+    let mut unproducible: Vec<usize> = merged_to_rules
+        .keys()
+        .filter(|node| !producible.contains(node))
+        .cloned()
+        .collect();
+    unproducible.sort();
+    unproducible
+        .into_iter()
+        .map(|node| GrammarLint::UnproducibleNode {
+            node,
+            awaited_by: awaited_by.remove(&node).unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// Rules creating nodes that are never awaited when parsing the target node.
+fn unreachable_rules(rules: &[ParserRule], merged_to_rules: &HashMap<usize, Vec<usize>>, target_node: usize) -> Vec<GrammarLint> {
+    /* Nodes reachable from the target: all nodes awaited by the rules of reachable nodes */
+    let mut reachable = HashSet::from([target_node]);
+    let mut queue = vec![target_node];
+    while let Some(node) = queue.pop() {
+        for rule_index in merged_to_rules.get(&node).into_iter().flatten() {
+            for awaited in rules[*rule_index].expanded.iter() {
+                if reachable.insert(*awaited) {
+                    queue.push(*awaited);
+                }
+            }
+        }
+    }
+
+    rules
+        .iter()
+        .filter(|rule| !reachable.contains(&rule.merged))
+        .map(|rule| GrammarLint::UnreachableRule {
+            rule: rule.creation_loc.clone(),
+            merged: rule.merged,
+        })
+        .collect()
+}
+
+/// Groups of rules merging the same nodes into the same node, declared at different locations.
+///
+/// Rules declared at the same location are generated together, and are not reported.
+fn duplicate_rules(rules: &[ParserRule]) -> Vec<GrammarLint> {
+    let mut by_rule: HashMap<(&[usize], usize), Vec<ParserRuleDeclarationLocation>> = HashMap::new();
+    for rule in rules.iter() {
+        let same_rule = by_rule.entry((&rule.expanded[..], rule.merged)).or_default();
+        if !same_rule.contains(&rule.creation_loc) {
+            same_rule.push(rule.creation_loc.clone());
+        }
+    }
+
+    let mut duplicates: Vec<GrammarLint> = by_rule
+        .into_iter()
+        .filter(|(_, rules)| rules.len() > 1)
+        .map(|((expanded, merged), rules)| GrammarLint::DuplicateRules {
+            expanded: expanded.to_vec(),
+            merged,
+            rules,
+        })
+        .collect();
+    duplicates.sort_by_key(|lint| match lint {
+        GrammarLint::DuplicateRules { rules, .. } => rules.first().map(|location| (location.file, location.line)),
+        _ => None,
+    });
+    duplicates
+}
+
+/// Cycles of nodes that can start with themselves, through the first nodes of their rules.
+///
+/// The Earley algorithm handles left recursion, so these are not errors. However, cycles through
+/// several nodes are hard to follow, and a cycle where a node merges into itself alone is ambiguous.
+fn left_recursive_cycles(rules: &[ParserRule], merged_to_rules: &HashMap<usize, Vec<usize>>) -> Vec<GrammarLint> {
+    /* Graph of the nodes, linked to the nodes their rules start with */
+    let mut graph = petgraph::graphmap::DiGraphMap::<usize, ()>::new();
+    for rule in rules.iter() {
+        let first_node = rule.expanded[0];
+        if merged_to_rules.contains_key(&first_node) {
+            graph.add_edge(rule.merged, first_node, ());
+        }
+    }
+
+    let mut cycles = Vec::new();
+    for mut component in petgraph::algo::tarjan_scc(&graph) {
+        let is_cycle = component.len() > 1 || graph.contains_edge(component[0], component[0]);
+        if !is_cycle {
+            continue;
+        }
+        component.sort();
+        let mut cycle_rules: Vec<_> = rules
+            .iter()
+            .filter(|rule| component.contains(&rule.merged) && component.contains(&rule.expanded[0]))
+            .map(|rule| (rule.merged, rule.creation_loc.clone()))
+            .collect();
+        /* Rules generated together are reported once */
+        cycle_rules.dedup();
+        cycles.push(GrammarLint::LeftRecursiveCycle {
+            nodes: component,
+            rules: cycle_rules,
+        });
+    }
+    cycles.sort_by_key(|lint| match lint {
+        GrammarLint::LeftRecursiveCycle { nodes, .. } => nodes.first().cloned(),
+        _ => None,
+    });
+    cycles
+}
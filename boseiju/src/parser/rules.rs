@@ -25,6 +25,7 @@ mod imperative_list;
 mod instant;
 mod keyword_ability;
 mod keyword_action;
+mod loyalty_ability;
 mod mana;
 mod number;
 mod object;
@@ -65,6 +66,7 @@ pub fn default_rules() -> impl Iterator<Item = ParserRule> {
         Box::new(instant::rules()),
         Box::new(keyword_ability::rules()),
         Box::new(keyword_action::rules()),
+        Box::new(loyalty_ability::rules()),
         Box::new(mana::rules()),
         Box::new(number::rules()),
         Box::new(object::rules()),
@@ -162,6 +164,7 @@ impl std::ops::Deref for RuleLhs {
 /// The parser rule declaration location is a debug helper that provides informations
 /// on where the rule has been written. This is only usefull while building the rule set,
 /// if multiple rules have the same left hend side.
+#[derive(serde::Serialize)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ParserRuleDeclarationLocation {
     pub file: &'static str,
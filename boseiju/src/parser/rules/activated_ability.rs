@@ -40,6 +40,131 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: super::ParserRuleDeclarationLocation::here(),
         },
+        /* "<cost>: <add mana imperative>. spend this mana only to cast <spell>" makes a mana ability with a spend restriction */
+        /* The restriction is a sentence of its own, so it is attached at the ability level */
+        super::ParserRule {
+            expanded: super::RuleLhs::new(&[
+                ParserNode::Cost { cost: dummy() }.id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Colons {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Imperative { imperative: dummy() }.id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Dot {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Spend {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::This {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Mana {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Only {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                    keyword_action: mtg_data::KeywordAction::Cast,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Spell { spell: dummy() }.id(),
+            ]),
+            merged: ParserNode::WrittenAbility { ability: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Cost { cost },
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Colons { .. })),
+                    ParserNode::Imperative { imperative },
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Dot { .. })),
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Spend {
+                        #[cfg(feature = "spanned_tree")]
+                            span: spend_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::This { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Mana { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Only { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
+                    ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                        keyword_action: mtg_data::KeywordAction::Cast,
+                        ..
+                    })),
+                    ParserNode::Spell { spell },
+                ] => {
+                    use crate::ability_tree::imperative::ImperativeKind;
+
+                    let add_mana = match &imperative.kind {
+                        ImperativeKind::AddMana(add_mana) if add_mana.spend_restriction.is_none() => add_mana,
+                        _ => return Err("Only added mana can have a spend restriction"),
+                    };
+                    let imperative = crate::ability_tree::imperative::Imperative {
+                        kind: ImperativeKind::AddMana(crate::ability_tree::imperative::AddManaImperative {
+                            possibilities: add_mana.possibilities.clone(),
+                            spend_restriction: Some(crate::ability_tree::imperative::ManaSpendRestriction {
+                                only_to_cast: spell.clone(),
+                                #[cfg(feature = "spanned_tree")]
+                                span: spell.node_span().merge(spend_span),
+                            }),
+                            #[cfg(feature = "spanned_tree")]
+                            span: spell.node_span().merge(&add_mana.span),
+                        }),
+                        executing_player: imperative.executing_player.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: spell.node_span().merge(&imperative.span),
+                    };
+                    let statement = crate::ability_tree::statement::Statement::Imperatives(
+                        crate::ability_tree::imperative_list::ImperativeList {
+                            imperatives: {
+                                let mut imperatives = crate::utils::HeapArrayVec::new();
+                                imperatives.push(imperative.clone());
+                                imperatives
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: imperative.span,
+                        },
+                    );
+                    Ok(ParserNode::WrittenAbility {
+                        ability: crate::ability_tree::ability::WrittenAbility::Activated(
+                            crate::ability_tree::ability::activated::ActivatedAbility {
+                                effect: crate::ability_tree::ability::spell::SpellAbility {
+                                    effects: {
+                                        let mut statements = crate::utils::HeapArrayVec::new();
+                                        statements.push(statement);
+                                        statements
+                                    },
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: imperative.span,
+                                },
+                                cost: cost.clone(),
+                                #[cfg(feature = "spanned_tree")]
+                                span: cost.node_span().merge(&imperative.span),
+                            },
+                        ),
+                    })
+                }
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: super::ParserRuleDeclarationLocation::here(),
+        },
     ]
     .into_iter()
 }
@@ -1,14 +1,26 @@
+mod cards_in_zone;
 mod creature_match_specifier;
 mod event_occured;
+mod number_comparison;
+mod permanent_has_state;
 mod player_control_permanent;
+mod player_life_total;
+mod spells_cast_this_turn;
 mod stack_object_has_state;
+mod this_is_your_turn;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
     [
+        cards_in_zone::rules().collect::<Vec<_>>(),
         creature_match_specifier::rules().collect::<Vec<_>>(),
         event_occured::rules().collect::<Vec<_>>(),
+        number_comparison::rules().collect::<Vec<_>>(),
+        permanent_has_state::rules().collect::<Vec<_>>(),
         player_control_permanent::rules().collect::<Vec<_>>(),
+        player_life_total::rules().collect::<Vec<_>>(),
+        spells_cast_this_turn::rules().collect::<Vec<_>>(),
         stack_object_has_state::rules().collect::<Vec<_>>(),
+        this_is_your_turn::rules().collect::<Vec<_>>(),
     ]
     .into_iter()
     .flatten()
@@ -0,0 +1,59 @@
+use crate::ability_tree::conditional;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "<number> cards are in <zone>" condition, such as "seven or more cards are in your graveyard" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Number { number: dummy() }.id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Are {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::In {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::ZoneReference { zone: dummy() }.id(),
+            ]),
+            merged: ParserNode::Condition { condition: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Number { number },
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Are { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::In { .. })),
+                    ParserNode::ZoneReference { zone },
+                ] => Ok(ParserNode::Condition {
+                    condition: conditional::Condition::CardsInZone(conditional::ConditionCardsInZone {
+                        amount: number.clone(),
+                        zone: zone.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: number.node_span().merge(&zone.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
@@ -11,10 +11,16 @@ use idris::Idris;
 use crate::ability_tree::AbilityTreeNode;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
-    [time::BackwardDuration::ThisTurn {
-        #[cfg(feature = "spanned_tree")]
-        span: Default::default(),
-    }]
+    [
+        time::BackwardDuration::LastTurn {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        time::BackwardDuration::ThisTurn {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+    ]
     .into_iter()
     .map(|duration| ParserRule {
         expanded: RuleLhs::new(&[
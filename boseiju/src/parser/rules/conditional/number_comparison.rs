@@ -0,0 +1,134 @@
+use crate::ability_tree::conditional;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "<player> have more life than <player>" condition, comparing life totals */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Have {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::More {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Than {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Player { player: dummy() }.id(),
+            ]),
+            merged: ParserNode::Condition { condition: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Have { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::More {
+                        #[cfg(feature = "spanned_tree")]
+                            span: comparison_span,
+                    })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Than { .. })),
+                    ParserNode::Player { player: other },
+                ] => Ok(ParserNode::Condition {
+                    condition: conditional::Condition::NumberComparison(conditional::ConditionNumberComparison {
+                        number: life_total(player),
+                        comparison: conditional::NumberComparison::MoreThan {
+                            #[cfg(feature = "spanned_tree")]
+                            span: *comparison_span,
+                        },
+                        other: life_total(other),
+                        #[cfg(feature = "spanned_tree")]
+                        span: player.node_span().merge(&other.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<player> have less life than <player>" condition, comparing life totals */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Have {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Less {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Than {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Player { player: dummy() }.id(),
+            ]),
+            merged: ParserNode::Condition { condition: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Have { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Less {
+                        #[cfg(feature = "spanned_tree")]
+                            span: comparison_span,
+                    })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Than { .. })),
+                    ParserNode::Player { player: other },
+                ] => Ok(ParserNode::Condition {
+                    condition: conditional::Condition::NumberComparison(conditional::ConditionNumberComparison {
+                        number: life_total(player),
+                        comparison: conditional::NumberComparison::LessThan {
+                            #[cfg(feature = "spanned_tree")]
+                            span: *comparison_span,
+                        },
+                        other: life_total(other),
+                        #[cfg(feature = "spanned_tree")]
+                        span: player.node_span().merge(&other.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
+
+fn life_total(player: &crate::ability_tree::player::PlayerSpecifier) -> crate::ability_tree::number::GameStateNumber {
+    crate::ability_tree::number::GameStateNumber::LifeTotal(crate::ability_tree::number::LifeTotal {
+        player: player.clone(),
+        #[cfg(feature = "spanned_tree")]
+        span: player.node_span(),
+    })
+}
@@ -0,0 +1,85 @@
+use crate::ability_tree::conditional;
+use crate::ability_tree::state;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        intermediates::CardState::Enchanted {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        intermediates::CardState::Tapped {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        intermediates::CardState::Untapped {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+    ]
+    .into_iter()
+    .map(|card_state| {
+        /* "<permanent> is <permanent state>" condition */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CardState(card_state)).id(),
+            ]),
+            merged: ParserNode::Condition { condition: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is { .. })),
+                    ParserNode::LexerToken(Token::CardState(card_state)),
+                ] => {
+                    #[cfg(feature = "spanned_tree")]
+                    let span = card_state.span();
+                    let state = match card_state {
+                        intermediates::CardState::Enchanted { .. } => {
+                            state::PermanentState::Enchanted(state::PermanentEnchantedState {
+                                #[cfg(feature = "spanned_tree")]
+                                span,
+                            })
+                        }
+                        intermediates::CardState::Tapped { .. } => state::PermanentState::Tapped(state::PermanentTappedState {
+                            #[cfg(feature = "spanned_tree")]
+                            span,
+                        }),
+                        intermediates::CardState::Untapped { .. } => {
+                            state::PermanentState::Untapped(state::PermanentUntappedState {
+                                #[cfg(feature = "spanned_tree")]
+                                span,
+                            })
+                        }
+                        _ => return Err("Card state is not a permanent state"),
+                    };
+                    Ok(ParserNode::Condition {
+                        condition: conditional::Condition::PermanentHasState(conditional::ConditionPermanentHasState {
+                            permanent: permanent.clone(),
+                            state,
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(&span),
+                        }),
+                    })
+                }
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        }
+    })
+}
@@ -0,0 +1,56 @@
+use crate::ability_tree::conditional;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "<player> have <number> life" condition, such as "you have 30 or more life" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Have {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Number { number: dummy() }.id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::Condition { condition: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Have { .. })),
+                    ParserNode::Number { number },
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::Condition {
+                    condition: conditional::Condition::PlayerLifeTotal(conditional::ConditionPlayerLifeTotal {
+                        player: player.clone(),
+                        life: number.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: player.node_span().merge(end_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
@@ -0,0 +1,74 @@
+use crate::ability_tree::conditional;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "<player> have cast <number> spells this turn" condition */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Have {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                    keyword_action: mtg_data::KeywordAction::Cast,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Number { number: dummy() }.id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Spell {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::BackwardDuration(
+                    crate::ability_tree::time::BackwardDuration::ThisTurn {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    },
+                ))
+                .id(),
+            ]),
+            merged: ParserNode::Condition { condition: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Have { .. })),
+                    ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                        keyword_action: mtg_data::KeywordAction::Cast,
+                        ..
+                    })),
+                    ParserNode::Number { number },
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Spell { .. })),
+                    ParserNode::LexerToken(Token::BackwardDuration(crate::ability_tree::time::BackwardDuration::ThisTurn {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::Condition {
+                    condition: conditional::Condition::SpellsCastThisTurn(conditional::ConditionSpellsCastThisTurn {
+                        player: player.clone(),
+                        amount: number.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: player.node_span().merge(end_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
@@ -0,0 +1,59 @@
+use crate::ability_tree::conditional;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [/* "it's your turn" condition */ ParserRule {
+        expanded: RuleLhs::new(&[
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+        ]),
+        merged: ParserNode::Condition { condition: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                    #[cfg(feature = "spanned_tree")]
+                        span: start_span,
+                })),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS { .. })),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your { .. })),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                    #[cfg(feature = "spanned_tree")]
+                        span: end_span,
+                })),
+            ] => Ok(ParserNode::Condition {
+                condition: conditional::Condition::ThisIsYourTurn(conditional::ConditionThisIsYourTurn {
+                    #[cfg(feature = "spanned_tree")]
+                    span: start_span.merge(end_span),
+                }),
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    }]
+    .into_iter()
+}
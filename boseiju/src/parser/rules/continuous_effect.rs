@@ -1,9 +1,13 @@
+mod change_control_rules;
 mod modify_rules_effects;
+mod object_characteristics_rules;
 mod object_gains_abilities_rules;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
     [
+        change_control_rules::rules().collect::<Vec<_>>(),
         modify_rules_effects::rules().collect::<Vec<_>>(),
+        object_characteristics_rules::rules().collect::<Vec<_>>(),
         object_gains_abilities_rules::rules().collect::<Vec<_>>(),
     ]
     .into_iter()
@@ -0,0 +1,100 @@
+use crate::ability_tree::ability::statik::continuous_effect::*;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::rules::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "<player> control <permanent>" is a control-changing effect, as in "you control enchanted creature" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Control {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Permanent { permanent: dummy() }.id(),
+            ]),
+            merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Control { .. })),
+                    ParserNode::Permanent { permanent },
+                ] => Ok(ParserNode::ContinuousEffect {
+                    effect: ContinuousEffect {
+                        effect: ContinuousEffectKind::ChangeControl(ChangeControlEffect {
+                            object: permanent.clone(),
+                            new_controller: player.clone(),
+                            #[cfg(feature = "spanned_tree")]
+                            span: player.node_span().merge(&permanent.node_span()),
+                        }),
+                        #[cfg(feature = "spanned_tree")]
+                        span: player.node_span().merge(&permanent.node_span()),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "gain control of <permanent>" is a control-changing effect where "you" become the controller */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Control {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Permanent { permanent: dummy() }.id(),
+            ]),
+            merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
+                        #[cfg(feature = "spanned_tree")]
+                            span: gain_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Control { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
+                    ParserNode::Permanent { permanent },
+                ] => Ok(ParserNode::ContinuousEffect {
+                    effect: ContinuousEffect {
+                        effect: ContinuousEffectKind::ChangeControl(ChangeControlEffect {
+                            object: permanent.clone(),
+                            new_controller: crate::ability_tree::player::PlayerSpecifier::You {
+                                #[cfg(feature = "spanned_tree")]
+                                span: gain_span.empty_at_start(),
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: gain_span.merge(&permanent.node_span()),
+                        }),
+                        #[cfg(feature = "spanned_tree")]
+                        span: gain_span.merge(&permanent.node_span()),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
@@ -129,6 +129,69 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
+        /* "<creature> can't be blocked except by <creature>" is a rule modification effect */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Creature { creature: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Cant {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CardActions(intermediates::CardActions::Blocks {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Except {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::By {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Creature { creature: dummy() }.id(),
+            ]),
+            merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Creature { creature },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Cant { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be { .. })),
+                    ParserNode::LexerToken(Token::CardActions(intermediates::CardActions::Blocks { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Except { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::By { .. })),
+                    ParserNode::Creature {
+                        creature: allowed_blockers,
+                    },
+                ] => Ok(ParserNode::ContinuousEffect {
+                    effect: continuous_effect::ContinuousEffect {
+                        effect: continuous_effect::ContinuousEffectKind::ModifyRule(
+                            continuous_effect::ModifyRuleEffect::CreatureCantBeBlockedExceptBy(
+                                continuous_effect::CreatureCantBeBlockedExceptBy {
+                                    creature: creature.clone(),
+                                    allowed_blockers: allowed_blockers.clone(),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: creature.node_span().merge(&allowed_blockers.node_span()),
+                                },
+                            ),
+                        ),
+                        #[cfg(feature = "spanned_tree")]
+                        span: creature.node_span().merge(&allowed_blockers.node_span()),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
     ]
     .into_iter()
 }
@@ -0,0 +1,588 @@
+use crate::ability_tree::ability::statik::continuous_effect::*;
+use crate::ability_tree::terminals;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::rules::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "<permanent> has base power and toughness <p/t>" sets the base power and toughness of the permanent */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Have {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CardProperty(intermediates::CardProperty::BasePowerAndToughness {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::PowerToughness {
+                    pt: terminals::PowerToughness {
+                        power: 0,
+                        toughness: 0,
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    },
+                })
+                .id(),
+            ]),
+            merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Have { .. })),
+                    ParserNode::LexerToken(Token::CardProperty(intermediates::CardProperty::BasePowerAndToughness {
+                        #[cfg(feature = "spanned_tree")]
+                            span: property_span,
+                    })),
+                    ParserNode::LexerToken(Token::PowerToughness { pt }),
+                ] => Ok(ParserNode::ContinuousEffect {
+                    effect: ContinuousEffect {
+                        effect: ContinuousEffectKind::ModifyObjectAbilities(ModifyObjectEffect {
+                            object: permanent.clone(),
+                            modifications: {
+                                let mut modifications = crate::utils::HeapArrayVec::new();
+                                modifications.push(ObjectAbilitiesModification::CharacteristicModification(
+                                    ObjectCharacteristicModification::PowerToughnessModifiers(PowerToughnessModifiers::Set(
+                                        PowerToughnessModifiersSet {
+                                            power: crate::ability_tree::number::Number::Number(
+                                                crate::ability_tree::number::FixedNumber {
+                                                    number: pt.power,
+                                                    #[cfg(feature = "spanned_tree")]
+                                                    span: pt.span,
+                                                },
+                                            ),
+                                            toughness: crate::ability_tree::number::Number::Number(
+                                                crate::ability_tree::number::FixedNumber {
+                                                    number: pt.toughness,
+                                                    #[cfg(feature = "spanned_tree")]
+                                                    span: pt.span,
+                                                },
+                                            ),
+                                            #[cfg(feature = "spanned_tree")]
+                                            span: property_span.merge(&pt.span),
+                                        },
+                                    )),
+                                ));
+                                modifications
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(&pt.span),
+                        }),
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(&pt.span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<permanent> loses all abilities" removes all abilities of the permanent */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Lose {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Ability {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Lose {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Ability {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::ContinuousEffect {
+                    effect: ContinuousEffect {
+                        effect: ContinuousEffectKind::ModifyObjectAbilities(ModifyObjectEffect {
+                            object: permanent.clone(),
+                            modifications: {
+                                let mut modifications = crate::utils::HeapArrayVec::new();
+                                modifications.push(ObjectAbilitiesModification::LoseAllAbilities(ObjectLoseAllAbilities {
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: start_span.merge(end_span),
+                                }));
+                                modifications
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(end_span),
+                        }),
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(end_span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<permanent> loses all abilities and has base power and toughness <p/t>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Lose {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Ability {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::And {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Have {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CardProperty(intermediates::CardProperty::BasePowerAndToughness {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::PowerToughness {
+                    pt: terminals::PowerToughness {
+                        power: 0,
+                        toughness: 0,
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    },
+                })
+                .id(),
+            ]),
+            merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Lose {
+                        #[cfg(feature = "spanned_tree")]
+                            span: lose_span,
+                    })),
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Ability {
+                        #[cfg(feature = "spanned_tree")]
+                            span: ability_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::And { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Have { .. })),
+                    ParserNode::LexerToken(Token::CardProperty(intermediates::CardProperty::BasePowerAndToughness {
+                        #[cfg(feature = "spanned_tree")]
+                            span: property_span,
+                    })),
+                    ParserNode::LexerToken(Token::PowerToughness { pt }),
+                ] => Ok(ParserNode::ContinuousEffect {
+                    effect: ContinuousEffect {
+                        effect: ContinuousEffectKind::ModifyObjectAbilities(ModifyObjectEffect {
+                            object: permanent.clone(),
+                            modifications: {
+                                let mut modifications = crate::utils::HeapArrayVec::new();
+                                modifications.push(ObjectAbilitiesModification::LoseAllAbilities(ObjectLoseAllAbilities {
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: lose_span.merge(ability_span),
+                                }));
+                                modifications.push(ObjectAbilitiesModification::CharacteristicModification(
+                                    ObjectCharacteristicModification::PowerToughnessModifiers(PowerToughnessModifiers::Set(
+                                        PowerToughnessModifiersSet {
+                                            power: crate::ability_tree::number::Number::Number(
+                                                crate::ability_tree::number::FixedNumber {
+                                                    number: pt.power,
+                                                    #[cfg(feature = "spanned_tree")]
+                                                    span: pt.span,
+                                                },
+                                            ),
+                                            toughness: crate::ability_tree::number::Number::Number(
+                                                crate::ability_tree::number::FixedNumber {
+                                                    number: pt.toughness,
+                                                    #[cfg(feature = "spanned_tree")]
+                                                    span: pt.span,
+                                                },
+                                            ),
+                                            #[cfg(feature = "spanned_tree")]
+                                            span: property_span.merge(&pt.span),
+                                        },
+                                    )),
+                                ));
+                                modifications
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(&pt.span),
+                        }),
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(&pt.span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<permanent> becomes <colors>" is a color-changing effect */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Become {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Colors { colors: dummy() }.id(),
+            ]),
+            merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Become {
+                        #[cfg(feature = "spanned_tree")]
+                            span: become_span,
+                    })),
+                    ParserNode::Colors { colors },
+                ] => Ok(ParserNode::ContinuousEffect {
+                    effect: ContinuousEffect {
+                        effect: ContinuousEffectKind::ModifyObjectAbilities(ModifyObjectEffect {
+                            object: permanent.clone(),
+                            modifications: {
+                                let mut modifications = crate::utils::HeapArrayVec::new();
+                                modifications.push(ObjectAbilitiesModification::CharacteristicModification(
+                                    ObjectCharacteristicModification::ColorChange(ObjectColorChange {
+                                        colors: colors.clone(),
+                                        #[cfg(feature = "spanned_tree")]
+                                        span: *become_span,
+                                    }),
+                                ));
+                                modifications
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(become_span),
+                        }),
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(become_span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<permanent> is <colors>" is a color-changing effect */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Colors { colors: dummy() }.id(),
+            ]),
+            merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is {
+                        #[cfg(feature = "spanned_tree")]
+                            span: is_span,
+                    })),
+                    ParserNode::Colors { colors },
+                ] => Ok(ParserNode::ContinuousEffect {
+                    effect: ContinuousEffect {
+                        effect: ContinuousEffectKind::ModifyObjectAbilities(ModifyObjectEffect {
+                            object: permanent.clone(),
+                            modifications: {
+                                let mut modifications = crate::utils::HeapArrayVec::new();
+                                modifications.push(ObjectAbilitiesModification::CharacteristicModification(
+                                    ObjectCharacteristicModification::ColorChange(ObjectColorChange {
+                                        colors: colors.clone(),
+                                        #[cfg(feature = "spanned_tree")]
+                                        span: *is_span,
+                                    }),
+                                ));
+                                modifications
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(is_span),
+                        }),
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(is_span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<permanents> are <colors>" is a color-changing effect */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Are {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Colors { colors: dummy() }.id(),
+            ]),
+            merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Are {
+                        #[cfg(feature = "spanned_tree")]
+                            span: are_span,
+                    })),
+                    ParserNode::Colors { colors },
+                ] => Ok(ParserNode::ContinuousEffect {
+                    effect: ContinuousEffect {
+                        effect: ContinuousEffectKind::ModifyObjectAbilities(ModifyObjectEffect {
+                            object: permanent.clone(),
+                            modifications: {
+                                let mut modifications = crate::utils::HeapArrayVec::new();
+                                modifications.push(ObjectAbilitiesModification::CharacteristicModification(
+                                    ObjectCharacteristicModification::ColorChange(ObjectColorChange {
+                                        colors: colors.clone(),
+                                        #[cfg(feature = "spanned_tree")]
+                                        span: *are_span,
+                                    }),
+                                ));
+                                modifications
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(are_span),
+                        }),
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(are_span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<permanent> is every creature type" is a type-changing effect, as for changeling */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Every {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CardType(terminals::CardType {
+                    card_type: mtg_data::CardType::Creature,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Type {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Every {
+                        #[cfg(feature = "spanned_tree")]
+                            span: every_span,
+                    })),
+                    ParserNode::LexerToken(Token::CardType(terminals::CardType {
+                        card_type: mtg_data::CardType::Creature,
+                        ..
+                    })),
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Type {
+                        #[cfg(feature = "spanned_tree")]
+                            span: type_span,
+                    })),
+                ] => Ok(ParserNode::ContinuousEffect {
+                    effect: ContinuousEffect {
+                        effect: ContinuousEffectKind::ModifyObjectAbilities(ModifyObjectEffect {
+                            object: permanent.clone(),
+                            modifications: {
+                                let mut modifications = crate::utils::HeapArrayVec::new();
+                                modifications.push(ObjectAbilitiesModification::CharacteristicModification(
+                                    ObjectCharacteristicModification::IsEveryCreatureType(ObjectIsEveryCreatureType {
+                                        #[cfg(feature = "spanned_tree")]
+                                        span: every_span.merge(type_span),
+                                    }),
+                                ));
+                                modifications
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(type_span),
+                        }),
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(type_span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<permanent> becomes a <p/t> <creature subtype> creature in addition to its other types" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Become {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::A {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::PowerToughness {
+                    pt: terminals::PowerToughness {
+                        power: 0,
+                        toughness: 0,
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    },
+                })
+                .id(),
+                ParserNode::CreatureSubtype { subtype: dummy() }.id(),
+                ParserNode::LexerToken(Token::CardType(terminals::CardType {
+                    card_type: mtg_data::CardType::Creature,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::InAdditionTo {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Its {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Other {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Types {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Become { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::A { .. })),
+                    ParserNode::LexerToken(Token::PowerToughness { pt }),
+                    ParserNode::CreatureSubtype { subtype },
+                    ParserNode::LexerToken(Token::CardType(terminals::CardType {
+                        card_type: mtg_data::CardType::Creature,
+                        ..
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::InAdditionTo { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Its { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Other { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Types {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::ContinuousEffect {
+                    effect: ContinuousEffect {
+                        effect: ContinuousEffectKind::ModifyObjectAbilities(ModifyObjectEffect {
+                            object: permanent.clone(),
+                            modifications: {
+                                let mut modifications = crate::utils::HeapArrayVec::new();
+                                modifications.push(ObjectAbilitiesModification::CharacteristicModification(
+                                    ObjectCharacteristicModification::TypeChange(ObjectTypeChange {
+                                        new_types: Box::new(crate::ability_tree::type_line::TypeLine::creature(
+                                            &[subtype.creature_subtype],
+                                            #[cfg(feature = "spanned_tree")]
+                                            subtype.node_span().merge(end_span),
+                                        )),
+                                        in_addition_to_other_types: true,
+                                        #[cfg(feature = "spanned_tree")]
+                                        span: subtype.node_span().merge(end_span),
+                                    }),
+                                ));
+                                modifications.push(ObjectAbilitiesModification::CharacteristicModification(
+                                    ObjectCharacteristicModification::PowerToughnessModifiers(PowerToughnessModifiers::Set(
+                                        PowerToughnessModifiersSet {
+                                            power: crate::ability_tree::number::Number::Number(
+                                                crate::ability_tree::number::FixedNumber {
+                                                    number: pt.power,
+                                                    #[cfg(feature = "spanned_tree")]
+                                                    span: pt.span,
+                                                },
+                                            ),
+                                            toughness: crate::ability_tree::number::Number::Number(
+                                                crate::ability_tree::number::FixedNumber {
+                                                    number: pt.toughness,
+                                                    #[cfg(feature = "spanned_tree")]
+                                                    span: pt.span,
+                                                },
+                                            ),
+                                            #[cfg(feature = "spanned_tree")]
+                                            span: pt.span,
+                                        },
+                                    )),
+                                ));
+                                modifications
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(end_span),
+                        }),
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(end_span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
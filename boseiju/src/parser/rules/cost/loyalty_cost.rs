@@ -0,0 +1,106 @@
+use crate::ability_tree::ability::loyalty;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "+<number>" loyalty cost */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::PowerToughnessModElements(
+                    intermediates::PowerToughnessModElements::Plus {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    },
+                ))
+                .id(),
+                ParserNode::Number { number: dummy() }.id(),
+            ]),
+            merged: ParserNode::LoyaltyCost { cost: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::PowerToughnessModElements(intermediates::PowerToughnessModElements::Plus {
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                    ParserNode::Number { number },
+                ] => Ok(ParserNode::LoyaltyCost {
+                    cost: loyalty::LoyaltyCost::Plus(loyalty::LoyaltyCostPlus {
+                        amount: number.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: number.node_span().merge(span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "−<number>" loyalty cost, including "−X" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::PowerToughnessModElements(
+                    intermediates::PowerToughnessModElements::Minus {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    },
+                ))
+                .id(),
+                ParserNode::Number { number: dummy() }.id(),
+            ]),
+            merged: ParserNode::LoyaltyCost { cost: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::PowerToughnessModElements(intermediates::PowerToughnessModElements::Minus {
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                    ParserNode::Number { number },
+                ] => Ok(ParserNode::LoyaltyCost {
+                    cost: loyalty::LoyaltyCost::Minus(loyalty::LoyaltyCostMinus {
+                        amount: number.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: number.node_span().merge(span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "0" loyalty cost, which is not signed */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::LexerToken(Token::Number(intermediates::Number::Number {
+                num: 0,
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id()]),
+            merged: ParserNode::LoyaltyCost { cost: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::Number(intermediates::Number::Number {
+                        num: 0,
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                ] => Ok(ParserNode::LoyaltyCost {
+                    cost: loyalty::LoyaltyCost::Zero(loyalty::LoyaltyCostZero {
+                        #[cfg(feature = "spanned_tree")]
+                        span: *span,
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
@@ -2,6 +2,7 @@ mod creature_performs_action;
 mod object_gains_state;
 mod permanent_performs_action;
 mod player_performs_action;
+mod zone_change_event;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
     [
@@ -9,6 +10,7 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
         object_gains_state::rules().collect::<Vec<_>>(),
         permanent_performs_action::rules().collect::<Vec<_>>(),
         player_performs_action::rules().collect::<Vec<_>>(),
+        zone_change_event::rules().collect::<Vec<_>>(),
     ]
     .into_iter()
     .flatten()
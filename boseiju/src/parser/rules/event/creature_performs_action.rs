@@ -2,6 +2,7 @@ mod creature_attacks_action;
 mod creature_blocks_action;
 mod creature_deals_damage_action;
 mod creature_dies_action;
+mod creature_is_dealt_damage_action;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
     [
@@ -9,6 +10,7 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
         creature_blocks_action::rules().collect::<Vec<_>>(),
         creature_deals_damage_action::rules().collect::<Vec<_>>(),
         creature_dies_action::rules().collect::<Vec<_>>(),
+        creature_is_dealt_damage_action::rules().collect::<Vec<_>>(),
     ]
     .into_iter()
     .flatten()
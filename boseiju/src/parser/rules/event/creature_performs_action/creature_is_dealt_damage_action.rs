@@ -0,0 +1,57 @@
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::rules::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* "<creature> is dealt <damage kind>" */
+    crate::ability_tree::terminals::DamageKind::all().map(|damage_kind| ParserRule {
+        expanded: RuleLhs::new(&[
+            ParserNode::Creature { creature: dummy() }.id(),
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::DamageKind(damage_kind)).id(),
+        ]),
+        merged: ParserNode::Event { event: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[
+                ParserNode::Creature { creature },
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is { .. })),
+                ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                ParserNode::LexerToken(Token::DamageKind(damage_kind)),
+            ] => Ok(ParserNode::Event {
+                event: crate::ability_tree::event::Event::CreaturePerformsAction(
+                    crate::ability_tree::event::CreaturePerformsActionEvent {
+                        action: crate::ability_tree::action::CreatureAction::IsDealtDamage(
+                            crate::ability_tree::action::CreatureIsDealtDamageAction {
+                                creature: creature.clone(),
+                                damage_kind: *damage_kind,
+                                #[cfg(feature = "spanned_tree")]
+                                span: creature.node_span().merge(&damage_kind.node_span()),
+                            },
+                        ),
+                        #[cfg(feature = "spanned_tree")]
+                        span: creature.node_span().merge(&damage_kind.node_span()),
+                    },
+                ),
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    })
+}
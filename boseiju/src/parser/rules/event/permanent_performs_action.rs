@@ -11,6 +11,129 @@ use idris::Idris;
 use crate::ability_tree::AbilityTreeNode;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* "<number> <counter> is put on <permanent>", also with "are" for "one or more +1/+1 counters are put on ~" */
+    let copulas = [
+        intermediates::EnglishKeyword::Is {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        intermediates::EnglishKeyword::Are {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+    ];
+    let counters_put_on_rules = crate::ability_tree::terminals::Counter::all()
+        .flat_map(move |counter| {
+            copulas.into_iter().map(move |copula| ParserRule {
+                expanded: RuleLhs::new(&[
+                    ParserNode::Number { number: dummy() }.id(),
+                    ParserNode::LexerToken(Token::Counter(counter.clone())).id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(copula)).id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::Permanent { permanent: dummy() }.id(),
+                ]),
+                merged: ParserNode::Event { event: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::Number { number },
+                        ParserNode::LexerToken(Token::Counter(counter)),
+                        ParserNode::LexerToken(Token::EnglishKeyword(
+                            intermediates::EnglishKeyword::Is { .. } | intermediates::EnglishKeyword::Are { .. },
+                        )),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On { .. })),
+                        ParserNode::Permanent { permanent },
+                    ] => Ok(ParserNode::Event {
+                        event: crate::ability_tree::event::Event::PermanentPerformsAction(
+                            crate::ability_tree::event::PermanentPerformsActionEvent {
+                                action: crate::ability_tree::action::PermanentAction::CountersPutOn(
+                                    crate::ability_tree::action::CountersPutOnPermanentAction {
+                                        permanent: permanent.clone(),
+                                        amount: number.clone(),
+                                        counter: Some(counter.clone()),
+                                        #[cfg(feature = "spanned_tree")]
+                                        span: number.node_span().merge(&permanent.node_span()),
+                                    },
+                                ),
+                                #[cfg(feature = "spanned_tree")]
+                                span: number.node_span().merge(&permanent.node_span()),
+                            },
+                        ),
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: ParserRuleDeclarationLocation::here(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    /* "<number> counter is put on <permanent>" without a kind of counter, for any counter */
+    let any_counter_put_on_rules = copulas
+        .into_iter()
+        .map(|copula| ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Number { number: dummy() }.id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Counter {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(copula)).id(),
+                ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Permanent { permanent: dummy() }.id(),
+            ]),
+            merged: ParserNode::Event { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Number { number },
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Counter { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(
+                        intermediates::EnglishKeyword::Is { .. } | intermediates::EnglishKeyword::Are { .. },
+                    )),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On { .. })),
+                    ParserNode::Permanent { permanent },
+                ] => Ok(ParserNode::Event {
+                    event: crate::ability_tree::event::Event::PermanentPerformsAction(
+                        crate::ability_tree::event::PermanentPerformsActionEvent {
+                            action: crate::ability_tree::action::PermanentAction::CountersPutOn(
+                                crate::ability_tree::action::CountersPutOnPermanentAction {
+                                    permanent: permanent.clone(),
+                                    amount: number.clone(),
+                                    counter: None,
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: number.node_span().merge(&permanent.node_span()),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: number.node_span().merge(&permanent.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        })
+        .collect::<Vec<_>>();
+
     [
         /* "<permanent reference> enters the battlefield" is a permanent etb event */
         ParserRule {
@@ -92,6 +215,198 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
+        /* "<permanent> becomes the target of <spell>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Become {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::Target {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Spell { spell: dummy() }.id(),
+            ]),
+            merged: ParserNode::Event { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Become { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The { .. })),
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::Target { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
+                    ParserNode::Spell { spell },
+                ] => Ok(ParserNode::Event {
+                    event: crate::ability_tree::event::Event::PermanentPerformsAction(
+                        crate::ability_tree::event::PermanentPerformsActionEvent {
+                            action: crate::ability_tree::action::PermanentAction::BecomesTarget(
+                                crate::ability_tree::action::PermanentBecomesTargetAction {
+                                    permanent: permanent.clone(),
+                                    source: Some(Box::new(spell.clone())),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: permanent.node_span().merge(&spell.node_span()),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(&spell.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<permanent> becomes the target of a spell or ability" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Become {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::Target {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::A {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Spell {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Ability {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::Event { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Become { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The { .. })),
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::Target { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::A { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Spell { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Ability {
+                        #[cfg(feature = "spanned_tree")]
+                            span: ability_span,
+                    })),
+                ] => Ok(ParserNode::Event {
+                    event: crate::ability_tree::event::Event::PermanentPerformsAction(
+                        crate::ability_tree::event::PermanentPerformsActionEvent {
+                            action: crate::ability_tree::action::PermanentAction::BecomesTarget(
+                                crate::ability_tree::action::PermanentBecomesTargetAction {
+                                    permanent: permanent.clone(),
+                                    source: None,
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: permanent.node_span().merge(ability_span),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(ability_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<permanent> is tapped for mana" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CardState(intermediates::CardState::Tapped {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::For {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Mana {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::Event { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is { .. })),
+                    ParserNode::LexerToken(Token::CardState(intermediates::CardState::Tapped { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::For { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Mana {
+                        #[cfg(feature = "spanned_tree")]
+                            span: mana_span,
+                    })),
+                ] => Ok(ParserNode::Event {
+                    event: crate::ability_tree::event::Event::PermanentPerformsAction(
+                        crate::ability_tree::event::PermanentPerformsActionEvent {
+                            action: crate::ability_tree::action::PermanentAction::TappedForMana(
+                                crate::ability_tree::action::PermanentTappedForManaAction {
+                                    permanent: permanent.clone(),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: permanent.node_span().merge(mana_span),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(mana_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
     ]
     .into_iter()
+    .chain(counters_put_on_rules)
+    .chain(any_counter_put_on_rules)
 }
@@ -1,10 +1,16 @@
 mod player_attacks_action;
 mod player_casts_spell_action;
+mod player_draws_card_action;
+mod player_life_actions;
+mod player_sacrifices_action;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
     [
         player_attacks_action::rules().collect::<Vec<_>>(),
         player_casts_spell_action::rules().collect::<Vec<_>>(),
+        player_draws_card_action::rules().collect::<Vec<_>>(),
+        player_life_actions::rules().collect::<Vec<_>>(),
+        player_sacrifices_action::rules().collect::<Vec<_>>(),
     ]
     .into_iter()
     .flatten()
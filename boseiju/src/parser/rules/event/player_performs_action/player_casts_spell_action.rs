@@ -11,45 +11,99 @@ use idris::Idris;
 use crate::ability_tree::AbilityTreeNode;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
-    /* "<player> cast <spell>" */
-    std::iter::once(ParserRule {
-        expanded: RuleLhs::new(&[
-            ParserNode::Player { player: dummy() }.id(),
-            ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
-                keyword_action: mtg_data::KeywordAction::Cast,
-                #[cfg(feature = "spanned_tree")]
-                span: Default::default(),
-            }))
-            .id(),
-            ParserNode::Spell { spell: dummy() }.id(),
-        ]),
-        merged: ParserNode::Event { event: dummy() }.id(),
-        reduction: |nodes: &[ParserNode]| match &nodes {
-            &[
-                ParserNode::Player { player },
+    [
+        /* "<player> cast <spell>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
                 ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
                     keyword_action: mtg_data::KeywordAction::Cast,
-                    ..
-                })),
-                ParserNode::Spell { spell },
-            ] => Ok(ParserNode::Event {
-                event: crate::ability_tree::event::Event::PlayerPerformsAction(
-                    crate::ability_tree::event::PlayerPerformsActionEvent {
-                        action: crate::ability_tree::action::PlayerAction::CastsSpell(
-                            crate::ability_tree::action::PlayerCastsSpellAction {
-                                player: player.clone(),
-                                spell: spell.clone(),
-                                #[cfg(feature = "spanned_tree")]
-                                span: player.node_span().merge(&spell.node_span()),
-                            },
-                        ),
-                        #[cfg(feature = "spanned_tree")]
-                        span: player.node_span().merge(&spell.node_span()),
-                    },
-                ),
-            }),
-            _ => Err("Provided tokens do not match rule definition"),
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Spell { spell: dummy() }.id(),
+            ]),
+            merged: ParserNode::Event { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                        keyword_action: mtg_data::KeywordAction::Cast,
+                        ..
+                    })),
+                    ParserNode::Spell { spell },
+                ] => Ok(ParserNode::Event {
+                    event: crate::ability_tree::event::Event::PlayerPerformsAction(
+                        crate::ability_tree::event::PlayerPerformsActionEvent {
+                            action: crate::ability_tree::action::PlayerAction::CastsSpell(
+                                crate::ability_tree::action::PlayerCastsSpellAction {
+                                    player: player.clone(),
+                                    spell: spell.clone(),
+                                    from_zone: None,
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: player.node_span().merge(&spell.node_span()),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: player.node_span().merge(&spell.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
         },
-        creation_loc: ParserRuleDeclarationLocation::here(),
-    })
+        /* "<player> cast <spell> from <zone>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                    keyword_action: mtg_data::KeywordAction::Cast,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Spell { spell: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::From {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::ZoneReference { zone: dummy() }.id(),
+            ]),
+            merged: ParserNode::Event { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                        keyword_action: mtg_data::KeywordAction::Cast,
+                        ..
+                    })),
+                    ParserNode::Spell { spell },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::From { .. })),
+                    ParserNode::ZoneReference { zone },
+                ] => Ok(ParserNode::Event {
+                    event: crate::ability_tree::event::Event::PlayerPerformsAction(
+                        crate::ability_tree::event::PlayerPerformsActionEvent {
+                            action: crate::ability_tree::action::PlayerAction::CastsSpell(
+                                crate::ability_tree::action::PlayerCastsSpellAction {
+                                    player: player.clone(),
+                                    spell: spell.clone(),
+                                    from_zone: Some(zone.clone()),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: player.node_span().merge(&zone.node_span()),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: player.node_span().merge(&zone.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
 }
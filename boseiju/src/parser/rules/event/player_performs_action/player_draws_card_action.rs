@@ -0,0 +1,135 @@
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::rules::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "<player> draw a card" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::A {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::Event { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::A { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card {
+                        #[cfg(feature = "spanned_tree")]
+                            span: card_span,
+                    })),
+                ] => Ok(ParserNode::Event {
+                    event: crate::ability_tree::event::Event::PlayerPerformsAction(
+                        crate::ability_tree::event::PlayerPerformsActionEvent {
+                            action: crate::ability_tree::action::PlayerAction::DrawsCard(
+                                crate::ability_tree::action::PlayerDrawsCardAction {
+                                    player: player.clone(),
+                                    card_rank_in_turn: None,
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: player.node_span().merge(card_span),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: player.node_span().merge(card_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<player> draw your second card each turn" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Second {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::Event { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw { .. })),
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Second { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card { .. })),
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                        #[cfg(feature = "spanned_tree")]
+                            span: turn_span,
+                    })),
+                ] => Ok(ParserNode::Event {
+                    event: crate::ability_tree::event::Event::PlayerPerformsAction(
+                        crate::ability_tree::event::PlayerPerformsActionEvent {
+                            action: crate::ability_tree::action::PlayerAction::DrawsCard(
+                                crate::ability_tree::action::PlayerDrawsCardAction {
+                                    player: player.clone(),
+                                    card_rank_in_turn: Some(2),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: player.node_span().merge(turn_span),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: player.node_span().merge(turn_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
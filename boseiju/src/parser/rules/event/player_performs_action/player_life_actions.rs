@@ -0,0 +1,103 @@
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::rules::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "<player> gain life" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::Event { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                        #[cfg(feature = "spanned_tree")]
+                            span: life_span,
+                    })),
+                ] => Ok(ParserNode::Event {
+                    event: crate::ability_tree::event::Event::PlayerPerformsAction(
+                        crate::ability_tree::event::PlayerPerformsActionEvent {
+                            action: crate::ability_tree::action::PlayerAction::GainsLife(
+                                crate::ability_tree::action::PlayerGainsLifeAction {
+                                    player: player.clone(),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: player.node_span().merge(life_span),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: player.node_span().merge(life_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<player> lose life" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Lose {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::Event { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Lose { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                        #[cfg(feature = "spanned_tree")]
+                            span: life_span,
+                    })),
+                ] => Ok(ParserNode::Event {
+                    event: crate::ability_tree::event::Event::PlayerPerformsAction(
+                        crate::ability_tree::event::PlayerPerformsActionEvent {
+                            action: crate::ability_tree::action::PlayerAction::LosesLife(
+                                crate::ability_tree::action::PlayerLosesLifeAction {
+                                    player: player.clone(),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: player.node_span().merge(life_span),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: player.node_span().merge(life_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
@@ -0,0 +1,55 @@
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::rules::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* "<player> sacrifice <permanent>" */
+    std::iter::once(ParserRule {
+        expanded: RuleLhs::new(&[
+            ParserNode::Player { player: dummy() }.id(),
+            ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                keyword_action: mtg_data::KeywordAction::Sacrifice,
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::Permanent { permanent: dummy() }.id(),
+        ]),
+        merged: ParserNode::Event { event: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[
+                ParserNode::Player { player },
+                ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                    keyword_action: mtg_data::KeywordAction::Sacrifice,
+                    ..
+                })),
+                ParserNode::Permanent { permanent },
+            ] => Ok(ParserNode::Event {
+                event: crate::ability_tree::event::Event::PlayerPerformsAction(
+                    crate::ability_tree::event::PlayerPerformsActionEvent {
+                        action: crate::ability_tree::action::PlayerAction::Sacrifices(
+                            crate::ability_tree::action::PlayerSacrificesAction {
+                                player: player.clone(),
+                                permanent: permanent.clone(),
+                                #[cfg(feature = "spanned_tree")]
+                                span: player.node_span().merge(&permanent.node_span()),
+                            },
+                        ),
+                        #[cfg(feature = "spanned_tree")]
+                        span: player.node_span().merge(&permanent.node_span()),
+                    },
+                ),
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    })
+}
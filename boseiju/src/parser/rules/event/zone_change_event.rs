@@ -0,0 +1,163 @@
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::rules::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* Cards can be put into zones with "is" or "are", as in "one or more creature cards are put into your graveyard" */
+    let copulas = [
+        intermediates::EnglishKeyword::Is {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        intermediates::EnglishKeyword::Are {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+    ];
+
+    let put_into_zone_rules = copulas.into_iter().flat_map(|copula| {
+        [
+            /* "<card> is put into <zone>" is a zone change event */
+            ParserRule {
+                expanded: RuleLhs::new(&[
+                    ParserNode::Card { card: dummy() }.id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(copula)).id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Into {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::ZoneReference { zone: dummy() }.id(),
+                ]),
+                merged: ParserNode::Event { event: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::Card { card },
+                        ParserNode::LexerToken(Token::EnglishKeyword(
+                            intermediates::EnglishKeyword::Is { .. } | intermediates::EnglishKeyword::Are { .. },
+                        )),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Into { .. })),
+                        ParserNode::ZoneReference { zone },
+                    ] => Ok(ParserNode::Event {
+                        event: crate::ability_tree::event::Event::ZoneChange(crate::ability_tree::event::ZoneChangeEvent {
+                            card: card.clone(),
+                            from: None,
+                            to: Some(zone.clone()),
+                            #[cfg(feature = "spanned_tree")]
+                            span: card.node_span().merge(&zone.node_span()),
+                        }),
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: ParserRuleDeclarationLocation::here(),
+            },
+            /* "<card> is put into <zone> from <zone>" is a zone change event */
+            ParserRule {
+                expanded: RuleLhs::new(&[
+                    ParserNode::Card { card: dummy() }.id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(copula)).id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Into {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::ZoneReference { zone: dummy() }.id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::From {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::ZoneReference { zone: dummy() }.id(),
+                ]),
+                merged: ParserNode::Event { event: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::Card { card },
+                        ParserNode::LexerToken(Token::EnglishKeyword(
+                            intermediates::EnglishKeyword::Is { .. } | intermediates::EnglishKeyword::Are { .. },
+                        )),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Into { .. })),
+                        ParserNode::ZoneReference { zone: to },
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::From { .. })),
+                        ParserNode::ZoneReference { zone: from },
+                    ] => Ok(ParserNode::Event {
+                        event: crate::ability_tree::event::Event::ZoneChange(crate::ability_tree::event::ZoneChangeEvent {
+                            card: card.clone(),
+                            from: Some(from.clone()),
+                            to: Some(to.clone()),
+                            #[cfg(feature = "spanned_tree")]
+                            span: card.node_span().merge(&from.node_span()),
+                        }),
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: ParserRuleDeclarationLocation::here(),
+            },
+        ]
+    });
+
+    [
+        /* "<permanent> leaves the battlefield" is a zone change event from the battlefield */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::CardActions(intermediates::CardActions::Leave {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::GlobalZone(intermediates::GlobalZone::TheBattlefield {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::Event { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::CardActions(intermediates::CardActions::Leave { .. })),
+                    ParserNode::LexerToken(Token::GlobalZone(intermediates::GlobalZone::TheBattlefield {
+                        #[cfg(feature = "spanned_tree")]
+                            span: battlefield_span,
+                    })),
+                ] => Ok(ParserNode::Event {
+                    event: crate::ability_tree::event::Event::ZoneChange(crate::ability_tree::event::ZoneChangeEvent {
+                        card: permanent.to_card(),
+                        from: Some(crate::ability_tree::zone::ZoneReference::TheBattlefield {
+                            #[cfg(feature = "spanned_tree")]
+                            span: *battlefield_span,
+                        }),
+                        to: None,
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(battlefield_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+    .chain(put_into_zone_rules)
+}
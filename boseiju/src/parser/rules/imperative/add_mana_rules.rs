@@ -34,6 +34,7 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                     imperative: crate::ability_tree::imperative::ImperativeKind::AddMana(
                         crate::ability_tree::imperative::AddManaImperative {
                             possibilities: [mana.clone()].into_iter().collect(),
+                            spend_restriction: None,
                             #[cfg(feature = "spanned_tree")]
                             span: mana.node_span().merge(add_span),
                         },
@@ -73,6 +74,7 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                     imperative: crate::ability_tree::imperative::ImperativeKind::AddMana(
                         crate::ability_tree::imperative::AddManaImperative {
                             possibilities: [m1.clone(), m2.clone()].into_iter().collect(),
+                            spend_restriction: None,
                             #[cfg(feature = "spanned_tree")]
                             span: m2.node_span().merge(add_span),
                         },
@@ -126,6 +128,7 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                     imperative: crate::ability_tree::imperative::ImperativeKind::AddMana(
                         crate::ability_tree::imperative::AddManaImperative {
                             possibilities: [m1.clone(), m2.clone(), m3.clone()].into_iter().collect(),
+                            spend_restriction: None,
                             #[cfg(feature = "spanned_tree")]
                             span: m3.node_span().merge(add_span),
                         },
@@ -13,8 +13,7 @@ use crate::ability_tree::AbilityTreeNode;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
     [
-        /* A single choice is presented with a newline, bullet and imperative. */
-        /* Fixme: hard limit on the number of choices ?  */
+        /* A single mode is presented with a newline, bullet and imperative. */
         ParserRule {
             expanded: RuleLhs::new(&[
                 ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::NewLine {
@@ -29,7 +28,7 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                 .id(),
                 ParserNode::SpellAbility { ability: dummy() }.id(),
             ]),
-            merged: ParserNode::ImperativeChoices { choices: dummy() }.id(),
+            merged: ParserNode::ModalMode { mode: dummy() }.id(),
             reduction: |nodes: &[ParserNode]| match &nodes {
                 &[
                     ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::NewLine {
@@ -38,13 +37,10 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                     })),
                     ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Bullet { .. })),
                     ParserNode::SpellAbility { ability },
-                ] => Ok(ParserNode::ImperativeChoices {
-                    choices: ImperativeChoices {
-                        choices: {
-                            let mut choices = crate::utils::HeapArrayVec::new();
-                            choices.push(ability.clone());
-                            choices
-                        },
+                ] => Ok(ParserNode::ModalMode {
+                    mode: crate::ability_tree::imperative::ModalMode {
+                        additional_cost: None,
+                        effect: ability.clone(),
                         #[cfg(feature = "spanned_tree")]
                         span: ability.node_span().merge(new_line_span),
                     },
@@ -53,10 +49,9 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
-        /* Add choices to choices */
+        /* Modes can have their own additional cost: "• {1} — <spell ability>" */
         ParserRule {
             expanded: RuleLhs::new(&[
-                ParserNode::ImperativeChoices { choices: dummy() }.id(),
                 ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::NewLine {
                     #[cfg(feature = "spanned_tree")]
                     span: Default::default(),
@@ -67,19 +62,131 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                     span: Default::default(),
                 }))
                 .id(),
+                ParserNode::Cost { cost: dummy() }.id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::LongDash {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
                 ParserNode::SpellAbility { ability: dummy() }.id(),
             ]),
-            merged: ParserNode::ImperativeChoices { choices: dummy() }.id(),
+            merged: ParserNode::ModalMode { mode: dummy() }.id(),
             reduction: |nodes: &[ParserNode]| match &nodes {
                 &[
-                    ParserNode::ImperativeChoices { choices },
-                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::NewLine { .. })),
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::NewLine {
+                        #[cfg(feature = "spanned_tree")]
+                            span: new_line_span,
+                    })),
                     ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Bullet { .. })),
+                    ParserNode::Cost { cost },
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::LongDash { .. })),
+                    ParserNode::SpellAbility { ability },
+                ] => Ok(ParserNode::ModalMode {
+                    mode: crate::ability_tree::imperative::ModalMode {
+                        additional_cost: Some(cost.clone()),
+                        effect: ability.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: ability.node_span().merge(new_line_span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* Spree modes use a plus sign instead of a bullet: "+ {1} — <spell ability>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::NewLine {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::PowerToughnessModElements(
+                    intermediates::PowerToughnessModElements::Plus {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    },
+                ))
+                .id(),
+                ParserNode::Cost { cost: dummy() }.id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::LongDash {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::SpellAbility { ability: dummy() }.id(),
+            ]),
+            merged: ParserNode::ModalMode { mode: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::NewLine {
+                        #[cfg(feature = "spanned_tree")]
+                            span: new_line_span,
+                    })),
+                    ParserNode::LexerToken(Token::PowerToughnessModElements(intermediates::PowerToughnessModElements::Plus {
+                        ..
+                    })),
+                    ParserNode::Cost { cost },
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::LongDash { .. })),
                     ParserNode::SpellAbility { ability },
+                ] => Ok(ParserNode::ModalMode {
+                    mode: crate::ability_tree::imperative::ModalMode {
+                        additional_cost: Some(cost.clone()),
+                        effect: ability.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: ability.node_span().merge(new_line_span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* A single mode makes up choices */
+        /* Fixme: hard limit on the number of choices ?  */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::ModalMode { mode: dummy() }.id()]),
+            merged: ParserNode::ImperativeChoices { choices: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::ModalMode { mode }] => Ok(ParserNode::ImperativeChoices {
+                    choices: ImperativeChoices {
+                        choices: {
+                            let mut choices = crate::utils::HeapArrayVec::new();
+                            choices.push(mode.clone());
+                            choices
+                        },
+                        #[cfg(feature = "spanned_tree")]
+                        span: mode.node_span(),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* Add modes to choices, each previous mode being ended by a dot */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::ImperativeChoices { choices: dummy() }.id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Dot {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::ModalMode { mode: dummy() }.id(),
+            ]),
+            merged: ParserNode::ImperativeChoices { choices: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::ImperativeChoices { choices },
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Dot { .. })),
+                    ParserNode::ModalMode { mode },
                 ] => Ok(ParserNode::ImperativeChoices {
                     choices: {
                         let mut choices = choices.clone();
-                        choices.choices.push(ability.clone());
+                        choices.choices.push(mode.clone());
+                        #[cfg(feature = "spanned_tree")]
+                        {
+                            choices.span = choices.span.merge(&mode.node_span());
+                        }
                         choices
                     },
                 }),
@@ -87,7 +194,49 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
-        /* From a choose clause and choices, we can make a choose imperative */
+        /* "choose <number> — <choices>" is a modal imperative */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Choose {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Number { number: dummy() }.id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::LongDash {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::ImperativeChoices { choices: dummy() }.id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Choose {
+                        #[cfg(feature = "spanned_tree")]
+                            span: choose_span,
+                    })),
+                    ParserNode::Number { number },
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::LongDash { .. })),
+                    ParserNode::ImperativeChoices { choices },
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::Modal(
+                        crate::ability_tree::imperative::ModalImperative {
+                            mode_count: number.clone(),
+                            can_choose_same_mode: false,
+                            only_unchosen_modes: false,
+                            modes: choices.choices.clone(),
+                            #[cfg(feature = "spanned_tree")]
+                            span: choose_span.merge(&choices.span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "choose <number> that hasn't been chosen — <choices>" can only choose new modes */
         ParserRule {
             expanded: RuleLhs::new(&[
                 ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Choose {
@@ -96,6 +245,16 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                 }))
                 .id(),
                 ParserNode::Number { number: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::ChoiceReference(intermediates::ChoiceReference::HasntBeenChosen {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
                 ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::LongDash {
                     #[cfg(feature = "spanned_tree")]
                     span: Default::default(),
@@ -103,7 +262,7 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                 .id(),
                 ParserNode::ImperativeChoices { choices: dummy() }.id(),
             ]),
-            merged: ParserNode::Imperative { imperative: dummy() }.id(),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
             reduction: |nodes: &[ParserNode]| match &nodes {
                 &[
                     ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Choose {
@@ -111,6 +270,8 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                             span: choose_span,
                     })),
                     ParserNode::Number { number },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That { .. })),
+                    ParserNode::LexerToken(Token::ChoiceReference(intermediates::ChoiceReference::HasntBeenChosen { .. })),
                     ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::LongDash { .. })),
                     ParserNode::ImperativeChoices { choices },
                 ] => Ok(ParserNode::ImperativeKind {
@@ -118,6 +279,97 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                         crate::ability_tree::imperative::ModalImperative {
                             mode_count: number.clone(),
                             can_choose_same_mode: false,
+                            only_unchosen_modes: true,
+                            modes: choices.choices.clone(),
+                            #[cfg(feature = "spanned_tree")]
+                            span: choose_span.merge(&choices.span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "choose <number>. you may choose the same mode more than once. <choices>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Choose {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Number { number: dummy() }.id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Dot {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::PlayerSpecifier(intermediates::PlayerSpecifier::You {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::May {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Choose {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Same {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Mode {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::MoreThanOnce {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Dot {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::ImperativeChoices { choices: dummy() }.id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Choose {
+                        #[cfg(feature = "spanned_tree")]
+                            span: choose_span,
+                    })),
+                    ParserNode::Number { number },
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Dot { .. })),
+                    ParserNode::LexerToken(Token::PlayerSpecifier(intermediates::PlayerSpecifier::You { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::May { .. })),
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Choose { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Same { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Mode { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::MoreThanOnce { .. })),
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Dot { .. })),
+                    ParserNode::ImperativeChoices { choices },
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::Modal(
+                        crate::ability_tree::imperative::ModalImperative {
+                            mode_count: number.clone(),
+                            can_choose_same_mode: true,
+                            only_unchosen_modes: false,
                             modes: choices.choices.clone(),
                             #[cfg(feature = "spanned_tree")]
                             span: choose_span.merge(&choices.span),
@@ -67,6 +67,137 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
+        /* "<card reference> deals damage equal to <game state number> to <damage receiver>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Card { card: dummy() }.id(),
+                ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::DamageKind(terminals::DamageKind::Damage {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::XDefinition { definition: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::DamageReceiver { receiver: dummy() }.id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Card { card: dealer },
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                    ParserNode::LexerToken(Token::DamageKind(terminals::DamageKind::Damage { .. })),
+                    ParserNode::XDefinition { definition },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
+                    ParserNode::DamageReceiver { receiver: to },
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::DealsDamage(
+                        crate::ability_tree::imperative::DealsDamageImperative {
+                            dealer: dealer.clone(),
+                            damages: {
+                                let mut damages = crate::utils::HeapArrayVec::new();
+                                damages.push(crate::ability_tree::imperative::DamagesDealt {
+                                    to: to.clone(),
+                                    amount: crate::ability_tree::number::Number::X(crate::ability_tree::number::XNumber {
+                                        x_definition: Box::new(definition.clone()),
+                                        #[cfg(feature = "spanned_tree")]
+                                        span: definition.node_span(),
+                                    }),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: definition.node_span().merge(&to.node_span()),
+                                });
+                                damages
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: dealer.node_span().merge(&definition.node_span()).merge(&to.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<card reference> deals X damage to <damage receiver>, where X is <game state number>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Card { card: dummy() }.id(),
+                ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::Number(intermediates::Number::X {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::DamageKind(terminals::DamageKind::Damage {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::DamageReceiver { receiver: dummy() }.id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::XDefinition { definition: dummy() }.id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Card { card: dealer },
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                    ParserNode::LexerToken(Token::Number(intermediates::Number::X {
+                        #[cfg(feature = "spanned_tree")]
+                            span: x_span,
+                    })),
+                    ParserNode::LexerToken(Token::DamageKind(terminals::DamageKind::Damage { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
+                    ParserNode::DamageReceiver { receiver: to },
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
+                    ParserNode::XDefinition { definition },
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::DealsDamage(
+                        crate::ability_tree::imperative::DealsDamageImperative {
+                            dealer: dealer.clone(),
+                            damages: {
+                                let mut damages = crate::utils::HeapArrayVec::new();
+                                damages.push(crate::ability_tree::imperative::DamagesDealt {
+                                    to: to.clone(),
+                                    amount: crate::ability_tree::number::Number::X(crate::ability_tree::number::XNumber {
+                                        x_definition: Box::new(definition.clone()),
+                                        #[cfg(feature = "spanned_tree")]
+                                        span: definition.node_span().merge(x_span),
+                                    }),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: definition.node_span().merge(&to.node_span()),
+                                });
+                                damages
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: dealer.node_span().merge(&definition.node_span()).merge(&to.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
         // /* Sometimes, objects have two damage actions */
         // ParserRule {
         //     expanded: RuleLhs::new(&[
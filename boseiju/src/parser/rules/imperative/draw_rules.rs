@@ -7,45 +7,148 @@ use crate::parser::rules::RuleLhs;
 use crate::utils::dummy;
 use idris::Idris;
 
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
-    /* "Draw <number> cards" makes a draw card imperative */
-    std::iter::once(ParserRule {
-        expanded: RuleLhs::new(&[
-            ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw {
-                #[cfg(feature = "spanned_tree")]
-                span: Default::default(),
-            }))
-            .id(),
-            ParserNode::Number { number: dummy() }.id(),
-            ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card {
-                #[cfg(feature = "spanned_tree")]
-                span: Default::default(),
-            }))
-            .id(),
-        ]),
-        merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
-        reduction: |nodes: &[ParserNode]| match &nodes {
-            &[
+    [
+        /* "Draw <number> cards" makes a draw card imperative */
+        ParserRule {
+            expanded: RuleLhs::new(&[
                 ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw {
                     #[cfg(feature = "spanned_tree")]
-                        span: start_span,
-                })),
-                ParserNode::Number { number },
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Number { number: dummy() }.id(),
                 ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card {
                     #[cfg(feature = "spanned_tree")]
-                        span: end_span,
-                })),
-            ] => Ok(ParserNode::ImperativeKind {
-                imperative: crate::ability_tree::imperative::ImperativeKind::Draw(
-                    crate::ability_tree::imperative::DrawImperative {
-                        amount: number.clone(),
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::Number { number },
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::Draw(
+                        crate::ability_tree::imperative::DrawImperative {
+                            amount: number.clone(),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(end_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "draw cards equal to <game state number>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::XDefinition { definition: dummy() }.id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card { .. })),
+                    ParserNode::XDefinition { definition },
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::Draw(
+                        crate::ability_tree::imperative::DrawImperative {
+                            amount: crate::ability_tree::number::Number::X(crate::ability_tree::number::XNumber {
+                                x_definition: Box::new(definition.clone()),
+                                #[cfg(feature = "spanned_tree")]
+                                span: definition.node_span(),
+                            }),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(&definition.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "draw X cards, where X is <game state number>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::Number(intermediates::Number::X {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::XDefinition { definition: dummy() }.id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::Number(intermediates::Number::X {
                         #[cfg(feature = "spanned_tree")]
-                        span: start_span.merge(end_span),
-                    },
-                ),
-            }),
-            _ => Err("Provided tokens do not match rule definition"),
+                            span: x_span,
+                    })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card { .. })),
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
+                    ParserNode::XDefinition { definition },
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::Draw(
+                        crate::ability_tree::imperative::DrawImperative {
+                            amount: crate::ability_tree::number::Number::X(crate::ability_tree::number::XNumber {
+                                x_definition: Box::new(definition.clone()),
+                                #[cfg(feature = "spanned_tree")]
+                                span: definition.node_span().merge(x_span),
+                            }),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(&definition.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
         },
-        creation_loc: ParserRuleDeclarationLocation::here(),
-    })
+    ]
+    .into_iter()
 }
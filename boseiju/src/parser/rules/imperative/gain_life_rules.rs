@@ -7,45 +7,148 @@ use crate::parser::rules::RuleLhs;
 use crate::utils::dummy;
 use idris::Idris;
 
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
-    /* "Gain <number> life" makes a gain life imperative. */
-    std::iter::once(ParserRule {
-        expanded: RuleLhs::new(&[
-            ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
-                #[cfg(feature = "spanned_tree")]
-                span: Default::default(),
-            }))
-            .id(),
-            ParserNode::Number { number: dummy() }.id(),
-            ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
-                #[cfg(feature = "spanned_tree")]
-                span: Default::default(),
-            }))
-            .id(),
-        ]),
-        merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
-        reduction: |nodes: &[ParserNode]| match &nodes {
-            &[
+    [
+        /* "Gain <number> life" makes a gain life imperative. */
+        ParserRule {
+            expanded: RuleLhs::new(&[
                 ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
                     #[cfg(feature = "spanned_tree")]
-                        span: start_span,
-                })),
-                ParserNode::Number { number },
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Number { number: dummy() }.id(),
                 ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
                     #[cfg(feature = "spanned_tree")]
-                        span: end_span,
-                })),
-            ] => Ok(ParserNode::ImperativeKind {
-                imperative: crate::ability_tree::imperative::ImperativeKind::GainLife(
-                    crate::ability_tree::imperative::GainLifeImperative {
-                        amount: number.clone(),
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::Number { number },
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::GainLife(
+                        crate::ability_tree::imperative::GainLifeImperative {
+                            amount: number.clone(),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(end_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "gain life equal to <game state number>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::XDefinition { definition: dummy() }.id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life { .. })),
+                    ParserNode::XDefinition { definition },
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::GainLife(
+                        crate::ability_tree::imperative::GainLifeImperative {
+                            amount: crate::ability_tree::number::Number::X(crate::ability_tree::number::XNumber {
+                                x_definition: Box::new(definition.clone()),
+                                #[cfg(feature = "spanned_tree")]
+                                span: definition.node_span(),
+                            }),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(&definition.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "gain X life, where X is <game state number>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::Number(intermediates::Number::X {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::XDefinition { definition: dummy() }.id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::Number(intermediates::Number::X {
                         #[cfg(feature = "spanned_tree")]
-                        span: start_span.merge(end_span),
-                    },
-                ),
-            }),
-            _ => Err("Provided tokens do not match rule definition"),
+                            span: x_span,
+                    })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life { .. })),
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
+                    ParserNode::XDefinition { definition },
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::GainLife(
+                        crate::ability_tree::imperative::GainLifeImperative {
+                            amount: crate::ability_tree::number::Number::X(crate::ability_tree::number::XNumber {
+                                x_definition: Box::new(definition.clone()),
+                                #[cfg(feature = "spanned_tree")]
+                                span: definition.node_span().merge(x_span),
+                            }),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(&definition.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
         },
-        creation_loc: ParserRuleDeclarationLocation::here(),
-    })
+    ]
+    .into_iter()
 }
@@ -14,14 +14,10 @@ use crate::ability_tree::AbilityTreeNode;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
     [
-        /* "Until end of turn, <continuous effect>" makes a generated continuous effect. */
+        /* "<duration>, <continuous effect>" makes a generated continuous effect, as in "until end of turn, ...". */
         ParserRule {
             expanded: RuleLhs::new(&[
-                ParserNode::LexerToken(Token::ForwardDuration(time::ForwardDuration::UntilEndOfTurn {
-                    #[cfg(feature = "spanned_tree")]
-                    span: Default::default(),
-                }))
-                .id(),
+                ParserNode::ForwardDuration { duration: dummy() }.id(),
                 ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
                     #[cfg(feature = "spanned_tree")]
                     span: Default::default(),
@@ -32,22 +28,16 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
             reduction: |nodes: &[ParserNode]| match &nodes {
                 &[
-                    ParserNode::LexerToken(Token::ForwardDuration(time::ForwardDuration::UntilEndOfTurn {
-                        #[cfg(feature = "spanned_tree")]
-                            span: start_span,
-                    })),
+                    ParserNode::ForwardDuration { duration },
                     ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
                     ParserNode::ContinuousEffect { effect },
                 ] => Ok(ParserNode::ImperativeKind {
                     imperative: crate::ability_tree::imperative::ImperativeKind::GenerateContinuousEffect(
                         crate::ability_tree::imperative::GenerateContinuousEffectImperative {
                             effect: effect.clone(),
-                            duration: time::ForwardDuration::UntilEndOfTurn {
-                                #[cfg(feature = "spanned_tree")]
-                                span: *start_span,
-                            },
+                            duration: *duration,
                             #[cfg(feature = "spanned_tree")]
-                            span: effect.node_span().merge(start_span),
+                            span: effect.node_span().merge(&duration.node_span()),
                         },
                     ),
                 }),
@@ -55,11 +45,36 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
-        /* "<continuous effect> until end of turn" makes a generated continuous effect. */
+        /* "<continuous effect> <duration>" makes a generated continuous effect, as in "... until end of turn". */
         ParserRule {
             expanded: RuleLhs::new(&[
                 ParserNode::ContinuousEffect { effect: dummy() }.id(),
-                ParserNode::LexerToken(Token::ForwardDuration(time::ForwardDuration::UntilEndOfTurn {
+                ParserNode::ForwardDuration { duration: dummy() }.id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::ContinuousEffect { effect },
+                    ParserNode::ForwardDuration { duration },
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::GenerateContinuousEffect(
+                        crate::ability_tree::imperative::GenerateContinuousEffectImperative {
+                            effect: effect.clone(),
+                            duration: *duration,
+                            #[cfg(feature = "spanned_tree")]
+                            span: effect.node_span().merge(&duration.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<continuous effect> this turn" makes a generated continuous effect, as in "prevent all combat damage that would be dealt this turn". */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::ContinuousEffect { effect: dummy() }.id(),
+                ParserNode::LexerToken(Token::BackwardDuration(time::BackwardDuration::ThisTurn {
                     #[cfg(feature = "spanned_tree")]
                     span: Default::default(),
                 }))
@@ -69,7 +84,7 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             reduction: |nodes: &[ParserNode]| match &nodes {
                 &[
                     ParserNode::ContinuousEffect { effect },
-                    ParserNode::LexerToken(Token::ForwardDuration(time::ForwardDuration::UntilEndOfTurn {
+                    ParserNode::LexerToken(Token::BackwardDuration(time::BackwardDuration::ThisTurn {
                         #[cfg(feature = "spanned_tree")]
                             span: end_span,
                     })),
@@ -166,6 +181,67 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
+        /* "gain control of <permanent>" without duration makes a control-changing effect that lasts forever */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Control {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Permanent { permanent: dummy() }.id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
+                        #[cfg(feature = "spanned_tree")]
+                            span: gain_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Control { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
+                    ParserNode::Permanent { permanent },
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::GenerateContinuousEffect(
+                        crate::ability_tree::imperative::GenerateContinuousEffectImperative {
+                            effect: continuous_effect::ContinuousEffect {
+                                effect: continuous_effect::ContinuousEffectKind::ChangeControl(
+                                    continuous_effect::ChangeControlEffect {
+                                        object: permanent.clone(),
+                                        new_controller: crate::ability_tree::player::PlayerSpecifier::You {
+                                            #[cfg(feature = "spanned_tree")]
+                                            span: gain_span.empty_at_start(),
+                                        },
+                                        #[cfg(feature = "spanned_tree")]
+                                        span: gain_span.merge(&permanent.node_span()),
+                                    },
+                                ),
+                                #[cfg(feature = "spanned_tree")]
+                                span: gain_span.merge(&permanent.node_span()),
+                            },
+                            duration: time::ForwardDuration::Forever {
+                                #[cfg(feature = "spanned_tree")]
+                                span: permanent.node_span().empty_at_end(),
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: gain_span.merge(&permanent.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
     ]
     .into_iter()
 }
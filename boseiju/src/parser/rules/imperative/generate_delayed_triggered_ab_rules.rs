@@ -56,6 +56,43 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
+        /* "the next time <event>, <spell ability>" is a delayed triggered ability without the "at" keyword */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::IncomingInstant { instant: dummy() }.id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::SpellAbility { ability: dummy() }.id(),
+            ]),
+            merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::IncomingInstant {
+                        instant: instant @ crate::ability_tree::time::IncomingInstant::NextTime(_),
+                    },
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
+                    ParserNode::SpellAbility { ability },
+                ] => Ok(ParserNode::ImperativeKind {
+                    imperative: crate::ability_tree::imperative::ImperativeKind::GenerateDelayedTriggeredAbility(
+                        crate::ability_tree::imperative::GenerateDelayedTriggeredAbilityImperative {
+                            ability: crate::ability_tree::ability::triggered::DelayedTriggerAbility {
+                                instant: instant.clone(),
+                                effect: ability.clone(),
+                                #[cfg(feature = "spanned_tree")]
+                                span: ability.node_span().merge(&instant.node_span()),
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: ability.node_span().merge(&instant.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
     ]
     .into_iter()
 }
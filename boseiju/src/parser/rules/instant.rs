@@ -1,5 +1,9 @@
 use super::ParserNode;
+use super::ParserRule;
+use super::ParserRuleDeclarationLocation;
+use super::RuleLhs;
 use crate::ability_tree::terminals;
+use crate::ability_tree::time;
 use crate::lexer::tokens::Token;
 use crate::lexer::tokens::intermediates;
 use crate::utils::dummy;
@@ -59,180 +63,645 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             span: Default::default(),
         },
     ];
-    let steps_to_owned_instants = all_steps.into_iter().flat_map(|step| {
-        [
-            /* "the beginning of your <step>" is a owned instant */
-            super::ParserRule {
-                expanded: super::RuleLhs::new(&[
+    let all_phases = [
+        terminals::Phase::Beginning {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        terminals::Phase::PrecombatMain {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        terminals::Phase::Combat {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        terminals::Phase::PostcombatMain {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        terminals::Phase::End {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+    ];
+
+    let steps_to_step_or_phase = all_steps.into_iter().map(|step| ParserRule {
+        expanded: RuleLhs::new(&[ParserNode::LexerToken(Token::Step(step)).id()]),
+        merged: ParserNode::StepOrPhase { step_or_phase: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[ParserNode::LexerToken(Token::Step(step))] => Ok(ParserNode::StepOrPhase {
+                step_or_phase: time::StepOrPhase::Step(*step),
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    });
+
+    let phases_to_step_or_phase = all_phases.into_iter().map(|phase| ParserRule {
+        expanded: RuleLhs::new(&[ParserNode::LexerToken(Token::Phase(phase)).id()]),
+        merged: ParserNode::StepOrPhase { step_or_phase: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[ParserNode::LexerToken(Token::Phase(phase))] => Ok(ParserNode::StepOrPhase {
+                step_or_phase: time::StepOrPhase::Phase(*phase),
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    });
+
+    let instant_rules = [
+        /* "the beginning of your <step or phase>" is a recurrent instant for the "you" player */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::StepOrPhase { step_or_phase: dummy() }.id(),
+            ]),
+            merged: ParserNode::RecurrentInstant { instant: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
                     ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
                         #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
-                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning {
-                        #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
-                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
-                        #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
                     ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
                         #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
-                    ParserNode::LexerToken(Token::Step(step)).id(),
-                ]),
-                merged: ParserNode::RecurrentInstant { instant: dummy() }.id(),
-                reduction: |nodes: &[ParserNode]| match &nodes {
-                    &[
-                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
-                            #[cfg(feature = "spanned_tree")]
-                                span: start_span,
-                        })),
-                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning { .. })),
-                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
-                        ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                            span: player_span,
+                    })),
+                    ParserNode::StepOrPhase { step_or_phase },
+                ] => Ok(ParserNode::RecurrentInstant {
+                    instant: time::RecurrentInstant {
+                        step_or_phase: step_or_phase.clone(),
+                        owner: crate::ability_tree::player::PlayerSpecifier::You {
                             #[cfg(feature = "spanned_tree")]
-                                span: player_span,
-                        })),
-                        ParserNode::LexerToken(Token::Step(step)),
-                    ] => Ok(ParserNode::RecurrentInstant {
-                        instant: crate::ability_tree::time::RecurrentInstant {
-                            step_or_phase: crate::ability_tree::time::StepOrPhase::Step(step.clone()),
-                            owner: crate::ability_tree::player::PlayerSpecifier::You {
-                                #[cfg(feature = "spanned_tree")]
-                                span: *player_span,
-                            },
+                            span: *player_span,
+                        },
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(&step_or_phase.node_span()),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "the beginning of each <step or phase>" is a recurrent instant for all players */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::StepOrPhase { step_or_phase: dummy() }.id(),
+            ]),
+            merged: ParserNode::RecurrentInstant { instant: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                        #[cfg(feature = "spanned_tree")]
+                            span: player_span,
+                    })),
+                    ParserNode::StepOrPhase { step_or_phase },
+                ] => Ok(ParserNode::RecurrentInstant {
+                    instant: time::RecurrentInstant {
+                        step_or_phase: step_or_phase.clone(),
+                        owner: crate::ability_tree::player::PlayerSpecifier::All {
                             #[cfg(feature = "spanned_tree")]
-                            span: start_span.merge(&step.node_span()),
+                            span: *player_span,
                         },
-                    }),
-                    _ => Err("Provided tokens do not match rule definition"),
-                },
-                creation_loc: super::ParserRuleDeclarationLocation::here(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(&step_or_phase.node_span()),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
             },
-            /* "the beginning of each <step>" is a owned instant for all players */
-            super::ParserRule {
-                expanded: super::RuleLhs::new(&[
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "the beginning of <player>'s <step or phase>" is a recurrent instant for that player, as in "each opponent's upkeep" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::StepOrPhase { step_or_phase: dummy() }.id(),
+            ]),
+            merged: ParserNode::RecurrentInstant { instant: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
                     ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
                         #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
-                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning {
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS { .. })),
+                    ParserNode::StepOrPhase { step_or_phase },
+                ] => Ok(ParserNode::RecurrentInstant {
+                    instant: time::RecurrentInstant {
+                        step_or_phase: step_or_phase.clone(),
+                        owner: player.clone(),
                         #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
-                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                        span: start_span.merge(&step_or_phase.node_span()),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "the <step or phase> on your turn" is a recurrent instant for the "you" player, as in "the beginning of combat on your turn" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::StepOrPhase { step_or_phase: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::RecurrentInstant { instant: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
                         #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
-                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                            span: start_span,
+                    })),
+                    ParserNode::StepOrPhase { step_or_phase },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On { .. })),
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
                         #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
-                    ParserNode::LexerToken(Token::Step(step)).id(),
-                ]),
-                merged: ParserNode::RecurrentInstant { instant: dummy() }.id(),
-                reduction: |nodes: &[ParserNode]| match &nodes {
-                    &[
-                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
-                            #[cfg(feature = "spanned_tree")]
-                                span: start_span,
-                        })),
-                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning { .. })),
-                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
-                        ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
-                            #[cfg(feature = "spanned_tree")]
-                                span: player_span,
-                        })),
-                        ParserNode::LexerToken(Token::Step(step)),
-                    ] => Ok(ParserNode::RecurrentInstant {
-                        instant: crate::ability_tree::time::RecurrentInstant {
-                            step_or_phase: crate::ability_tree::time::StepOrPhase::Step(step.clone()),
-                            owner: crate::ability_tree::player::PlayerSpecifier::All {
-                                #[cfg(feature = "spanned_tree")]
-                                span: *player_span,
-                            },
+                            span: player_span,
+                    })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                        #[cfg(feature = "spanned_tree")]
+                            span: turn_span,
+                    })),
+                ] => Ok(ParserNode::RecurrentInstant {
+                    instant: time::RecurrentInstant {
+                        step_or_phase: step_or_phase.clone(),
+                        owner: crate::ability_tree::player::PlayerSpecifier::You {
                             #[cfg(feature = "spanned_tree")]
-                            span: start_span.merge(&step.node_span()),
+                            span: *player_span,
                         },
-                    }),
-                    _ => Err("Provided tokens do not match rule definition"),
-                },
-                creation_loc: super::ParserRuleDeclarationLocation::here(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(turn_span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
             },
-            /* "the beginning of your next <step>" is an incoming instant for "you" player */
-            super::ParserRule {
-                expanded: super::RuleLhs::new(&[
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "the <step or phase> on <player>'s turn" is a recurrent instant for that player */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::StepOrPhase { step_or_phase: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::RecurrentInstant { instant: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
                     ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
                         #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
-                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning {
+                            span: start_span,
+                    })),
+                    ParserNode::StepOrPhase { step_or_phase },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On { .. })),
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
                         #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
-                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                            span: turn_span,
+                    })),
+                ] => Ok(ParserNode::RecurrentInstant {
+                    instant: time::RecurrentInstant {
+                        step_or_phase: step_or_phase.clone(),
+                        owner: player.clone(),
                         #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
+                        span: start_span.merge(turn_span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "the beginning of your next <step or phase>" is an incoming instant for the "you" player */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Next {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::StepOrPhase { step_or_phase: dummy() }.id(),
+            ]),
+            merged: ParserNode::IncomingInstant { instant: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
                     ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
                         #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
-                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Next {
-                        #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    }))
-                    .id(),
-                    ParserNode::LexerToken(Token::Step(step)).id(),
-                ]),
-                merged: ParserNode::IncomingInstant { instant: dummy() }.id(),
-                reduction: |nodes: &[ParserNode]| match &nodes {
-                    &[
-                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                            span: player_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Next { .. })),
+                    ParserNode::StepOrPhase { step_or_phase },
+                ] => Ok(ParserNode::IncomingInstant {
+                    instant: time::IncomingInstant::NextStepOrPhase(time::IncomingNextStepOrPhase {
+                        step_or_phase: step_or_phase.clone(),
+                        owner: crate::ability_tree::player::PlayerSpecifier::You {
                             #[cfg(feature = "spanned_tree")]
-                                span: start_span,
-                        })),
-                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning { .. })),
-                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
-                        ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                            span: *player_span,
+                        },
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(&step_or_phase.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "the beginning of <player>'s next <step or phase>" is an incoming instant for that player */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Next {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::StepOrPhase { step_or_phase: dummy() }.id(),
+            ]),
+            merged: ParserNode::IncomingInstant { instant: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Next { .. })),
+                    ParserNode::StepOrPhase { step_or_phase },
+                ] => Ok(ParserNode::IncomingInstant {
+                    instant: time::IncomingInstant::NextStepOrPhase(time::IncomingNextStepOrPhase {
+                        step_or_phase: step_or_phase.clone(),
+                        owner: player.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(&step_or_phase.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "the beginning of the next <step or phase>" is an incoming instant, regardless of the turn it happens in */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::TheNext {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::StepOrPhase { step_or_phase: dummy() }.id(),
+            ]),
+            merged: ParserNode::IncomingInstant { instant: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Beginning { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::TheNext {
+                        #[cfg(feature = "spanned_tree")]
+                            span: player_span,
+                    })),
+                    ParserNode::StepOrPhase { step_or_phase },
+                ] => Ok(ParserNode::IncomingInstant {
+                    instant: time::IncomingInstant::NextStepOrPhase(time::IncomingNextStepOrPhase {
+                        step_or_phase: step_or_phase.clone(),
+                        owner: crate::ability_tree::player::PlayerSpecifier::Any {
                             #[cfg(feature = "spanned_tree")]
-                                span: player_span,
-                        })),
-                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Next { .. })),
-                        ParserNode::LexerToken(Token::Step(step)),
-                    ] => Ok(ParserNode::IncomingInstant {
-                        instant: crate::ability_tree::time::IncomingInstant::NextStepOrPhase(
-                            crate::ability_tree::time::IncomingNextStepOrPhase {
-                                step_or_phase: crate::ability_tree::time::StepOrPhase::Step(step.clone()),
-                                owner: crate::ability_tree::player::PlayerSpecifier::You {
-                                    #[cfg(feature = "spanned_tree")]
-                                    span: *player_span,
-                                },
+                            span: *player_span,
+                        },
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(&step_or_phase.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "the next time <event>" is an incoming instant for the next occurrence of the event */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::TheNext {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Time {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Event { event: dummy() }.id(),
+            ]),
+            merged: ParserNode::IncomingInstant { instant: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::TheNext {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Time { .. })),
+                    ParserNode::Event { event },
+                ] => Ok(ParserNode::IncomingInstant {
+                    instant: time::IncomingInstant::NextTime(time::IncomingNextTime {
+                        event: Box::new(event.clone()),
+                        this_turn_only: false,
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(&event.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "the next time <event> this turn" only looks for the event during the current turn */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::TheNext {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Time {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Event { event: dummy() }.id(),
+                ParserNode::LexerToken(Token::BackwardDuration(time::BackwardDuration::ThisTurn {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::IncomingInstant { instant: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::TheNext {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Time { .. })),
+                    ParserNode::Event { event },
+                    ParserNode::LexerToken(Token::BackwardDuration(time::BackwardDuration::ThisTurn {
+                        #[cfg(feature = "spanned_tree")]
+                            span: this_turn_span,
+                    })),
+                ] => Ok(ParserNode::IncomingInstant {
+                    instant: time::IncomingInstant::NextTime(time::IncomingNextTime {
+                        event: Box::new(event.clone()),
+                        this_turn_only: true,
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(this_turn_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "when <player> next cast <spell> this turn" is the next time the player casts the spell during this turn */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::When {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Next {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                    keyword_action: mtg_data::KeywordAction::Cast,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Spell { spell: dummy() }.id(),
+                ParserNode::LexerToken(Token::BackwardDuration(time::BackwardDuration::ThisTurn {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::IncomingInstant { instant: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::When {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Next { .. })),
+                    ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                        keyword_action: mtg_data::KeywordAction::Cast,
+                        ..
+                    })),
+                    ParserNode::Spell { spell },
+                    ParserNode::LexerToken(Token::BackwardDuration(time::BackwardDuration::ThisTurn {
+                        #[cfg(feature = "spanned_tree")]
+                            span: this_turn_span,
+                    })),
+                ] => Ok(ParserNode::IncomingInstant {
+                    instant: time::IncomingInstant::NextTime(time::IncomingNextTime {
+                        event: Box::new(crate::ability_tree::event::Event::PlayerPerformsAction(
+                            crate::ability_tree::event::PlayerPerformsActionEvent {
+                                action: crate::ability_tree::action::PlayerAction::CastsSpell(
+                                    crate::ability_tree::action::PlayerCastsSpellAction {
+                                        player: player.clone(),
+                                        spell: spell.clone(),
+                                        from_zone: None,
+                                        #[cfg(feature = "spanned_tree")]
+                                        span: player.node_span().merge(&spell.node_span()),
+                                    },
+                                ),
                                 #[cfg(feature = "spanned_tree")]
-                                span: step.node_span().merge(start_span),
+                                span: player.node_span().merge(&spell.node_span()),
                             },
-                        ),
+                        )),
+                        this_turn_only: true,
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(this_turn_span),
                     }),
-                    _ => Err("Provided tokens do not match rule definition"),
-                },
-                creation_loc: super::ParserRuleDeclarationLocation::here(),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
             },
-        ]
-    });
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ];
 
-    [steps_to_owned_instants].into_iter().flatten()
+    steps_to_step_or_phase.chain(phases_to_step_or_phase).chain(instant_rules)
 }
@@ -14,22 +14,28 @@ mod dash;
 mod disguise;
 mod echo;
 mod enchant;
+mod entwine;
 mod equip;
+mod escalate;
+mod evoke;
 mod fabricate;
 mod flashback;
 mod freerunning;
 mod kicker;
 mod megamorph;
 mod morph;
+mod multikicker;
 mod multiple_keyword_abilities;
 mod ninjutsu;
 mod outlast;
+mod overload;
 mod prototype;
 mod rampage;
 mod reconfigure;
 mod reinforce;
 mod renown;
 mod ripple;
+mod spree;
 mod surge;
 mod suspend;
 mod vanishing;
@@ -86,22 +92,28 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
         disguise::rules().collect::<Vec<_>>(),
         echo::rules().collect::<Vec<_>>(),
         enchant::rules().collect::<Vec<_>>(),
+        entwine::rules().collect::<Vec<_>>(),
         equip::rules().collect::<Vec<_>>(),
+        escalate::rules().collect::<Vec<_>>(),
+        evoke::rules().collect::<Vec<_>>(),
         fabricate::rules().collect::<Vec<_>>(),
         flashback::rules().collect::<Vec<_>>(),
         freerunning::rules().collect::<Vec<_>>(),
         kicker::rules().collect::<Vec<_>>(),
         megamorph::rules().collect::<Vec<_>>(),
         morph::rules().collect::<Vec<_>>(),
+        multikicker::rules().collect::<Vec<_>>(),
         multiple_keyword_abilities::rules().collect::<Vec<_>>(),
         ninjutsu::rules().collect::<Vec<_>>(),
         prototype::rules().collect::<Vec<_>>(),
         outlast::rules().collect::<Vec<_>>(),
+        overload::rules().collect::<Vec<_>>(),
         rampage::rules().collect::<Vec<_>>(),
         reinforce::rules().collect::<Vec<_>>(),
         reconfigure::rules().collect::<Vec<_>>(),
         renown::rules().collect::<Vec<_>>(),
         ripple::rules().collect::<Vec<_>>(),
+        spree::rules().collect::<Vec<_>>(),
         surge::rules().collect::<Vec<_>>(),
         suspend::rules().collect::<Vec<_>>(),
         vanishing::rules().collect::<Vec<_>>(),
@@ -0,0 +1,87 @@
+use super::ParserNode;
+use super::ParserRule;
+use super::ParserRuleDeclarationLocation;
+use super::RuleLhs;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::utils::dummy;
+use idris::Idris;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* Spree <modes>: the spell is a modal spell where any number of modes can be chosen */
+    std::iter::once(ParserRule {
+        expanded: RuleLhs::new(&[
+            ParserNode::LexerToken(Token::KeywordAbility(intermediates::KeywordAbility {
+                keyword_ability: mtg_data::KeywordAbility::Spree,
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::ImperativeChoices { choices: dummy() }.id(),
+        ]),
+        merged: ParserNode::KeywordAbility {
+            keyword_ability: dummy(),
+        }
+        .id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[
+                ParserNode::LexerToken(Token::KeywordAbility(keyword)),
+                ParserNode::ImperativeChoices { choices },
+            ] => {
+                let mut keyword_ability = crate::ability_tree::ability::keyword_ability::keyword_to_abilities(*keyword)?;
+                #[cfg(feature = "spanned_tree")]
+                let span = keyword.span.merge(&choices.span);
+                keyword_ability.ability =
+                    crate::ability_tree::ability::WrittenAbility::Spell(crate::ability_tree::ability::spell::SpellAbility {
+                        effects: {
+                            let mut effects = crate::utils::HeapArrayVec::new();
+                            effects.push(crate::ability_tree::statement::Statement::Imperatives(
+                                crate::ability_tree::imperative_list::ImperativeList {
+                                    imperatives: {
+                                        let mut imperatives = crate::utils::HeapArrayVec::new();
+                                        imperatives.push(crate::ability_tree::imperative::Imperative {
+                                            kind: crate::ability_tree::imperative::ImperativeKind::Modal(
+                                                crate::ability_tree::imperative::ModalImperative {
+                                                    mode_count: crate::ability_tree::number::Number::OrMore(
+                                                        crate::ability_tree::number::OrMoreNumber {
+                                                            minimum: 1,
+                                                            #[cfg(feature = "spanned_tree")]
+                                                            span: keyword.span,
+                                                        },
+                                                    ),
+                                                    can_choose_same_mode: false,
+                                                    only_unchosen_modes: false,
+                                                    modes: choices.choices.clone(),
+                                                    #[cfg(feature = "spanned_tree")]
+                                                    span,
+                                                },
+                                            ),
+                                            executing_player: crate::ability_tree::player::PlayerSpecifier::You {
+                                                #[cfg(feature = "spanned_tree")]
+                                                span: keyword.span,
+                                            },
+                                            #[cfg(feature = "spanned_tree")]
+                                            span,
+                                        });
+                                        imperatives
+                                    },
+                                    #[cfg(feature = "spanned_tree")]
+                                    span,
+                                },
+                            ));
+                            effects
+                        },
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    });
+                #[cfg(feature = "spanned_tree")]
+                {
+                    keyword_ability.span = span;
+                }
+                Ok(ParserNode::KeywordAbility { keyword_ability })
+            }
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    })
+}
@@ -1,4 +1,6 @@
 use crate::ability_tree::number;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
 use crate::parser::ParserNode;
 use crate::parser::rules::ParserRule;
 use crate::parser::rules::ParserRuleDeclarationLocation;
@@ -10,6 +12,159 @@ use idris::Idris;
 use crate::ability_tree::AbilityTreeNode;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* "<counter> on <permanent>" can make a number of counters, such as "+1/+1 counters on ~" */
+    let counters_on_permanent_rules = crate::ability_tree::terminals::Counter::all().map(|counter| ParserRule {
+        expanded: RuleLhs::new(&[
+            ParserNode::LexerToken(Token::Counter(counter)).id(),
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::Permanent { permanent: dummy() }.id(),
+        ]),
+        merged: ParserNode::GameStateNumber { number: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[
+                ParserNode::LexerToken(Token::Counter(counter)),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On { .. })),
+                ParserNode::Permanent { permanent },
+            ] => Ok(ParserNode::GameStateNumber {
+                number: number::GameStateNumber::NumberOfCounters(number::NumberOfCounters {
+                    counter: counter.clone(),
+                    permanent: permanent.clone(),
+                    #[cfg(feature = "spanned_tree")]
+                    span: counter.node_span().merge(&permanent.node_span()),
+                }),
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    });
+
+    /* Aggregated properties of permanents, such as "the greatest power among creatures you control" */
+    let aggregated_properties = [
+        intermediates::CardProperty::ManaValue {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        intermediates::CardProperty::Power {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        intermediates::CardProperty::Toughness {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+    ];
+
+    let permanents_aggregate_rules = aggregated_properties.into_iter().flat_map(|property| {
+        [
+            /* "the greatest <property> among <permanent>" */
+            ParserRule {
+                expanded: RuleLhs::new(&[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Greatest {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::CardProperty(property)).id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Among {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::Permanent { permanent: dummy() }.id(),
+                ]),
+                merged: ParserNode::GameStateNumber { number: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                            #[cfg(feature = "spanned_tree")]
+                                span: start_span,
+                        })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Greatest {
+                            #[cfg(feature = "spanned_tree")]
+                                span: greatest_span,
+                        })),
+                        ParserNode::LexerToken(Token::CardProperty(property)),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Among { .. })),
+                        ParserNode::Permanent { permanent },
+                    ] => Ok(ParserNode::GameStateNumber {
+                        number: number::GameStateNumber::PermanentsAggregate(number::PermanentsAggregate {
+                            aggregation: number::Aggregation::Greatest {
+                                #[cfg(feature = "spanned_tree")]
+                                span: *greatest_span,
+                            },
+                            property: aggregated_property(property)?,
+                            permanents: permanent.clone(),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(&permanent.node_span()),
+                        }),
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: ParserRuleDeclarationLocation::here(),
+            },
+            /* "the total <property> of <permanent>" */
+            ParserRule {
+                expanded: RuleLhs::new(&[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Total {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::CardProperty(property)).id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::Permanent { permanent: dummy() }.id(),
+                ]),
+                merged: ParserNode::GameStateNumber { number: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::The {
+                            #[cfg(feature = "spanned_tree")]
+                                span: start_span,
+                        })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Total {
+                            #[cfg(feature = "spanned_tree")]
+                                span: total_span,
+                        })),
+                        ParserNode::LexerToken(Token::CardProperty(property)),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
+                        ParserNode::Permanent { permanent },
+                    ] => Ok(ParserNode::GameStateNumber {
+                        number: number::GameStateNumber::PermanentsAggregate(number::PermanentsAggregate {
+                            aggregation: number::Aggregation::Total {
+                                #[cfg(feature = "spanned_tree")]
+                                span: *total_span,
+                            },
+                            property: aggregated_property(property)?,
+                            permanents: permanent.clone(),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(&permanent.node_span()),
+                        }),
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: ParserRuleDeclarationLocation::here(),
+            },
+        ]
+    });
+
     [
         /* <permanent reference> can make a number of permanents on the battlefield */
         ParserRule {
@@ -27,6 +182,290 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
+        /* "<specified card> in <zone>" can make a number of cards in a zone, such as "cards in your graveyard" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::SpecifiedCard { card: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::In {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::ZoneReference { zone: dummy() }.id(),
+            ]),
+            merged: ParserNode::GameStateNumber { number: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::SpecifiedCard { card },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::In { .. })),
+                    ParserNode::ZoneReference { zone },
+                ] => Ok(ParserNode::GameStateNumber {
+                    number: number::GameStateNumber::NumberOfCardsInZone(number::NumberOfCardsInZone {
+                        card: card.clone(),
+                        zone: zone.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: card.node_span().merge(&zone.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "time(s) <spell> was kicked" is the number of times a spell was kicked */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Time {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Spell { spell: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::StackObjectState(
+                    crate::ability_tree::state::StackObjectState::Kicked {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    },
+                ))
+                .id(),
+            ]),
+            merged: ParserNode::GameStateNumber { number: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Time {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::Spell { spell },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is { .. })),
+                    ParserNode::LexerToken(Token::StackObjectState(crate::ability_tree::state::StackObjectState::Kicked {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::GameStateNumber {
+                    number: number::GameStateNumber::NumberOfTimesKicked(number::NumberOfTimesKicked {
+                        spell: spell.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(end_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "time(s) ~ was kicked" is the number of times the spell itself was kicked */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Time {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CardOwnName(intermediates::CardOwnName {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::StackObjectState(
+                    crate::ability_tree::state::StackObjectState::Kicked {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    },
+                ))
+                .id(),
+            ]),
+            merged: ParserNode::GameStateNumber { number: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Time {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::CardOwnName(intermediates::CardOwnName {
+                        #[cfg(feature = "spanned_tree")]
+                            span: name_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is { .. })),
+                    ParserNode::LexerToken(Token::StackObjectState(crate::ability_tree::state::StackObjectState::Kicked {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::GameStateNumber {
+                    number: number::GameStateNumber::NumberOfTimesKicked(number::NumberOfTimesKicked {
+                        spell: crate::ability_tree::object::Spell::SelfReferencing(
+                            crate::ability_tree::object::SelfReferencing {
+                                #[cfg(feature = "spanned_tree")]
+                                span: *name_span,
+                            },
+                        ),
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(end_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "your devotion to <colors>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Devotion {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Colors { colors: dummy() }.id(),
+            ]),
+            merged: ParserNode::GameStateNumber { number: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                        #[cfg(feature = "spanned_tree")]
+                            span: your_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Devotion { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                        #[cfg(feature = "spanned_tree")]
+                            span: to_span,
+                    })),
+                    ParserNode::Colors { colors },
+                ] => Ok(ParserNode::GameStateNumber {
+                    number: number::GameStateNumber::Devotion(number::Devotion {
+                        player: crate::ability_tree::player::PlayerSpecifier::You {
+                            #[cfg(feature = "spanned_tree")]
+                            span: *your_span,
+                        },
+                        colors: colors.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: your_span.merge(to_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "your life total" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::PlayerProperties(intermediates::PlayerProperties::LifeTotal {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::GameStateNumber { number: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                        #[cfg(feature = "spanned_tree")]
+                            span: your_span,
+                    })),
+                    ParserNode::LexerToken(Token::PlayerProperties(intermediates::PlayerProperties::LifeTotal {
+                        #[cfg(feature = "spanned_tree")]
+                            span: life_total_span,
+                    })),
+                ] => Ok(ParserNode::GameStateNumber {
+                    number: number::GameStateNumber::LifeTotal(number::LifeTotal {
+                        player: crate::ability_tree::player::PlayerSpecifier::You {
+                            #[cfg(feature = "spanned_tree")]
+                            span: *your_span,
+                        },
+                        #[cfg(feature = "spanned_tree")]
+                        span: your_span.merge(life_total_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<player>'s life total" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::PlayerProperties(intermediates::PlayerProperties::LifeTotal {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::GameStateNumber { number: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS { .. })),
+                    ParserNode::LexerToken(Token::PlayerProperties(intermediates::PlayerProperties::LifeTotal {
+                        #[cfg(feature = "spanned_tree")]
+                            span: life_total_span,
+                    })),
+                ] => Ok(ParserNode::GameStateNumber {
+                    number: number::GameStateNumber::LifeTotal(number::LifeTotal {
+                        player: player.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: player.node_span().merge(life_total_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
     ]
     .into_iter()
+    .chain(counters_on_permanent_rules)
+    .chain(permanents_aggregate_rules)
+}
+
+fn aggregated_property(property: &intermediates::CardProperty) -> Result<number::AggregatedProperty, &'static str> {
+    match property {
+        intermediates::CardProperty::ManaValue {
+            #[cfg(feature = "spanned_tree")]
+            span,
+        } => Ok(number::AggregatedProperty::ManaValue {
+            #[cfg(feature = "spanned_tree")]
+            span: *span,
+        }),
+        intermediates::CardProperty::Power {
+            #[cfg(feature = "spanned_tree")]
+            span,
+        } => Ok(number::AggregatedProperty::Power {
+            #[cfg(feature = "spanned_tree")]
+            span: *span,
+        }),
+        intermediates::CardProperty::Toughness {
+            #[cfg(feature = "spanned_tree")]
+            span,
+        } => Ok(number::AggregatedProperty::Toughness {
+            #[cfg(feature = "spanned_tree")]
+            span: *span,
+        }),
+        _ => Err("Card property can not be aggregated over permanents"),
+    }
 }
@@ -1,21 +1,27 @@
 mod artifact;
+mod battle;
 mod card;
 mod creature;
 mod damage_receiver;
 mod enchantment;
 mod land;
 mod permanent;
+mod planeswalker;
+mod player_or_permanent;
 mod spell;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
     [
         artifact::rules().collect::<Vec<_>>(),
+        battle::rules().collect::<Vec<_>>(),
         card::rules().collect::<Vec<_>>(),
         creature::rules().collect::<Vec<_>>(),
         damage_receiver::rules().collect::<Vec<_>>(),
         enchantment::rules().collect::<Vec<_>>(),
         land::rules().collect::<Vec<_>>(),
         permanent::rules().collect::<Vec<_>>(),
+        planeswalker::rules().collect::<Vec<_>>(),
+        player_or_permanent::rules().collect::<Vec<_>>(),
         spell::rules().collect::<Vec<_>>(),
     ]
     .into_iter()
@@ -0,0 +1,154 @@
+use crate::ability_tree::object;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "<count> <specified battle>" is a battle */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::CountSpecifier { count: dummy() }.id(),
+                ParserNode::SpecifiedBattle { battle: dummy() }.id(),
+            ]),
+            merged: ParserNode::Battle { battle: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::CountSpecifier { count }, ParserNode::SpecifiedBattle { battle }] => Ok(ParserNode::Battle {
+                    battle: object::Battle::Reference(object::reference::BattleReference {
+                        count: count.clone(),
+                        battle: battle.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: count.node_span().merge(&battle.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "another <specified battle>" is a + other battle */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Another {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::SpecifiedBattle { battle: dummy() }.id(),
+            ]),
+            merged: ParserNode::Battle { battle: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Another {
+                        #[cfg(feature = "spanned_tree")]
+                            span: another_span,
+                    })),
+                    ParserNode::SpecifiedBattle { battle },
+                ] => Ok(ParserNode::Battle {
+                    battle: object::Battle::Reference(object::reference::BattleReference {
+                        count: object::CountSpecifier::A {
+                            #[cfg(feature = "spanned_tree")]
+                            span: *another_span,
+                        },
+                        battle: battle.add_factor_specifier(object::specified_object::BattleSpecifier::Another(
+                            object::specified_object::AnotherObjectSpecifier {
+                                #[cfg(feature = "spanned_tree")]
+                                span: *another_span,
+                            },
+                        )),
+                        #[cfg(feature = "spanned_tree")]
+                        span: battle.node_span().merge(another_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<specified battle>" is a battle with an implicit "all" */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::SpecifiedBattle { battle: dummy() }.id()]),
+            merged: ParserNode::Battle { battle: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::SpecifiedBattle { battle }] => Ok(ParserNode::Battle {
+                    battle: object::Battle::Reference(object::reference::BattleReference {
+                        count: object::CountSpecifier::All {
+                            #[cfg(feature = "spanned_tree")]
+                            span: battle.node_span().empty_at_start(),
+                        },
+                        battle: battle.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: battle.node_span(),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "this <specified battle>" can be used as a battle reference */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::This {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::SpecifiedBattle { battle: dummy() }.id(),
+            ]),
+            merged: ParserNode::Battle { battle: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::This {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::SpecifiedBattle {
+                        #[cfg(feature = "spanned_tree")]
+                        battle,
+                        ..
+                    },
+                ] => Ok(ParserNode::Battle {
+                    battle: object::Battle::SelfReferencing(object::SelfReferencing {
+                        #[cfg(feature = "spanned_tree")]
+                        span: battle.node_span().merge(start_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "it" makes a previously mentionned battle */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::Battle { battle: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                ] => Ok(ParserNode::Battle {
+                    battle: object::Battle::PreviouslyMentionned(object::PreviouslyMentionned {
+                        #[cfg(feature = "spanned_tree")]
+                        span: *span,
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
@@ -97,6 +97,50 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
+        /* "<player>" can be used as a damage receiver, the player specifier already tells which players are dealt damage */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::Player { player: dummy() }.id()]),
+            merged: ParserNode::DamageReceiver { receiver: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::Player { player }] => Ok(ParserNode::DamageReceiver {
+                    receiver: object::DamageReceiver::Reference(object::reference::DamageReceiverReference {
+                        count: object::CountSpecifier::All {
+                            #[cfg(feature = "spanned_tree")]
+                            span: player.node_span(),
+                        },
+                        kind: object::kind::DamageReceiverKind::Player(player.clone()),
+                        #[cfg(feature = "spanned_tree")]
+                        span: player.node_span(),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<card own name>" is a self referencing damage receiver */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::LexerToken(Token::CardOwnName(intermediates::CardOwnName {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id()]),
+            merged: ParserNode::DamageReceiver { receiver: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::CardOwnName(intermediates::CardOwnName {
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                ] => Ok(ParserNode::DamageReceiver {
+                    receiver: object::DamageReceiver::SelfReferencing(object::SelfReferencing {
+                        #[cfg(feature = "spanned_tree")]
+                        span: *span,
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
     ]
     .into_iter()
 }
@@ -0,0 +1,159 @@
+use crate::ability_tree::object;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "<count> <specified planeswalker>" is a planeswalker */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::CountSpecifier { count: dummy() }.id(),
+                ParserNode::SpecifiedPlaneswalker { planeswalker: dummy() }.id(),
+            ]),
+            merged: ParserNode::Planeswalker { planeswalker: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::CountSpecifier { count },
+                    ParserNode::SpecifiedPlaneswalker { planeswalker },
+                ] => Ok(ParserNode::Planeswalker {
+                    planeswalker: object::Planeswalker::Reference(object::reference::PlaneswalkerReference {
+                        count: count.clone(),
+                        planeswalker: planeswalker.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: count.node_span().merge(&planeswalker.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "another <specified planeswalker>" is a + other planeswalker */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Another {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::SpecifiedPlaneswalker { planeswalker: dummy() }.id(),
+            ]),
+            merged: ParserNode::Planeswalker { planeswalker: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Another {
+                        #[cfg(feature = "spanned_tree")]
+                            span: another_span,
+                    })),
+                    ParserNode::SpecifiedPlaneswalker { planeswalker },
+                ] => Ok(ParserNode::Planeswalker {
+                    planeswalker: object::Planeswalker::Reference(object::reference::PlaneswalkerReference {
+                        count: object::CountSpecifier::A {
+                            #[cfg(feature = "spanned_tree")]
+                            span: *another_span,
+                        },
+                        planeswalker: planeswalker.add_factor_specifier(
+                            object::specified_object::PlaneswalkerSpecifier::Another(
+                                object::specified_object::AnotherObjectSpecifier {
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: *another_span,
+                                },
+                            ),
+                        ),
+                        #[cfg(feature = "spanned_tree")]
+                        span: planeswalker.node_span().merge(another_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<specified planeswalker>" is a planeswalker with an implicit "all" */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::SpecifiedPlaneswalker { planeswalker: dummy() }.id()]),
+            merged: ParserNode::Planeswalker { planeswalker: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::SpecifiedPlaneswalker { planeswalker }] => Ok(ParserNode::Planeswalker {
+                    planeswalker: object::Planeswalker::Reference(object::reference::PlaneswalkerReference {
+                        count: object::CountSpecifier::All {
+                            #[cfg(feature = "spanned_tree")]
+                            span: planeswalker.node_span().empty_at_start(),
+                        },
+                        planeswalker: planeswalker.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: planeswalker.node_span(),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "this <specified planeswalker>" can be used as a planeswalker reference */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::This {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::SpecifiedPlaneswalker { planeswalker: dummy() }.id(),
+            ]),
+            merged: ParserNode::Planeswalker { planeswalker: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::This {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::SpecifiedPlaneswalker {
+                        #[cfg(feature = "spanned_tree")]
+                        planeswalker,
+                        ..
+                    },
+                ] => Ok(ParserNode::Planeswalker {
+                    planeswalker: object::Planeswalker::SelfReferencing(object::SelfReferencing {
+                        #[cfg(feature = "spanned_tree")]
+                        span: planeswalker.node_span().merge(start_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "it" makes a previously mentionned planeswalker */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::Planeswalker { planeswalker: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                ] => Ok(ParserNode::Planeswalker {
+                    planeswalker: object::Planeswalker::PreviouslyMentionned(object::PreviouslyMentionned {
+                        #[cfg(feature = "spanned_tree")]
+                        span: *span,
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
@@ -0,0 +1,123 @@
+use crate::ability_tree::object;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "<count> <player or permanent kind>" is a player or permanent */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::CountSpecifier { count: dummy() }.id(),
+                ParserNode::PlayerOrPermanentKind { kind: dummy() }.id(),
+            ]),
+            merged: ParserNode::PlayerOrPermanent { object: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::CountSpecifier { count },
+                    ParserNode::PlayerOrPermanentKind { kind },
+                ] => Ok(ParserNode::PlayerOrPermanent {
+                    object: object::PlayerOrPermanent::Reference(object::reference::PlayerOrPermanentReference {
+                        count: count.clone(),
+                        kind: kind.clone(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: count.node_span().merge(&kind.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "target player or <permanent kind>": the lexer merges "target player", so the target count is split back here */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::PlayerSpecifier(intermediates::PlayerSpecifier::TargetPlayer {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::PermanentKind { permanent: dummy() }.id(),
+            ]),
+            merged: ParserNode::PlayerOrPermanent { object: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::PlayerSpecifier(intermediates::PlayerSpecifier::TargetPlayer {
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or { .. })),
+                    ParserNode::PermanentKind { permanent },
+                ] => Ok(ParserNode::PlayerOrPermanent {
+                    object: object::PlayerOrPermanent::Reference(object::reference::PlayerOrPermanentReference {
+                        count: object::CountSpecifier::Target(crate::ability_tree::number::Number::Number(
+                            crate::ability_tree::number::FixedNumber {
+                                number: 1,
+                                #[cfg(feature = "spanned_tree")]
+                                span: *span,
+                            },
+                        )),
+                        kind: object::kind::PlayerOrPermanentKind::OneAmong(object::OneAmong {
+                            references: {
+                                let mut references = crate::utils::HeapArrayVec::new();
+                                references.push(object::kind::PlayerOrPermanentKind::Player(
+                                    crate::ability_tree::player::PlayerSpecifier::Any {
+                                        #[cfg(feature = "spanned_tree")]
+                                        span: *span,
+                                    },
+                                ));
+                                references.push(object::kind::PlayerOrPermanentKind::Permanent(permanent.clone()));
+                                references
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: permanent.node_span().merge(span),
+                        }),
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "it" makes a previously mentionned player or permanent */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::PlayerOrPermanent { object: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                ] => Ok(ParserNode::PlayerOrPermanent {
+                    object: object::PlayerOrPermanent::PreviouslyMentionned(object::PreviouslyMentionned {
+                        #[cfg(feature = "spanned_tree")]
+                        span: *span,
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
@@ -1,4 +1,6 @@
 use crate::ability_tree::object;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
 use crate::parser::ParserNode;
 use crate::parser::rules::ParserRule;
 use crate::parser::rules::ParserRuleDeclarationLocation;
@@ -6,8 +8,23 @@ use crate::parser::rules::RuleLhs;
 use crate::utils::dummy;
 use idris::Idris;
 
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
     [
+        /* "<battle kind>" can be used as a damage receiver kind */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::SpecifiedBattle { battle: dummy() }.id()]),
+            merged: ParserNode::DamageReceiverKind { receiver: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::SpecifiedBattle { battle }] => Ok(ParserNode::DamageReceiverKind {
+                    receiver: object::kind::DamageReceiverKind::Battle(battle.clone()),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
         /* "<creature kind>" can be used as a damage receiver kind */
         ParserRule {
             expanded: RuleLhs::new(&[ParserNode::SpecifiedCreature { creature: dummy() }.id()]),
@@ -44,6 +61,39 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
+        /* "<damage receiver kind> or <damage receiver kind>" makes a one among kind */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::DamageReceiverKind { receiver: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::DamageReceiverKind { receiver: dummy() }.id(),
+            ]),
+            merged: ParserNode::DamageReceiverKind { receiver: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::DamageReceiverKind { receiver: r1 },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or { .. })),
+                    ParserNode::DamageReceiverKind { receiver: r2 },
+                ] => Ok(ParserNode::DamageReceiverKind {
+                    receiver: object::kind::DamageReceiverKind::OneAmong(object::OneAmong {
+                        references: {
+                            let mut references = crate::utils::HeapArrayVec::new();
+                            references.push(r1.clone());
+                            references.push(r2.clone());
+                            references
+                        },
+                        #[cfg(feature = "spanned_tree")]
+                        span: r1.node_span().merge(&r2.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
     ]
     .into_iter()
 }
@@ -51,6 +51,18 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
+        /* "<specified battle>" can be used as a permanent kind */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::SpecifiedBattle { battle: dummy() }.id()]),
+            merged: ParserNode::PermanentKind { permanent: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::SpecifiedBattle { battle }] => Ok(ParserNode::PermanentKind {
+                    permanent: object::kind::PermanentKind::Battle(battle.clone()),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
         /* "<specified creature>" can be used as a permanent kind */
         ParserRule {
             expanded: RuleLhs::new(&[ParserNode::SpecifiedCreature { creature: dummy() }.id()]),
@@ -0,0 +1,120 @@
+use crate::ability_tree::object;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        /* "<permanent kind>" can be used as a player or permanent kind */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::PermanentKind { permanent: dummy() }.id()]),
+            merged: ParserNode::PlayerOrPermanentKind { kind: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::PermanentKind { permanent }] => Ok(ParserNode::PlayerOrPermanentKind {
+                    kind: object::kind::PlayerOrPermanentKind::Permanent(permanent.clone()),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<permanent kind> or player" makes a one among kind */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::PermanentKind { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Player {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::PlayerOrPermanentKind { kind: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::PermanentKind { permanent },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Player {
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                ] => Ok(ParserNode::PlayerOrPermanentKind {
+                    kind: object::kind::PlayerOrPermanentKind::OneAmong(object::OneAmong {
+                        references: {
+                            let mut references = crate::utils::HeapArrayVec::new();
+                            references.push(object::kind::PlayerOrPermanentKind::Permanent(permanent.clone()));
+                            references.push(object::kind::PlayerOrPermanentKind::Player(
+                                crate::ability_tree::player::PlayerSpecifier::Any {
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: *span,
+                                },
+                            ));
+                            references
+                        },
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "player or <permanent kind>" makes a one among kind */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Player {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::PermanentKind { permanent: dummy() }.id(),
+            ]),
+            merged: ParserNode::PlayerOrPermanentKind { kind: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Player {
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or { .. })),
+                    ParserNode::PermanentKind { permanent },
+                ] => Ok(ParserNode::PlayerOrPermanentKind {
+                    kind: object::kind::PlayerOrPermanentKind::OneAmong(object::OneAmong {
+                        references: {
+                            let mut references = crate::utils::HeapArrayVec::new();
+                            references.push(object::kind::PlayerOrPermanentKind::Player(
+                                crate::ability_tree::player::PlayerSpecifier::Any {
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: *span,
+                                },
+                            ));
+                            references.push(object::kind::PlayerOrPermanentKind::Permanent(permanent.clone()));
+                            references
+                        },
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+}
@@ -0,0 +1,218 @@
+use crate::ability_tree::object;
+use crate::ability_tree::terminals;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = ParserRule> {
+    /* <battle subtype> is a battle "subtype" specifier */
+    let subtypes_to_specifiers = crate::ability_tree::terminals::BattleSubtype::all()
+        .map(|subtype| ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::LexerToken(Token::BattleSubtype(subtype)).id()]),
+            merged: ParserNode::BattleSpecifier { specifier: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::LexerToken(Token::BattleSubtype(subtype))] => Ok(ParserNode::BattleSpecifier {
+                    specifier: object::specified_object::BattleSpecifier::Subtype(
+                        object::specified_object::BattleSubtypeSpecifier {
+                            subtype: *subtype,
+                            #[cfg(feature = "spanned_tree")]
+                            span: subtype.node_span(),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        })
+        .collect::<Vec<_>>();
+
+    let characteristic_specifiers = vec![
+        /* "with <number> defense counters on it" makes a counters specifier */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::With {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Number { number: dummy() }.id(),
+                ParserNode::LexerToken(Token::Counter(terminals::Counter {
+                    kind: terminals::CounterKind::Defense,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::BattleSpecifier { specifier: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::With {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::Number { number },
+                    ParserNode::LexerToken(Token::Counter(counter)),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::BattleSpecifier {
+                    specifier: object::specified_object::BattleSpecifier::WithCounters(
+                        object::specified_object::CountersOnObjectSpecifier {
+                            amount: number.clone(),
+                            counter: counter.clone(),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(end_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ];
+
+    let common_specifiers = vec![
+        /* "<control specifier>" is a battle specifier */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::ControlSpecifier { specifier: dummy() }.id()]),
+            merged: ParserNode::BattleSpecifier { specifier: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::ControlSpecifier { specifier }] => Ok(ParserNode::BattleSpecifier {
+                    specifier: object::specified_object::BattleSpecifier::Control(specifier.clone()),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<protector specifier>" is a battle specifier */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::ProtectorSpecifier { specifier: dummy() }.id()]),
+            merged: ParserNode::BattleSpecifier { specifier: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::ProtectorSpecifier { specifier }] => Ok(ParserNode::BattleSpecifier {
+                    specifier: object::specified_object::BattleSpecifier::Protector(specifier.clone()),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<color specifier>" is a battle specifier */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::ColorSpecifier { specifier: dummy() }.id()]),
+            merged: ParserNode::BattleSpecifier { specifier: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::ColorSpecifier { specifier }] => Ok(ParserNode::BattleSpecifier {
+                    specifier: object::specified_object::BattleSpecifier::Color(specifier.clone()),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<another specifier>" is a battle specifier */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::AnotherSpecifier { specifier: dummy() }.id()]),
+            merged: ParserNode::BattleSpecifier { specifier: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::AnotherSpecifier { specifier }] => Ok(ParserNode::BattleSpecifier {
+                    specifier: object::specified_object::BattleSpecifier::Another(specifier.clone()),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ];
+
+    let merging_specifiers = vec![
+        /* "<battle specifier>" on its own can make a battle specifiers node */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::BattleSpecifier { specifier: dummy() }.id()]),
+            merged: ParserNode::BattleSpecifiers { specifiers: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::BattleSpecifier { specifier }] => Ok(ParserNode::BattleSpecifiers {
+                    specifiers: object::specified_object::Specifiers::Single(specifier.clone()),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<battle specifier> <battle specifier>" -> and list */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::BattleSpecifier { specifier: dummy() }.id(),
+                ParserNode::BattleSpecifier { specifier: dummy() }.id(),
+            ]),
+            merged: ParserNode::BattleSpecifiers { specifiers: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::BattleSpecifier { specifier: s1 },
+                    ParserNode::BattleSpecifier { specifier: s2 },
+                ] => Ok(ParserNode::BattleSpecifiers {
+                    specifiers: object::specified_object::Specifiers::And(object::specified_object::SpecifierAndList {
+                        specifiers: [s1.clone(), s2.clone()].into_iter().collect(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: s1.node_span().merge(&s2.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<battle specifier> or <battle specifier>" -> or list */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::BattleSpecifier { specifier: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::BattleSpecifier { specifier: dummy() }.id(),
+            ]),
+            merged: ParserNode::BattleSpecifiers { specifiers: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::BattleSpecifier { specifier: s1 },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or { .. })),
+                    ParserNode::BattleSpecifier { specifier: s2 },
+                ] => Ok(ParserNode::BattleSpecifiers {
+                    specifiers: object::specified_object::Specifiers::Or(object::specified_object::SpecifierOrList {
+                        specifiers: [s1.clone(), s2.clone()].into_iter().collect(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: s1.node_span().merge(&s2.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ];
+
+    [
+        subtypes_to_specifiers,
+        characteristic_specifiers,
+        common_specifiers,
+        merging_specifiers,
+    ]
+    .into_iter()
+    .flatten()
+}
@@ -0,0 +1,206 @@
+use crate::ability_tree::object;
+use crate::ability_tree::terminals;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = ParserRule> {
+    /* <planeswalker subtype> is a planeswalker "subtype" specifier */
+    let subtypes_to_specifiers = crate::ability_tree::terminals::PlaneswalkerSubtype::all()
+        .map(|subtype| ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::LexerToken(Token::PlaneswalkerSubtype(subtype)).id()]),
+            merged: ParserNode::PlaneswalkerSpecifier { specifier: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::LexerToken(Token::PlaneswalkerSubtype(subtype))] => Ok(ParserNode::PlaneswalkerSpecifier {
+                    specifier: object::specified_object::PlaneswalkerSpecifier::Subtype(
+                        object::specified_object::PlaneswalkerSubtypeSpecifier {
+                            subtype: *subtype,
+                            #[cfg(feature = "spanned_tree")]
+                            span: subtype.node_span(),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        })
+        .collect::<Vec<_>>();
+
+    let characteristic_specifiers = vec![
+        /* "with <number> loyalty counters on it" makes a counters specifier */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::With {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Number { number: dummy() }.id(),
+                ParserNode::LexerToken(Token::Counter(terminals::Counter {
+                    kind: terminals::CounterKind::Loyalty,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::PlaneswalkerSpecifier { specifier: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::With {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::Number { number },
+                    ParserNode::LexerToken(Token::Counter(counter)),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::PlaneswalkerSpecifier {
+                    specifier: object::specified_object::PlaneswalkerSpecifier::WithCounters(
+                        object::specified_object::CountersOnObjectSpecifier {
+                            amount: number.clone(),
+                            counter: counter.clone(),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(end_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ];
+
+    let common_specifiers = vec![
+        /* "<control specifier>" is a planeswalker specifier */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::ControlSpecifier { specifier: dummy() }.id()]),
+            merged: ParserNode::PlaneswalkerSpecifier { specifier: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::ControlSpecifier { specifier }] => Ok(ParserNode::PlaneswalkerSpecifier {
+                    specifier: object::specified_object::PlaneswalkerSpecifier::Control(specifier.clone()),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<color specifier>" is a planeswalker specifier */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::ColorSpecifier { specifier: dummy() }.id()]),
+            merged: ParserNode::PlaneswalkerSpecifier { specifier: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::ColorSpecifier { specifier }] => Ok(ParserNode::PlaneswalkerSpecifier {
+                    specifier: object::specified_object::PlaneswalkerSpecifier::Color(specifier.clone()),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<another specifier>" is a planeswalker specifier */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::AnotherSpecifier { specifier: dummy() }.id()]),
+            merged: ParserNode::PlaneswalkerSpecifier { specifier: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::AnotherSpecifier { specifier }] => Ok(ParserNode::PlaneswalkerSpecifier {
+                    specifier: object::specified_object::PlaneswalkerSpecifier::Another(specifier.clone()),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ];
+
+    let merging_specifiers = vec![
+        /* "<planeswalker specifier>" on its own can make a planeswalker specifiers node */
+        ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::PlaneswalkerSpecifier { specifier: dummy() }.id()]),
+            merged: ParserNode::PlaneswalkerSpecifiers { specifiers: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::PlaneswalkerSpecifier { specifier }] => Ok(ParserNode::PlaneswalkerSpecifiers {
+                    specifiers: object::specified_object::Specifiers::Single(specifier.clone()),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<planeswalker specifier> <planeswalker specifier>" -> and list */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::PlaneswalkerSpecifier { specifier: dummy() }.id(),
+                ParserNode::PlaneswalkerSpecifier { specifier: dummy() }.id(),
+            ]),
+            merged: ParserNode::PlaneswalkerSpecifiers { specifiers: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::PlaneswalkerSpecifier { specifier: s1 },
+                    ParserNode::PlaneswalkerSpecifier { specifier: s2 },
+                ] => Ok(ParserNode::PlaneswalkerSpecifiers {
+                    specifiers: object::specified_object::Specifiers::And(object::specified_object::SpecifierAndList {
+                        specifiers: [s1.clone(), s2.clone()].into_iter().collect(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: s1.node_span().merge(&s2.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<planeswalker specifier> or <planeswalker specifier>" -> or list */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::PlaneswalkerSpecifier { specifier: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::PlaneswalkerSpecifier { specifier: dummy() }.id(),
+            ]),
+            merged: ParserNode::PlaneswalkerSpecifiers { specifiers: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::PlaneswalkerSpecifier { specifier: s1 },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Or { .. })),
+                    ParserNode::PlaneswalkerSpecifier { specifier: s2 },
+                ] => Ok(ParserNode::PlaneswalkerSpecifiers {
+                    specifiers: object::specified_object::Specifiers::Or(object::specified_object::SpecifierOrList {
+                        specifiers: [s1.clone(), s2.clone()].into_iter().collect(),
+                        #[cfg(feature = "spanned_tree")]
+                        span: s1.node_span().merge(&s2.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ];
+
+    [
+        subtypes_to_specifiers,
+        characteristic_specifiers,
+        common_specifiers,
+        merging_specifiers,
+    ]
+    .into_iter()
+    .flatten()
+}
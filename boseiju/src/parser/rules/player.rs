@@ -134,6 +134,31 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: super::ParserRuleDeclarationLocation::here(),
         },
+        super::ParserRule {
+            expanded: super::RuleLhs::new(&[ParserNode::LexerToken(Token::PlayerSpecifier(
+                intermediates::PlayerSpecifier::TargetPlayer {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                },
+            ))
+            .id()]),
+            merged: ParserNode::Player { player: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::PlayerSpecifier(intermediates::PlayerSpecifier::TargetPlayer {
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                ] => Ok(ParserNode::Player {
+                    player: crate::ability_tree::player::PlayerSpecifier::TargetPlayer {
+                        #[cfg(feature = "spanned_tree")]
+                        span: *span,
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: super::ParserRuleDeclarationLocation::here(),
+        },
         super::ParserRule {
             expanded: super::RuleLhs::new(&[ParserNode::LexerToken(Token::PlayerSpecifier(
                 intermediates::PlayerSpecifier::ToYourLeft {
@@ -212,6 +237,41 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
     ];
 
     let non_terminal_player_rules = vec![
+        /* "that player" refers to a player mentionned earlier in the ability */
+        super::ParserRule {
+            expanded: super::RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Player {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::Player { player: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Player {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::Player {
+                    player: crate::ability_tree::player::PlayerSpecifier::PerviouslyMentionnedPlayer {
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(end_span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: super::ParserRuleDeclarationLocation::here(),
+        },
         /* Object's controller is a player specifier */
         super::ParserRule {
             expanded: super::RuleLhs::new(&[
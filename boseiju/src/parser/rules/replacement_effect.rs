@@ -1,5 +1,19 @@
+mod damage_prevention;
+mod damage_redirection;
+mod doubling;
 mod etb_replacement;
+mod event_replacement;
+mod replaced_event;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
-    [etb_replacement::rules().collect::<Vec<_>>()].into_iter().flatten()
+    [
+        damage_prevention::rules().collect::<Vec<_>>(),
+        damage_redirection::rules().collect::<Vec<_>>(),
+        doubling::rules().collect::<Vec<_>>(),
+        etb_replacement::rules().collect::<Vec<_>>(),
+        event_replacement::rules().collect::<Vec<_>>(),
+        replaced_event::rules().collect::<Vec<_>>(),
+    ]
+    .into_iter()
+    .flatten()
 }
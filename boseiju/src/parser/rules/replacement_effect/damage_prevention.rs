@@ -0,0 +1,382 @@
+use crate::ability_tree::ability::statik::continuous_effect;
+use crate::ability_tree::replacement_effect::*;
+use crate::ability_tree::time;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    crate::ability_tree::terminals::DamageKind::all().flat_map(|damage_kind| {
+        [
+            /* "prevent all <damage kind> that would be dealt" is a prevention effect */
+            ParserRule {
+                expanded: RuleLhs::new(&[
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Prevent {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::DamageKind(damage_kind)).id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                ]),
+                merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Prevent {
+                            #[cfg(feature = "spanned_tree")]
+                                span: start_span,
+                        })),
+                        ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All { .. })),
+                        ParserNode::LexerToken(Token::DamageKind(damage_kind)),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be { .. })),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                            #[cfg(feature = "spanned_tree")]
+                                span: end_span,
+                        })),
+                    ] => Ok(ParserNode::ContinuousEffect {
+                        effect: continuous_effect::ContinuousEffect {
+                            effect: continuous_effect::ContinuousEffectKind::ReplacementEffect(
+                                ReplacementEffect::DamagePrevention(DamagePreventionEffect {
+                                    damage_kind: *damage_kind,
+                                    receiver: None,
+                                    source_of_choice: false,
+                                    next_time_only: false,
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: start_span.merge(end_span),
+                                }),
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(end_span),
+                        },
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: ParserRuleDeclarationLocation::here(),
+            },
+            /* "prevent all <damage kind> that would be dealt to <damage receiver>" is a prevention effect */
+            ParserRule {
+                expanded: RuleLhs::new(&[
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Prevent {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::DamageKind(damage_kind)).id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::DamageReceiver { receiver: dummy() }.id(),
+                ]),
+                merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Prevent {
+                            #[cfg(feature = "spanned_tree")]
+                                span: start_span,
+                        })),
+                        ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All { .. })),
+                        ParserNode::LexerToken(Token::DamageKind(damage_kind)),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be { .. })),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
+                        ParserNode::DamageReceiver { receiver },
+                    ] => Ok(ParserNode::ContinuousEffect {
+                        effect: continuous_effect::ContinuousEffect {
+                            effect: continuous_effect::ContinuousEffectKind::ReplacementEffect(
+                                ReplacementEffect::DamagePrevention(DamagePreventionEffect {
+                                    damage_kind: *damage_kind,
+                                    receiver: Some(receiver.clone()),
+                                    source_of_choice: false,
+                                    next_time_only: false,
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: start_span.merge(&receiver.node_span()),
+                                }),
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(&receiver.node_span()),
+                        },
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: ParserRuleDeclarationLocation::here(),
+            },
+            /* "if a source would deal <damage kind> to <damage receiver>, prevent that damage" is a prevention effect */
+            ParserRule {
+                expanded: RuleLhs::new(&[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::If {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::A {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Source {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::DamageKind(damage_kind)).id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::DamageReceiver { receiver: dummy() }.id(),
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Prevent {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::DamageKind(crate::ability_tree::terminals::DamageKind::Damage {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                ]),
+                merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::If {
+                            #[cfg(feature = "spanned_tree")]
+                                span: start_span,
+                        })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::A { .. })),
+                        ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Source { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                        ParserNode::LexerToken(Token::DamageKind(damage_kind)),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
+                        ParserNode::DamageReceiver { receiver },
+                        ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
+                        ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Prevent { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That { .. })),
+                        ParserNode::LexerToken(Token::DamageKind(crate::ability_tree::terminals::DamageKind::Damage {
+                            #[cfg(feature = "spanned_tree")]
+                                span: end_span,
+                        })),
+                    ] => Ok(ParserNode::ContinuousEffect {
+                        effect: continuous_effect::ContinuousEffect {
+                            effect: continuous_effect::ContinuousEffectKind::ReplacementEffect(
+                                ReplacementEffect::DamagePrevention(DamagePreventionEffect {
+                                    damage_kind: *damage_kind,
+                                    receiver: Some(receiver.clone()),
+                                    source_of_choice: false,
+                                    next_time_only: false,
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: start_span.merge(end_span),
+                                }),
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(end_span),
+                        },
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: ParserRuleDeclarationLocation::here(),
+            },
+            /* "the next time a source of your choice would deal <damage kind> to <damage receiver> this turn, prevent that damage" */
+            /* The prevention only applies once during the turn, so it makes a generated continuous effect */
+            ParserRule {
+                expanded: RuleLhs::new(&[
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::TheNext {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Time {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::ChoiceReference(intermediates::ChoiceReference::SourceOfYourChoice {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::DamageKind(damage_kind)).id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::DamageReceiver { receiver: dummy() }.id(),
+                    ParserNode::LexerToken(Token::BackwardDuration(time::BackwardDuration::ThisTurn {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Prevent {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::DamageKind(crate::ability_tree::terminals::DamageKind::Damage {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                ]),
+                merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::TheNext {
+                            #[cfg(feature = "spanned_tree")]
+                                span: start_span,
+                        })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Time { .. })),
+                        ParserNode::LexerToken(Token::ChoiceReference(intermediates::ChoiceReference::SourceOfYourChoice { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                        ParserNode::LexerToken(Token::DamageKind(damage_kind)),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
+                        ParserNode::DamageReceiver { receiver },
+                        ParserNode::LexerToken(Token::BackwardDuration(time::BackwardDuration::ThisTurn {
+                            #[cfg(feature = "spanned_tree")]
+                                span: duration_span,
+                        })),
+                        ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
+                        ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Prevent { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That { .. })),
+                        ParserNode::LexerToken(Token::DamageKind(crate::ability_tree::terminals::DamageKind::Damage {
+                            #[cfg(feature = "spanned_tree")]
+                                span: end_span,
+                        })),
+                    ] => Ok(ParserNode::ImperativeKind {
+                        imperative: crate::ability_tree::imperative::ImperativeKind::GenerateContinuousEffect(
+                            crate::ability_tree::imperative::GenerateContinuousEffectImperative {
+                                effect: continuous_effect::ContinuousEffect {
+                                    effect: continuous_effect::ContinuousEffectKind::ReplacementEffect(
+                                        ReplacementEffect::DamagePrevention(DamagePreventionEffect {
+                                            damage_kind: *damage_kind,
+                                            receiver: Some(receiver.clone()),
+                                            source_of_choice: true,
+                                            next_time_only: true,
+                                            #[cfg(feature = "spanned_tree")]
+                                            span: start_span.merge(end_span),
+                                        }),
+                                    ),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: start_span.merge(end_span),
+                                },
+                                duration: time::ForwardDuration::UntilEndOfTurn {
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: *duration_span,
+                                },
+                                #[cfg(feature = "spanned_tree")]
+                                span: start_span.merge(end_span),
+                            },
+                        ),
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: ParserRuleDeclarationLocation::here(),
+            },
+        ]
+    })
+}
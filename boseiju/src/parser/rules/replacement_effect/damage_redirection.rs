@@ -0,0 +1,338 @@
+use crate::ability_tree::ability::statik::continuous_effect;
+use crate::ability_tree::replacement_effect::*;
+use crate::ability_tree::time;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    crate::ability_tree::terminals::DamageKind::all().flat_map(|damage_kind| {
+        [
+            /* "all <damage kind> that would be dealt to <damage receiver> is dealt to <damage receiver> instead" is a redirection effect */
+            ParserRule {
+                expanded: RuleLhs::new(&[
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::DamageKind(damage_kind)).id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::DamageReceiver { receiver: dummy() }.id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::DamageReceiver { receiver: dummy() }.id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Instead {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                ]),
+                merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                            #[cfg(feature = "spanned_tree")]
+                                span: start_span,
+                        })),
+                        ParserNode::LexerToken(Token::DamageKind(damage_kind)),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be { .. })),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
+                        ParserNode::DamageReceiver { receiver: original_receiver },
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is { .. })),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
+                        ParserNode::DamageReceiver { receiver: new_receiver },
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Instead {
+                            #[cfg(feature = "spanned_tree")]
+                                span: end_span,
+                        })),
+                    ] => Ok(ParserNode::ContinuousEffect {
+                        effect: continuous_effect::ContinuousEffect {
+                            effect: continuous_effect::ContinuousEffectKind::ReplacementEffect(
+                                ReplacementEffect::DamageRedirection(DamageRedirectionEffect {
+                                    damage_kind: *damage_kind,
+                                    original_receiver: original_receiver.clone(),
+                                    new_receiver: new_receiver.clone(),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: start_span.merge(end_span),
+                                }),
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(end_span),
+                        },
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: ParserRuleDeclarationLocation::here(),
+            },
+            /* "if <damage kind> would be dealt to <damage receiver>, it's dealt to <damage receiver> instead" is a redirection effect */
+            ParserRule {
+                expanded: RuleLhs::new(&[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::If {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::DamageKind(damage_kind)).id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::DamageReceiver { receiver: dummy() }.id(),
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::DamageReceiver { receiver: dummy() }.id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Instead {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                ]),
+                merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::If {
+                            #[cfg(feature = "spanned_tree")]
+                                span: start_span,
+                        })),
+                        ParserNode::LexerToken(Token::DamageKind(damage_kind)),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be { .. })),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
+                        ParserNode::DamageReceiver { receiver: original_receiver },
+                        ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS { .. })),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
+                        ParserNode::DamageReceiver { receiver: new_receiver },
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Instead {
+                            #[cfg(feature = "spanned_tree")]
+                                span: end_span,
+                        })),
+                    ] => Ok(ParserNode::ContinuousEffect {
+                        effect: continuous_effect::ContinuousEffect {
+                            effect: continuous_effect::ContinuousEffectKind::ReplacementEffect(
+                                ReplacementEffect::DamageRedirection(DamageRedirectionEffect {
+                                    damage_kind: *damage_kind,
+                                    original_receiver: original_receiver.clone(),
+                                    new_receiver: new_receiver.clone(),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: start_span.merge(end_span),
+                                }),
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(end_span),
+                        },
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: ParserRuleDeclarationLocation::here(),
+            },
+            /* "all <damage kind> that would be dealt to <damage receiver> this turn is dealt to <damage receiver> instead" */
+            /* The redirection only lasts for the turn, so it makes a generated continuous effect */
+            ParserRule {
+                expanded: RuleLhs::new(&[
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::DamageKind(damage_kind)).id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::DamageReceiver { receiver: dummy() }.id(),
+                    ParserNode::LexerToken(Token::BackwardDuration(time::BackwardDuration::ThisTurn {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::DamageReceiver { receiver: dummy() }.id(),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Instead {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                ]),
+                merged: ParserNode::ImperativeKind { imperative: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                            #[cfg(feature = "spanned_tree")]
+                                span: start_span,
+                        })),
+                        ParserNode::LexerToken(Token::DamageKind(damage_kind)),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::That { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be { .. })),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
+                        ParserNode::DamageReceiver { receiver: original_receiver },
+                        ParserNode::LexerToken(Token::BackwardDuration(time::BackwardDuration::ThisTurn {
+                            #[cfg(feature = "spanned_tree")]
+                                span: duration_span,
+                        })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Is { .. })),
+                        ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
+                        ParserNode::DamageReceiver { receiver: new_receiver },
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Instead {
+                            #[cfg(feature = "spanned_tree")]
+                                span: end_span,
+                        })),
+                    ] => Ok(ParserNode::ImperativeKind {
+                        imperative: crate::ability_tree::imperative::ImperativeKind::GenerateContinuousEffect(
+                            crate::ability_tree::imperative::GenerateContinuousEffectImperative {
+                                effect: continuous_effect::ContinuousEffect {
+                                    effect: continuous_effect::ContinuousEffectKind::ReplacementEffect(
+                                        ReplacementEffect::DamageRedirection(DamageRedirectionEffect {
+                                            damage_kind: *damage_kind,
+                                            original_receiver: original_receiver.clone(),
+                                            new_receiver: new_receiver.clone(),
+                                            #[cfg(feature = "spanned_tree")]
+                                            span: start_span.merge(end_span),
+                                        }),
+                                    ),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: start_span.merge(end_span),
+                                },
+                                duration: time::ForwardDuration::UntilEndOfTurn {
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: *duration_span,
+                                },
+                                #[cfg(feature = "spanned_tree")]
+                                span: start_span.merge(end_span),
+                            },
+                        ),
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: ParserRuleDeclarationLocation::here(),
+            },
+        ]
+    })
+}
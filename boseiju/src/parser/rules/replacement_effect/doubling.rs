@@ -0,0 +1,194 @@
+use crate::ability_tree::ability::statik::continuous_effect;
+use crate::ability_tree::replacement_effect::*;
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* "if <replaced event>, twice that many <counter> are put on it instead" is a doubling effect */
+    let counters_rules = crate::ability_tree::terminals::Counter::all().map(|counter| ParserRule {
+        expanded: RuleLhs::new(&[
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::If {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::ReplacedEvent { event: dummy() }.id(),
+            ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::Number(intermediates::Number::TwiceThatMany {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::Counter(counter)).id(),
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Are {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Instead {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+        ]),
+        merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::If {
+                    #[cfg(feature = "spanned_tree")]
+                        span: start_span,
+                })),
+                ParserNode::ReplacedEvent { event },
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
+                ParserNode::LexerToken(Token::Number(intermediates::Number::TwiceThatMany { .. })),
+                ParserNode::LexerToken(Token::Counter(_)),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Are { .. })),
+                ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put { .. })),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On { .. })),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It { .. })),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Instead {
+                    #[cfg(feature = "spanned_tree")]
+                        span: end_span,
+                })),
+            ] => Ok(ParserNode::ContinuousEffect {
+                effect: continuous_effect::ContinuousEffect {
+                    effect: continuous_effect::ContinuousEffectKind::ReplacementEffect(ReplacementEffect::Doubling(
+                        DoublingEffect {
+                            replaced_event: Box::new(event.clone()),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(end_span),
+                        },
+                    )),
+                    #[cfg(feature = "spanned_tree")]
+                    span: start_span.merge(end_span),
+                },
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    });
+
+    [
+        /* "if <replaced event>, it creates twice that many of those tokens instead" is a doubling effect */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::If {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::ReplacedEvent { event: dummy() }.id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                    keyword_action: mtg_data::KeywordAction::Create,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::Number(intermediates::Number::TwiceThatMany {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Those {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::Supertype(crate::ability_tree::terminals::Supertype {
+                    supertype: mtg_data::Supertype::Token,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Instead {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::ContinuousEffect { effect: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::If {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::ReplacedEvent { event },
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::It { .. })),
+                    ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                        keyword_action: mtg_data::KeywordAction::Create,
+                        ..
+                    })),
+                    ParserNode::LexerToken(Token::Number(intermediates::Number::TwiceThatMany { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Those { .. })),
+                    ParserNode::LexerToken(Token::Supertype(crate::ability_tree::terminals::Supertype {
+                        supertype: mtg_data::Supertype::Token,
+                        ..
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Instead {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::ContinuousEffect {
+                    effect: continuous_effect::ContinuousEffect {
+                        effect: continuous_effect::ContinuousEffectKind::ReplacementEffect(ReplacementEffect::Doubling(
+                            DoublingEffect {
+                                replaced_event: Box::new(event.clone()),
+                                #[cfg(feature = "spanned_tree")]
+                                span: start_span.merge(end_span),
+                            },
+                        )),
+                        #[cfg(feature = "spanned_tree")]
+                        span: start_span.merge(end_span),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+    .chain(counters_rules)
+}
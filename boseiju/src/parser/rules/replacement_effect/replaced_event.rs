@@ -0,0 +1,554 @@
+use crate::lexer::tokens::Token;
+use crate::lexer::tokens::intermediates;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* Replaced events are the events of triggered abilities written with "would", as in "if a creature would die" */
+    let damage_rules = crate::ability_tree::terminals::DamageKind::all().map(|damage_kind| {
+        /* "<creature reference> would deal <damage kind>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Creature { creature: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::DamageKind(damage_kind)).id(),
+            ]),
+            merged: ParserNode::ReplacedEvent { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Creature { creature },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Deals { .. })),
+                    ParserNode::LexerToken(Token::DamageKind(damage_kind)),
+                ] => Ok(ParserNode::ReplacedEvent {
+                    event: crate::ability_tree::event::Event::CreaturePerformsAction(
+                        crate::ability_tree::event::CreaturePerformsActionEvent {
+                            action: crate::ability_tree::action::CreatureAction::DealsDamage(
+                                crate::ability_tree::action::CreatureDealsDamageAction {
+                                    creature: creature.clone(),
+                                    damage_kind: *damage_kind,
+                                    to_player: None,
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: creature.node_span().merge(&damage_kind.node_span()),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: creature.node_span().merge(&damage_kind.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        }
+    });
+
+    /* "<number> <counter> would be put on <permanent>" */
+    let counters_rules = crate::ability_tree::terminals::Counter::all().map(|counter| ParserRule {
+        expanded: RuleLhs::new(&[
+            ParserNode::Number { number: dummy() }.id(),
+            ParserNode::LexerToken(Token::Counter(counter)).id(),
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::Permanent { permanent: dummy() }.id(),
+        ]),
+        merged: ParserNode::ReplacedEvent { event: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[
+                ParserNode::Number { number },
+                ParserNode::LexerToken(Token::Counter(counter)),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be { .. })),
+                ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put { .. })),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::On { .. })),
+                ParserNode::Permanent { permanent },
+            ] => Ok(ParserNode::ReplacedEvent {
+                event: crate::ability_tree::event::Event::PermanentPerformsAction(
+                    crate::ability_tree::event::PermanentPerformsActionEvent {
+                        action: crate::ability_tree::action::PermanentAction::CountersPutOn(
+                            crate::ability_tree::action::CountersPutOnPermanentAction {
+                                permanent: permanent.clone(),
+                                amount: number.clone(),
+                                counter: Some(counter.clone()),
+                                #[cfg(feature = "spanned_tree")]
+                                span: number.node_span().merge(&permanent.node_span()),
+                            },
+                        ),
+                        #[cfg(feature = "spanned_tree")]
+                        span: number.node_span().merge(&permanent.node_span()),
+                    },
+                ),
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    });
+
+    [
+        /* "an effect would create <number> tokens under your control" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::An {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Effect {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                    keyword_action: mtg_data::KeywordAction::Create,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Number { number: dummy() }.id(),
+                ParserNode::LexerToken(Token::Supertype(crate::ability_tree::terminals::Supertype {
+                    supertype: mtg_data::Supertype::Token,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::UnderControl(intermediates::UnderControl::UnderYourControl {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::ReplacedEvent { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::An {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Effect { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                    ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                        keyword_action: mtg_data::KeywordAction::Create,
+                        ..
+                    })),
+                    ParserNode::Number { number },
+                    ParserNode::LexerToken(Token::Supertype(crate::ability_tree::terminals::Supertype {
+                        supertype: mtg_data::Supertype::Token,
+                        ..
+                    })),
+                    ParserNode::LexerToken(Token::UnderControl(intermediates::UnderControl::UnderYourControl {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
+                ] => Ok(ParserNode::ReplacedEvent {
+                    event: crate::ability_tree::event::Event::PlayerPerformsAction(
+                        crate::ability_tree::event::PlayerPerformsActionEvent {
+                            action: crate::ability_tree::action::PlayerAction::CreatesTokens(
+                                crate::ability_tree::action::PlayerCreatesTokensAction {
+                                    player: crate::ability_tree::player::PlayerSpecifier::You {
+                                        #[cfg(feature = "spanned_tree")]
+                                        span: *end_span,
+                                    },
+                                    amount: number.clone(),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: start_span.merge(end_span),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: start_span.merge(end_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<creature reference> would die" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Creature { creature: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CardActions(intermediates::CardActions::Dies {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::ReplacedEvent { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Creature { creature },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                    ParserNode::LexerToken(Token::CardActions(intermediates::CardActions::Dies {
+                        #[cfg(feature = "spanned_tree")]
+                            span: dies_span,
+                    })),
+                ] => Ok(ParserNode::ReplacedEvent {
+                    event: crate::ability_tree::event::Event::CreaturePerformsAction(
+                        crate::ability_tree::event::CreaturePerformsActionEvent {
+                            action: crate::ability_tree::action::CreatureAction::Dies(
+                                crate::ability_tree::action::CreatureDiesAction {
+                                    creature: creature.clone(),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: creature.node_span().merge(dies_span),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: creature.node_span().merge(dies_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<player> would cast <spell>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                    keyword_action: mtg_data::KeywordAction::Cast,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Spell { spell: dummy() }.id(),
+            ]),
+            merged: ParserNode::ReplacedEvent { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                    ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                        keyword_action: mtg_data::KeywordAction::Cast,
+                        ..
+                    })),
+                    ParserNode::Spell { spell },
+                ] => Ok(ParserNode::ReplacedEvent {
+                    event: crate::ability_tree::event::Event::PlayerPerformsAction(
+                        crate::ability_tree::event::PlayerPerformsActionEvent {
+                            action: crate::ability_tree::action::PlayerAction::CastsSpell(
+                                crate::ability_tree::action::PlayerCastsSpellAction {
+                                    player: player.clone(),
+                                    spell: spell.clone(),
+                                    from_zone: None,
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: player.node_span().merge(&spell.node_span()),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: player.node_span().merge(&spell.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<player> would draw a card" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::A {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::ReplacedEvent { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Draw { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::A { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Card {
+                        #[cfg(feature = "spanned_tree")]
+                            span: card_span,
+                    })),
+                ] => Ok(ParserNode::ReplacedEvent {
+                    event: crate::ability_tree::event::Event::PlayerPerformsAction(
+                        crate::ability_tree::event::PlayerPerformsActionEvent {
+                            action: crate::ability_tree::action::PlayerAction::DrawsCard(
+                                crate::ability_tree::action::PlayerDrawsCardAction {
+                                    player: player.clone(),
+                                    card_rank_in_turn: None,
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: player.node_span().merge(card_span),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: player.node_span().merge(card_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<player> would gain life" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::ReplacedEvent { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Gain { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Life {
+                        #[cfg(feature = "spanned_tree")]
+                            span: life_span,
+                    })),
+                ] => Ok(ParserNode::ReplacedEvent {
+                    event: crate::ability_tree::event::Event::PlayerPerformsAction(
+                        crate::ability_tree::event::PlayerPerformsActionEvent {
+                            action: crate::ability_tree::action::PlayerAction::GainsLife(
+                                crate::ability_tree::action::PlayerGainsLifeAction {
+                                    player: player.clone(),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: player.node_span().merge(life_span),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: player.node_span().merge(life_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<permanent> would leave the battlefield" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Permanent { permanent: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CardActions(intermediates::CardActions::Leave {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::GlobalZone(intermediates::GlobalZone::TheBattlefield {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::ReplacedEvent { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Permanent { permanent },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                    ParserNode::LexerToken(Token::CardActions(intermediates::CardActions::Leave { .. })),
+                    ParserNode::LexerToken(Token::GlobalZone(intermediates::GlobalZone::TheBattlefield {
+                        #[cfg(feature = "spanned_tree")]
+                            span: battlefield_span,
+                    })),
+                ] => Ok(ParserNode::ReplacedEvent {
+                    event: crate::ability_tree::event::Event::ZoneChange(crate::ability_tree::event::ZoneChangeEvent {
+                        card: permanent.to_card(),
+                        from: Some(crate::ability_tree::zone::ZoneReference::TheBattlefield {
+                            #[cfg(feature = "spanned_tree")]
+                            span: *battlefield_span,
+                        }),
+                        to: None,
+                        #[cfg(feature = "spanned_tree")]
+                        span: permanent.node_span().merge(battlefield_span),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<card> would be put into <zone>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Card { card: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Into {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::ZoneReference { zone: dummy() }.id(),
+            ]),
+            merged: ParserNode::ReplacedEvent { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Card { card },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be { .. })),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Into { .. })),
+                    ParserNode::ZoneReference { zone },
+                ] => Ok(ParserNode::ReplacedEvent {
+                    event: crate::ability_tree::event::Event::ZoneChange(crate::ability_tree::event::ZoneChangeEvent {
+                        card: card.clone(),
+                        from: None,
+                        to: Some(zone.clone()),
+                        #[cfg(feature = "spanned_tree")]
+                        span: card.node_span().merge(&zone.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "<card> would be put into <zone> from <zone>" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Card { card: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Into {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::ZoneReference { zone: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::From {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::ZoneReference { zone: dummy() }.id(),
+            ]),
+            merged: ParserNode::ReplacedEvent { event: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Card { card },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Would { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Be { .. })),
+                    ParserNode::LexerToken(Token::ActionKeyword(intermediates::ActionKeyword::Put { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Into { .. })),
+                    ParserNode::ZoneReference { zone: to },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::From { .. })),
+                    ParserNode::ZoneReference { zone: from },
+                ] => Ok(ParserNode::ReplacedEvent {
+                    event: crate::ability_tree::event::Event::ZoneChange(crate::ability_tree::event::ZoneChangeEvent {
+                        card: card.clone(),
+                        from: Some(from.clone()),
+                        to: Some(to.clone()),
+                        #[cfg(feature = "spanned_tree")]
+                        span: card.node_span().merge(&from.node_span()),
+                    }),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+    ]
+    .into_iter()
+    .chain(damage_rules)
+    .chain(counters_rules)
+}
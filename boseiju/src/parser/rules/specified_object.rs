@@ -1,19 +1,23 @@
 mod specified_artifact;
+mod specified_battle;
 mod specified_card;
 mod specified_creature;
 mod specified_enchantment;
 mod specified_land;
 mod specified_permanent;
+mod specified_planeswalker;
 mod specified_spell;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
     [
         specified_artifact::rules().collect::<Vec<_>>(),
+        specified_battle::rules().collect::<Vec<_>>(),
         specified_card::rules().collect::<Vec<_>>(),
         specified_creature::rules().collect::<Vec<_>>(),
         specified_enchantment::rules().collect::<Vec<_>>(),
         specified_land::rules().collect::<Vec<_>>(),
         specified_permanent::rules().collect::<Vec<_>>(),
+        specified_planeswalker::rules().collect::<Vec<_>>(),
         specified_spell::rules().collect::<Vec<_>>(),
     ]
     .into_iter()
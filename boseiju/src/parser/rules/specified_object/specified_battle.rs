@@ -0,0 +1,11 @@
+mod battle;
+mod battle_specifiers;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    [
+        battle::rules().collect::<Vec<_>>(),
+        battle_specifiers::rules().collect::<Vec<_>>(),
+    ]
+    .into_iter()
+    .flatten()
+}
@@ -0,0 +1,79 @@
+use crate::ability_tree::object;
+use crate::lexer::tokens::Token;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* "<count> <battle kind> <battle specifiers>" makes a specified battle  */
+
+    let specifiers_to_specified_battles = ParserRule {
+        expanded: RuleLhs::new(&[
+            ParserNode::BattleKind { battle: dummy() }.id(),
+            ParserNode::BattleSpecifiers { specifiers: dummy() }.id(),
+        ]),
+        merged: ParserNode::SpecifiedBattle { battle: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[ParserNode::BattleKind { battle }, ParserNode::BattleSpecifiers { specifiers }] => {
+                Ok(ParserNode::SpecifiedBattle {
+                    battle: object::specified_object::SpecifiedBattle {
+                        kind: battle.clone(),
+                        specifiers: Some(specifiers.clone()),
+                        #[cfg(feature = "spanned_tree")]
+                        span: specifiers.node_span().merge(&battle.node_span()),
+                    },
+                })
+            }
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    };
+
+    /* battle subtypes can be used in place of the "battle" marker, adding a specifier */
+    let subtype_to_battle_specifiers = crate::ability_tree::terminals::BattleSubtype::all().map(|subtype| ParserRule {
+        expanded: RuleLhs::new(&[
+            ParserNode::LexerToken(Token::BattleSubtype(subtype)).id(),
+            ParserNode::BattleSpecifiers { specifiers: dummy() }.id(),
+        ]),
+        merged: ParserNode::SpecifiedBattle { battle: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[
+                ParserNode::LexerToken(Token::BattleSubtype(subtype)),
+                ParserNode::BattleSpecifiers { specifiers },
+            ] => Ok(ParserNode::SpecifiedBattle {
+                battle: object::specified_object::SpecifiedBattle {
+                    kind: object::kind::BattleKind::Battle {
+                        #[cfg(feature = "spanned_tree")]
+                        span: subtype.node_span(),
+                    },
+                    specifiers: Some(
+                        specifiers.add_factor_specifier(object::specified_object::BattleSpecifier::Subtype(
+                            object::specified_object::BattleSubtypeSpecifier {
+                                subtype: *subtype,
+                                #[cfg(feature = "spanned_tree")]
+                                span: subtype.node_span(),
+                            },
+                        )),
+                    ),
+                    #[cfg(feature = "spanned_tree")]
+                    span: specifiers.node_span().merge(&subtype.node_span()),
+                },
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    });
+
+    [
+        vec![specifiers_to_specified_battles],
+        subtype_to_battle_specifiers.collect::<Vec<_>>(),
+    ]
+    .into_iter()
+    .flatten()
+}
@@ -0,0 +1,69 @@
+use crate::ability_tree::object;
+use crate::lexer::tokens::Token;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* "<planeswalker kind>" makes a specified planeswalker  */
+
+    let specifiers_to_specified_planeswalkers = ParserRule {
+        expanded: RuleLhs::new(&[ParserNode::PlaneswalkerKind { planeswalker: dummy() }.id()]),
+        merged: ParserNode::SpecifiedPlaneswalker { planeswalker: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[ParserNode::PlaneswalkerKind { planeswalker }] => Ok(ParserNode::SpecifiedPlaneswalker {
+                planeswalker: object::specified_object::SpecifiedPlaneswalker {
+                    kind: planeswalker.clone(),
+                    specifiers: None,
+                    #[cfg(feature = "spanned_tree")]
+                    span: planeswalker.node_span(),
+                },
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    };
+
+    /* planeswalker subtypes can be used in place of the "planeswalker" marker, adding a specifier */
+    let subtype_to_planeswalker_specifiers =
+        crate::ability_tree::terminals::PlaneswalkerSubtype::all().map(|subtype| ParserRule {
+            expanded: RuleLhs::new(&[ParserNode::LexerToken(Token::PlaneswalkerSubtype(subtype)).id()]),
+            merged: ParserNode::SpecifiedPlaneswalker { planeswalker: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[ParserNode::LexerToken(Token::PlaneswalkerSubtype(subtype))] => Ok(ParserNode::SpecifiedPlaneswalker {
+                    planeswalker: object::specified_object::SpecifiedPlaneswalker {
+                        kind: object::kind::PlaneswalkerKind::Planeswalker {
+                            #[cfg(feature = "spanned_tree")]
+                            span: subtype.node_span(),
+                        },
+                        specifiers: Some(object::specified_object::Specifiers::Single(
+                            object::specified_object::PlaneswalkerSpecifier::Subtype(
+                                object::specified_object::PlaneswalkerSubtypeSpecifier {
+                                    subtype: *subtype,
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: subtype.node_span(),
+                                },
+                            ),
+                        )),
+                        #[cfg(feature = "spanned_tree")]
+                        span: subtype.node_span(),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        });
+
+    [
+        vec![specifiers_to_specified_planeswalkers],
+        subtype_to_planeswalker_specifiers.collect::<Vec<_>>(),
+    ]
+    .into_iter()
+    .flatten()
+}
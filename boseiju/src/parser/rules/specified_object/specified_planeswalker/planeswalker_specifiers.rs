@@ -0,0 +1,81 @@
+use crate::ability_tree::object;
+use crate::lexer::tokens::Token;
+use crate::parser::ParserNode;
+use crate::parser::rules::ParserRule;
+use crate::parser::rules::ParserRuleDeclarationLocation;
+use crate::parser::rules::RuleLhs;
+use crate::utils::dummy;
+use idris::Idris;
+
+#[cfg(feature = "spanned_tree")]
+use crate::ability_tree::AbilityTreeNode;
+
+pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* "<count> <planeswalker kind> <planeswalker specifiers>" makes a specified planeswalker  */
+
+    let specifiers_to_specified_planeswalkers = ParserRule {
+        expanded: RuleLhs::new(&[
+            ParserNode::PlaneswalkerKind { planeswalker: dummy() }.id(),
+            ParserNode::PlaneswalkerSpecifiers { specifiers: dummy() }.id(),
+        ]),
+        merged: ParserNode::SpecifiedPlaneswalker { planeswalker: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[
+                ParserNode::PlaneswalkerKind { planeswalker },
+                ParserNode::PlaneswalkerSpecifiers { specifiers },
+            ] => Ok(ParserNode::SpecifiedPlaneswalker {
+                planeswalker: object::specified_object::SpecifiedPlaneswalker {
+                    kind: planeswalker.clone(),
+                    specifiers: Some(specifiers.clone()),
+                    #[cfg(feature = "spanned_tree")]
+                    span: specifiers.node_span().merge(&planeswalker.node_span()),
+                },
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    };
+
+    /* planeswalker subtypes can be used in place of the "planeswalker" marker, adding a specifier */
+    let subtype_to_planeswalker_specifiers =
+        crate::ability_tree::terminals::PlaneswalkerSubtype::all().map(|subtype| ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::PlaneswalkerSubtype(subtype)).id(),
+                ParserNode::PlaneswalkerSpecifiers { specifiers: dummy() }.id(),
+            ]),
+            merged: ParserNode::SpecifiedPlaneswalker { planeswalker: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::PlaneswalkerSubtype(subtype)),
+                    ParserNode::PlaneswalkerSpecifiers { specifiers },
+                ] => Ok(ParserNode::SpecifiedPlaneswalker {
+                    planeswalker: object::specified_object::SpecifiedPlaneswalker {
+                        kind: object::kind::PlaneswalkerKind::Planeswalker {
+                            #[cfg(feature = "spanned_tree")]
+                            span: subtype.node_span(),
+                        },
+                        specifiers: Some(specifiers.add_factor_specifier(
+                            object::specified_object::PlaneswalkerSpecifier::Subtype(
+                                object::specified_object::PlaneswalkerSubtypeSpecifier {
+                                    subtype: *subtype,
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: subtype.node_span(),
+                                },
+                            ),
+                        )),
+                        #[cfg(feature = "spanned_tree")]
+                        span: specifiers.node_span().merge(&subtype.node_span()),
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        });
+
+    [
+        vec![specifiers_to_specified_planeswalkers],
+        subtype_to_planeswalker_specifiers.collect::<Vec<_>>(),
+    ]
+    .into_iter()
+    .flatten()
+}
@@ -1,3 +1,4 @@
+mod characteristic_defining_ability;
 mod statik_ability_kind;
 
 use crate::lexer::tokens::Token;
@@ -79,6 +80,186 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
+        /* "<static ab kind> as long as <condition>" -> static ability */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::StaticAbilityKind { kind: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::AsLongAs {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Condition { condition: dummy() }.id(),
+            ]),
+            merged: ParserNode::WrittenAbility { ability: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::StaticAbilityKind { kind },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::AsLongAs {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::Condition { condition },
+                ] => Ok(ParserNode::WrittenAbility {
+                    ability: crate::ability_tree::ability::WrittenAbility::Static(
+                        crate::ability_tree::ability::statik::StaticAbility {
+                            kind: kind.clone(),
+                            condition: Some(crate::ability_tree::conditional::Conditional::If(
+                                crate::ability_tree::conditional::ConditionalIf {
+                                    condition: condition.clone(),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: condition.node_span().merge(start_span),
+                                },
+                            )),
+                            #[cfg(feature = "spanned_tree")]
+                            span: kind.node_span().merge(&condition.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "during your turn, <static ab kind>" -> static ability */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::During {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::StaticAbilityKind { kind: dummy() }.id(),
+            ]),
+            merged: ParserNode::WrittenAbility { ability: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::During {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                        #[cfg(feature = "spanned_tree")]
+                            span: turn_span,
+                    })),
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
+                    ParserNode::StaticAbilityKind { kind },
+                ] => Ok(ParserNode::WrittenAbility {
+                    ability: crate::ability_tree::ability::WrittenAbility::Static(
+                        crate::ability_tree::ability::statik::StaticAbility {
+                            kind: kind.clone(),
+                            condition: Some(crate::ability_tree::conditional::Conditional::If(
+                                crate::ability_tree::conditional::ConditionalIf {
+                                    condition: crate::ability_tree::conditional::Condition::ThisIsYourTurn(
+                                        crate::ability_tree::conditional::ConditionThisIsYourTurn {
+                                            #[cfg(feature = "spanned_tree")]
+                                            span: start_span.merge(turn_span),
+                                        },
+                                    ),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: start_span.merge(turn_span),
+                                },
+                            )),
+                            #[cfg(feature = "spanned_tree")]
+                            span: kind.node_span().merge(start_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "during each of your turns, <static ab kind>" -> static ability, same as "during your turn" */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::During {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::StaticAbilityKind { kind: dummy() }.id(),
+            ]),
+            merged: ParserNode::WrittenAbility { ability: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::During {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                        #[cfg(feature = "spanned_tree")]
+                            span: turn_span,
+                    })),
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
+                    ParserNode::StaticAbilityKind { kind },
+                ] => Ok(ParserNode::WrittenAbility {
+                    ability: crate::ability_tree::ability::WrittenAbility::Static(
+                        crate::ability_tree::ability::statik::StaticAbility {
+                            kind: kind.clone(),
+                            condition: Some(crate::ability_tree::conditional::Conditional::If(
+                                crate::ability_tree::conditional::ConditionalIf {
+                                    condition: crate::ability_tree::conditional::Condition::ThisIsYourTurn(
+                                        crate::ability_tree::conditional::ConditionThisIsYourTurn {
+                                            #[cfg(feature = "spanned_tree")]
+                                            span: start_span.merge(turn_span),
+                                        },
+                                    ),
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: start_span.merge(turn_span),
+                                },
+                            )),
+                            #[cfg(feature = "spanned_tree")]
+                            span: kind.node_span().merge(start_span),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
         /* "<static ab kind> if <condition>" -> static ability */
         ParserRule {
             expanded: RuleLhs::new(&[
@@ -121,7 +302,11 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
         },
     ];
 
-    [statik_ability_rules, statik_ability_kind::rules().collect::<Vec<_>>()]
-        .into_iter()
-        .flatten()
+    [
+        statik_ability_rules,
+        characteristic_defining_ability::rules().collect::<Vec<_>>(),
+        statik_ability_kind::rules().collect::<Vec<_>>(),
+    ]
+    .into_iter()
+    .flatten()
 }
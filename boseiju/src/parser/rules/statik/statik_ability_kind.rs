@@ -11,6 +11,58 @@ use idris::Idris;
 use crate::ability_tree::AbilityTreeNode;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* "<player> may <cast|play> <card> from <zone>" make alternative casting permissions */
+    let alternative_casting_permissions = [mtg_data::KeywordAction::Cast, mtg_data::KeywordAction::Play]
+        .into_iter()
+        .map(|keyword_action| ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::Player { player: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::May {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
+                    keyword_action,
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Card { card: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::From {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::ZoneReference { zone: dummy() }.id(),
+            ]),
+            merged: ParserNode::StaticAbilityKind { kind: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::Player { player },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::May { .. })),
+                    ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction { keyword_action, .. })),
+                    ParserNode::Card { card },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::From { .. })),
+                    ParserNode::ZoneReference { zone },
+                ] => Ok(ParserNode::StaticAbilityKind {
+                    kind: crate::ability_tree::ability::statik::StaticAbilityKind::AlternativeCastingPermissions(
+                        crate::ability_tree::ability::statik::alterative_casting_permissions::AlternativeCastingPermissions {
+                            player: player.clone(),
+                            action: *keyword_action,
+                            object: card.clone(),
+                            from_zone: zone.clone(),
+                            additional_cost: None,
+                            #[cfg(feature = "spanned_tree")]
+                            span: player.node_span().merge(&zone.node_span()),
+                        },
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        });
+
     [
         /* Continuous effect make a static ability kind */
         ParserRule {
@@ -41,7 +93,7 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
-        /* Alternative casting permissions make static ability kind */
+        /* Alternative casting permissions with additionnal cost make static ability kind */
         ParserRule {
             expanded: RuleLhs::new(&[
                 ParserNode::Player { player: dummy() }.id(),
@@ -63,6 +115,19 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                 }))
                 .id(),
                 ParserNode::ZoneReference { zone: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::By {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Cost { cost: dummy() }.id(),
+                ParserNode::LexerToken(Token::InAdditionToPayingItsOtherCost(
+                    intermediates::InAdditionToPayingItsOtherCost {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    },
+                ))
+                .id(),
             ]),
             merged: ParserNode::StaticAbilityKind { kind: dummy() }.id(),
             reduction: |nodes: &[ParserNode]| match &nodes {
@@ -76,15 +141,24 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                     ParserNode::Card { card },
                     ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::From { .. })),
                     ParserNode::ZoneReference { zone },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::By { .. })),
+                    ParserNode::Cost { cost: inner },
+                    ParserNode::LexerToken(Token::InAdditionToPayingItsOtherCost(
+                        intermediates::InAdditionToPayingItsOtherCost {
+                            #[cfg(feature = "spanned_tree")]
+                                span: in_addition_span,
+                        },
+                    )),
                 ] => Ok(ParserNode::StaticAbilityKind {
                     kind: crate::ability_tree::ability::statik::StaticAbilityKind::AlternativeCastingPermissions(
                         crate::ability_tree::ability::statik::alterative_casting_permissions::AlternativeCastingPermissions {
                             player: player.clone(),
+                            action: mtg_data::KeywordAction::Cast,
                             object: card.clone(),
                             from_zone: zone.clone(),
-                            additional_cost: None,
+                            additional_cost: Some(inner.clone()),
                             #[cfg(feature = "spanned_tree")]
-                            span: player.node_span().merge(&zone.node_span()),
+                            span: player.node_span().merge(in_addition_span),
                         },
                     ),
                 }),
@@ -92,11 +166,30 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: ParserRuleDeclarationLocation::here(),
         },
-        /* Alternative casting permissions with additionnal cost make static ability kind */
+        /* "As an additional cost to cast this spell, <cost>" makes an additional casting cost */
         ParserRule {
             expanded: RuleLhs::new(&[
-                ParserNode::Player { player: dummy() }.id(),
-                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::May {
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::As {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::An {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Additional {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Cost {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To {
                     #[cfg(feature = "spanned_tree")]
                     span: Default::default(),
                 }))
@@ -107,56 +200,124 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
                     span: Default::default(),
                 }))
                 .id(),
-                ParserNode::Card { card: dummy() }.id(),
-                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::From {
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::This {
                     #[cfg(feature = "spanned_tree")]
                     span: Default::default(),
                 }))
                 .id(),
-                ParserNode::ZoneReference { zone: dummy() }.id(),
-                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::By {
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Spell {
                     #[cfg(feature = "spanned_tree")]
                     span: Default::default(),
                 }))
                 .id(),
-                ParserNode::Cost { cost: dummy() }.id(),
-                ParserNode::LexerToken(Token::InAdditionToPayingItsOtherCost(
-                    intermediates::InAdditionToPayingItsOtherCost {
-                        #[cfg(feature = "spanned_tree")]
-                        span: Default::default(),
-                    },
-                ))
+                ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
                 .id(),
+                ParserNode::Cost { cost: dummy() }.id(),
             ]),
             merged: ParserNode::StaticAbilityKind { kind: dummy() }.id(),
             reduction: |nodes: &[ParserNode]| match &nodes {
                 &[
-                    ParserNode::Player { player },
-                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::May { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::As {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::An { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Additional { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Cost { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::To { .. })),
                     ParserNode::LexerToken(Token::KeywordAction(intermediates::KeywordAction {
                         keyword_action: mtg_data::KeywordAction::Cast,
                         ..
                     })),
-                    ParserNode::Card { card },
-                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::From { .. })),
-                    ParserNode::ZoneReference { zone },
-                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::By { .. })),
-                    ParserNode::Cost { cost: inner },
-                    ParserNode::LexerToken(Token::InAdditionToPayingItsOtherCost(
-                        intermediates::InAdditionToPayingItsOtherCost {
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::This { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Spell { .. })),
+                    ParserNode::LexerToken(Token::ControlFlow(intermediates::ControlFlow::Comma { .. })),
+                    ParserNode::Cost { cost },
+                ] => Ok(ParserNode::StaticAbilityKind {
+                    kind: crate::ability_tree::ability::statik::StaticAbilityKind::AdditionalCastingCost(
+                        crate::ability_tree::ability::statik::additional_casting_cost::AdditionalCastingCost {
+                            cost: cost.clone(),
                             #[cfg(feature = "spanned_tree")]
-                                span: in_addition_span,
+                            span: cost.span.merge(start_span),
                         },
-                    )),
+                    ),
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: ParserRuleDeclarationLocation::here(),
+        },
+        /* "You may <cost> rather than pay this spell's mana cost" makes an alternative casting cost */
+        ParserRule {
+            expanded: RuleLhs::new(&[
+                ParserNode::LexerToken(Token::PlayerSpecifier(intermediates::PlayerSpecifier::You {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::May {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::Cost { cost: dummy() }.id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::RatherThan {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Pay {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::This {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Spell {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+                ParserNode::LexerToken(Token::CardProperty(intermediates::CardProperty::ManaValue {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id(),
+            ]),
+            merged: ParserNode::StaticAbilityKind { kind: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::PlayerSpecifier(intermediates::PlayerSpecifier::You {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::May { .. })),
+                    ParserNode::Cost { cost },
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::RatherThan { .. })),
+                    ParserNode::LexerToken(Token::PlayerAction(intermediates::PlayerAction::Pay { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::This { .. })),
+                    ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Spell { .. })),
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::ApostropheS { .. })),
+                    ParserNode::LexerToken(Token::CardProperty(intermediates::CardProperty::ManaValue {
+                        #[cfg(feature = "spanned_tree")]
+                            span: end_span,
+                    })),
                 ] => Ok(ParserNode::StaticAbilityKind {
-                    kind: crate::ability_tree::ability::statik::StaticAbilityKind::AlternativeCastingPermissions(
-                        crate::ability_tree::ability::statik::alterative_casting_permissions::AlternativeCastingPermissions {
-                            player: player.clone(),
-                            object: card.clone(),
-                            from_zone: zone.clone(),
-                            additional_cost: Some(inner.clone()),
+                    kind: crate::ability_tree::ability::statik::StaticAbilityKind::AlternativeCastingCost(
+                        crate::ability_tree::ability::statik::alternative_casting_cost::AlternativeCastingCost {
+                            cost: cost.clone(),
                             #[cfg(feature = "spanned_tree")]
-                            span: player.node_span().merge(in_addition_span),
+                            span: start_span.merge(end_span),
                         },
                     ),
                 }),
@@ -166,4 +327,5 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
         },
     ]
     .into_iter()
+    .chain(alternative_casting_permissions)
 }
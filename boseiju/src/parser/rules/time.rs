@@ -8,43 +8,35 @@ use crate::utils::dummy;
 use idris::Idris;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* Durations lexed as a single token, such as "until end of turn" or "until your next turn" */
     [
-        /* until end of turn to forward duration */
-        ParserRule {
-            expanded: RuleLhs::new(&[
-                ParserNode::LexerToken(Token::ForwardDuration(time::ForwardDuration::UntilEndOfTurn {
-                    #[cfg(feature = "spanned_tree")]
-                    span: Default::default(),
-                }))
-                .id(),
-            ]),
-            merged: ParserNode::ForwardDuration { duration: dummy() }.id(),
-            reduction: |nodes: &[ParserNode]| match &nodes {
-                &[ParserNode::LexerToken(Token::ForwardDuration(duration))] => Ok(ParserNode::ForwardDuration {
-                    duration: duration.clone(),
-                }),
-                _ => Err("Provided tokens do not match rule definition"),
-            },
-            creation_loc: ParserRuleDeclarationLocation::here(),
+        time::ForwardDuration::UntilEndOfCombat {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
         },
-        /* until end of next turn to forward duration */
-        ParserRule {
-            expanded: RuleLhs::new(&[ParserNode::LexerToken(Token::ForwardDuration(
-                time::ForwardDuration::UntilEndOfYourNextTurn {
-                    #[cfg(feature = "spanned_tree")]
-                    span: Default::default(),
-                },
-            ))
-            .id()]),
-            merged: ParserNode::ForwardDuration { duration: dummy() }.id(),
-            reduction: |nodes: &[ParserNode]| match &nodes {
-                &[ParserNode::LexerToken(Token::ForwardDuration(duration))] => Ok(ParserNode::ForwardDuration {
-                    duration: duration.clone(),
-                }),
-                _ => Err("Provided tokens do not match rule definition"),
-            },
-            creation_loc: ParserRuleDeclarationLocation::here(),
+        time::ForwardDuration::UntilEndOfTurn {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        time::ForwardDuration::UntilEndOfYourNextTurn {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        time::ForwardDuration::UntilYourNextTurn {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
         },
     ]
     .into_iter()
+    .map(|duration| ParserRule {
+        expanded: RuleLhs::new(&[ParserNode::LexerToken(Token::ForwardDuration(duration)).id()]),
+        merged: ParserNode::ForwardDuration { duration: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[ParserNode::LexerToken(Token::ForwardDuration(duration))] => Ok(ParserNode::ForwardDuration {
+                duration: duration.clone(),
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: ParserRuleDeclarationLocation::here(),
+    })
 }
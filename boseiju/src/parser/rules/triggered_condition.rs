@@ -8,6 +8,97 @@ use idris::Idris;
 use crate::ability_tree::AbilityTreeNode;
 
 pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
+    /* "<whenever|when> <event> during each of your turns" is the same as "during your turn" */
+    let during_each_of_your_turns = [
+        intermediates::EnglishKeyword::Whenever {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+        intermediates::EnglishKeyword::When {
+            #[cfg(feature = "spanned_tree")]
+            span: Default::default(),
+        },
+    ]
+    .into_iter()
+    .map(|trigger_keyword| super::ParserRule {
+        expanded: super::RuleLhs::new(&[
+            ParserNode::LexerToken(Token::EnglishKeyword(trigger_keyword)).id(),
+            ParserNode::Event { event: dummy() }.id(),
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::During {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+            ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                #[cfg(feature = "spanned_tree")]
+                span: Default::default(),
+            }))
+            .id(),
+        ]),
+        merged: ParserNode::TriggerCondition { condition: dummy() }.id(),
+        reduction: |nodes: &[ParserNode]| match &nodes {
+            &[
+                ParserNode::LexerToken(Token::EnglishKeyword(
+                    intermediates::EnglishKeyword::Whenever {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    }
+                    | intermediates::EnglishKeyword::When {
+                        #[cfg(feature = "spanned_tree")]
+                            span: start_span,
+                    },
+                )),
+                ParserNode::Event { event },
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::During {
+                    #[cfg(feature = "spanned_tree")]
+                        span: during_span,
+                })),
+                ParserNode::LexerToken(Token::CountSpecifier(intermediates::CountSpecifier::All { .. })),
+                ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::Of { .. })),
+                ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Your { .. })),
+                ParserNode::LexerToken(Token::VhyToSortLater(intermediates::VhyToSortLater::Turn {
+                    #[cfg(feature = "spanned_tree")]
+                        span: turn_span,
+                })),
+            ] => Ok(ParserNode::TriggerCondition {
+                condition: crate::ability_tree::ability::triggered::TriggerCondition {
+                    kind: crate::ability_tree::ability::triggered::TriggerConditionKind::Event(event.clone()),
+                    condition: Some(crate::ability_tree::conditional::Conditional::If(
+                        crate::ability_tree::conditional::ConditionalIf {
+                            condition: crate::ability_tree::conditional::Condition::ThisIsYourTurn(
+                                crate::ability_tree::conditional::ConditionThisIsYourTurn {
+                                    #[cfg(feature = "spanned_tree")]
+                                    span: during_span.merge(turn_span),
+                                },
+                            ),
+                            #[cfg(feature = "spanned_tree")]
+                            span: during_span.merge(turn_span),
+                        },
+                    )),
+                    #[cfg(feature = "spanned_tree")]
+                    span: event.node_span().merge(start_span),
+                },
+            }),
+            _ => Err("Provided tokens do not match rule definition"),
+        },
+        creation_loc: super::ParserRuleDeclarationLocation::here(),
+    });
+
     [
         /* "At <instant>" make trigger conditions */
         super::ParserRule {
@@ -392,4 +483,5 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
         },
     ]
     .into_iter()
+    .chain(during_each_of_your_turns)
 }
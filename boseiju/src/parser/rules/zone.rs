@@ -35,6 +35,58 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
             },
             creation_loc: super::ParserRuleDeclarationLocation::here(),
         },
+        /* "anywhere" is a zone reference, as in "is put into a graveyard from anywhere" */
+        super::ParserRule {
+            expanded: super::RuleLhs::new(
+                &[ParserNode::LexerToken(Token::GlobalZone(intermediates::GlobalZone::Anywhere {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                }))
+                .id()],
+            ),
+            merged: ParserNode::ZoneReference { zone: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::GlobalZone(intermediates::GlobalZone::Anywhere {
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                ] => Ok(ParserNode::ZoneReference {
+                    zone: crate::ability_tree::zone::ZoneReference::Anywhere {
+                        #[cfg(feature = "spanned_tree")]
+                        span: *span,
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: super::ParserRuleDeclarationLocation::here(),
+        },
+        /* "exile" is a zone reference, as in "cast a spell from exile" */
+        super::ParserRule {
+            expanded: super::RuleLhs::new(&[ParserNode::LexerToken(Token::AmbiguousToken(
+                intermediates::AmbiguousToken::Exile {
+                    #[cfg(feature = "spanned_tree")]
+                    span: Default::default(),
+                },
+            ))
+            .id()]),
+            merged: ParserNode::ZoneReference { zone: dummy() }.id(),
+            reduction: |nodes: &[ParserNode]| match &nodes {
+                &[
+                    ParserNode::LexerToken(Token::AmbiguousToken(intermediates::AmbiguousToken::Exile {
+                        #[cfg(feature = "spanned_tree")]
+                        span,
+                    })),
+                ] => Ok(ParserNode::ZoneReference {
+                    zone: crate::ability_tree::zone::ZoneReference::Exile {
+                        #[cfg(feature = "spanned_tree")]
+                        span: *span,
+                    },
+                }),
+                _ => Err("Provided tokens do not match rule definition"),
+            },
+            creation_loc: super::ParserRuleDeclarationLocation::here(),
+        },
         /* The battlefield under someone controls can be seen as a owned zone  */
         super::ParserRule {
             expanded: super::RuleLhs::new(&[
@@ -97,6 +149,39 @@ pub fn rules() -> impl Iterator<Item = crate::parser::rules::ParserRule> {
     .into_iter()
     .map(|zone| {
         [
+            /* "a <zone>" refers to the zone of any player, as in "is put into a graveyard" */
+            super::ParserRule {
+                expanded: super::RuleLhs::new(&[
+                    ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::A {
+                        #[cfg(feature = "spanned_tree")]
+                        span: Default::default(),
+                    }))
+                    .id(),
+                    ParserNode::LexerToken(Token::OwnableZone(zone)).id(),
+                ]),
+                merged: ParserNode::ZoneReference { zone: dummy() }.id(),
+                reduction: |nodes: &[ParserNode]| match &nodes {
+                    &[
+                        ParserNode::LexerToken(Token::EnglishKeyword(intermediates::EnglishKeyword::A {
+                            #[cfg(feature = "spanned_tree")]
+                                span: a_span,
+                        })),
+                        ParserNode::LexerToken(Token::OwnableZone(zone)),
+                    ] => Ok(ParserNode::ZoneReference {
+                        zone: crate::ability_tree::zone::ZoneReference::OwnedZone(crate::ability_tree::zone::OwnedZone {
+                            zone: *zone,
+                            owner: crate::ability_tree::player::PlayerSpecifier::Any {
+                                #[cfg(feature = "spanned_tree")]
+                                span: *a_span,
+                            },
+                            #[cfg(feature = "spanned_tree")]
+                            span: a_span.merge(&zone.node_span()),
+                        }),
+                    }),
+                    _ => Err("Provided tokens do not match rule definition"),
+                },
+                creation_loc: super::ParserRuleDeclarationLocation::here(),
+            },
             /* For zones, ambiguous token "your" is valid */
             super::ParserRule {
                 expanded: super::RuleLhs::new(&[
@@ -0,0 +1,246 @@
+//! Incremental parsing, for editors giving live feedback while an oracle text is typed.
+
+use super::EarleyTable;
+use super::ParserNode;
+use super::PossibleExpectedToken;
+use crate::lexer::LexerError;
+use crate::lexer::tokens::Token;
+use idris::Idris;
+use std::ops::Range;
+
+/// A parsing session over an oracle text that is being edited.
+///
+/// The session keeps the tokens of each line of the preprocessed text and the rows of the Earley table
+/// between edits. When the oracle text changes, only the lines that changed are lexed again, and the
+/// Earley algorithm resumes from the row of the first token that changed.
+///
+/// Positions are byte offsets in the preprocessed oracle text, like the spans of the ability tree.
+pub struct ParserSession {
+    card_name: crate::lexer::CardName,
+    oracle_text: String,
+    /// The preprocessed oracle text, that is actually lexed and parsed.
+    text: String,
+    /// Lines of the preprocessed text, each one with its trailing new line.
+    lines: Vec<LexedLine>,
+    /// Tokens of all the lines, up to the first lexer error.
+    tokens: Vec<Token>,
+    token_ranges: Vec<Range<usize>>,
+    nodes: Vec<ParserNode>,
+    earley_table: EarleyTable<'static>,
+}
+
+/// A line of the preprocessed text, lexed on its own.
+///
+/// Abilities never span several lines, so lines can be lexed independently of each other.
+struct LexedLine {
+    range: Range<usize>,
+    /// The tokens of the line, up to the lexer error if any.
+    tokens: Vec<(Token, Range<usize>)>,
+    error: Option<LexerError>,
+}
+
+impl LexedLine {
+    fn lex(text: &str, range: Range<usize>) -> Self {
+        match crate::lexer::lex_range(text, range.clone()) {
+            Ok(tokens) => Self {
+                range,
+                tokens,
+                error: None,
+            },
+            /* Keep the tokens before the error, so that completions are available while typing a word */
+            Err(error) => {
+                let crate::lexer::LexerError::NoTokenMatch { start, .. } = error;
+                Self {
+                    tokens: crate::lexer::lex_range(text, range.start..start).unwrap_or_default(),
+                    range,
+                    error: Some(error),
+                }
+            }
+        }
+    }
+}
+
+impl ParserSession {
+    /// Start a new session, lexing and running the Earley algorithm on the whole oracle text.
+    ///
+    /// Only the name of the card is known, see [`ParserSession::from_card_name`] to provide all its names.
+    pub fn new(card_name: &str, oracle_text: &str) -> Self {
+        Self::from_card_name(crate::lexer::CardName::new(card_name), oracle_text)
+    }
+
+    /// Start a new session with all the names of the card, such as the ones from [`crate::lexer::CardName::from_raw_card`].
+    pub fn from_card_name(card_name: crate::lexer::CardName, oracle_text: &str) -> Self {
+        let text = crate::lexer::preprocess_card(&card_name, oracle_text).text;
+        let lines = lex_lines(&text, 0..text.len());
+
+        let mut session = Self {
+            card_name,
+            oracle_text: oracle_text.to_string(),
+            text,
+            lines,
+            tokens: Vec::new(),
+            token_ranges: Vec::new(),
+            nodes: Vec::new(),
+            earley_table: super::empty_earley_table(0),
+        };
+        session.update_tokens();
+        super::fill_earley_table(&mut session.earley_table, &session.nodes);
+        session
+    }
+
+    /// The oracle text, as last provided to the session.
+    pub fn oracle_text(&self) -> &str {
+        &self.oracle_text
+    }
+
+    /// The preprocessed oracle text, all positions of the session are in this text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The tokens of the preprocessed text, up to the first lexer error if any.
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    /// Replace the given byte range of the oracle text with the replacement text.
+    pub fn edit(&mut self, range: Range<usize>, replacement: &str) {
+        let mut oracle_text = self.oracle_text.clone();
+        oracle_text.replace_range(range, replacement);
+        self.set_oracle_text(&oracle_text);
+    }
+
+    /// Update the oracle text of the session.
+    ///
+    /// The new text is preprocessed and compared to the previous one, to find the lines that changed.
+    /// Only those lines are lexed again, and the Earley table is kept up to the first token that changed.
+    pub fn set_oracle_text(&mut self, oracle_text: &str) {
+        self.oracle_text = oracle_text.to_string();
+        let new_text = crate::lexer::preprocess_card(&self.card_name, oracle_text).text;
+        if new_text == self.text {
+            return;
+        }
+
+        let (old_len, new_len) = (self.text.len(), new_text.len());
+        let prefix = common_prefix_len(self.text.as_bytes(), new_text.as_bytes());
+        let max_suffix = old_len.min(new_len) - prefix;
+        let suffix = common_suffix_len(self.text.as_bytes(), new_text.as_bytes()).min(max_suffix);
+
+        /* The lines to lex again go from the line of the first change to the line of the last change */
+        let first_line = self
+            .lines
+            .iter()
+            .position(|line| prefix < line.range.end)
+            .unwrap_or(self.lines.len() - 1);
+        let start = self.lines[first_line].range.start;
+        let changed_end = new_len - suffix;
+        let new_end = match new_text[changed_end..].find('\n') {
+            Some(index) => changed_end + index + 1,
+            None => new_len,
+        };
+        /* The text after the changed lines is the same in both texts, so this is a line start in the old text */
+        let old_end = new_end + old_len - new_len;
+        let last_line = self
+            .lines
+            .iter()
+            .position(|line| line.range.start >= old_end)
+            .unwrap_or(self.lines.len());
+
+        let mut following_lines = self.lines.split_off(last_line);
+        self.lines.truncate(first_line);
+        self.lines.extend(lex_lines(&new_text, start..new_end));
+
+        /* Move the lines after the change to their new position */
+        let shift = |range: &Range<usize>| range.start + new_len - old_len..range.end + new_len - old_len;
+        for line in following_lines.iter_mut().filter(|_| new_len != old_len) {
+            /* Token spans and lexer errors hold positions in the text, so the line is lexed again */
+            match cfg!(feature = "spanned_tree") || line.error.is_some() {
+                true => *line = LexedLine::lex(&new_text, shift(&line.range)),
+                false => {
+                    line.range = shift(&line.range);
+                    line.tokens.iter_mut().for_each(|(_, range)| *range = shift(range));
+                }
+            }
+        }
+        self.lines.extend(following_lines);
+        self.text = new_text;
+
+        /* Resume the Earley algorithm from the first token that changed */
+        let old_ids: Vec<usize> = self.nodes.iter().map(|node| node.id()).collect();
+        self.update_tokens();
+        let first_changed_token = old_ids
+            .iter()
+            .zip(self.nodes.iter())
+            .take_while(|(old_id, node)| **old_id == node.id())
+            .count();
+        /* The row right before the first changed token used it as lookahead, but the start row uses no lookahead */
+        self.earley_table.truncate(first_changed_token.max(1));
+        super::fill_earley_table(&mut self.earley_table, &self.nodes);
+    }
+
+    /// Build the ability tree of the current oracle text.
+    pub fn parse(&self) -> Result<crate::AbilityTree, crate::error::BoseijuError> {
+        if let Some(error) = self.lines.iter().find_map(|line| line.error.as_ref()) {
+            return Err(error.clone().into());
+        }
+        if self.tokens.is_empty() {
+            return Ok(crate::AbilityTree::empty());
+        }
+        Ok(super::reduce_earley_table(&self.earley_table, &self.nodes, &self.tokens)?)
+    }
+
+    /// The tokens that could come next at the given position of the preprocessed text.
+    ///
+    /// These are the tokens awaited by the Earley items after all the tokens ending before the position,
+    /// along with the nodes they would help to create. They are sorted by token.
+    pub fn completions(&self, position: usize) -> Vec<PossibleExpectedToken> {
+        let token_index = self.token_ranges.iter().take_while(|range| range.end <= position).count();
+        let mut completions = super::error::expected_tokens(&self.earley_table, token_index);
+        completions.sort_by_key(|completion| completion.expected);
+        completions
+    }
+
+    /// Rebuild the tokens and nodes from the lines, up to the first lexer error.
+    fn update_tokens(&mut self) {
+        self.tokens.clear();
+        self.token_ranges.clear();
+        for line in self.lines.iter() {
+            for (token, range) in line.tokens.iter() {
+                self.tokens.push(token.clone());
+                self.token_ranges.push(range.clone());
+            }
+            if line.error.is_some() {
+                break;
+            }
+        }
+        self.nodes = self.tokens.iter().cloned().map(ParserNode::from).collect();
+    }
+}
+
+/// Split the given range of the text in lines, and lex each of them.
+///
+/// The range shall start at a line start, and end after a new line or at the end of the text.
+fn lex_lines(text: &str, range: Range<usize>) -> Vec<LexedLine> {
+    let mut lines = Vec::new();
+    let mut start = range.start;
+    loop {
+        let end = match text[start..range.end].find('\n') {
+            Some(index) => start + index + 1,
+            None => range.end,
+        };
+        lines.push(LexedLine::lex(text, start..end));
+        start = end;
+        if start >= range.end {
+            break;
+        }
+    }
+    lines
+}
+
+fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b.iter()).take_while(|(a, b)| a == b).count()
+}
+
+fn common_suffix_len(a: &[u8], b: &[u8]) -> usize {
+    a.iter().rev().zip(b.iter().rev()).take_while(|(a, b)| a == b).count()
+}
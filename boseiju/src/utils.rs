@@ -1,7 +1,9 @@
 mod containers;
+mod graph_export;
 mod tree_formatter;
 
 pub use containers::*;
+pub use graph_export::*;
 pub use tree_formatter::*;
 
 #[cfg(feature = "parser")]
@@ -0,0 +1,203 @@
+//! Export of ability trees and other graphs, for visualisation.
+//!
+//! Graphs are first built as a [`Graph`], a plain list of nodes, edges and clusters,
+//! and then written in one of the [`GraphFormat`]s: Graphviz DOT or Mermaid flowcharts.
+
+use crate::ability_tree::AbTreeNodeData;
+use crate::ability_tree::AbilityTreeNode;
+
+/// Text formats graphs can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT digraph, see <https://graphviz.org/doc/info/lang.html>.
+    Dot,
+    /// Mermaid flowchart, see <https://mermaid.js.org/syntax/flowchart.html>.
+    Mermaid,
+}
+
+/// A directed graph, with labeled nodes and edges.
+///
+/// Nodes can be grouped in clusters, that are drawn as boxes around them.
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+    clusters: Vec<GraphCluster>,
+}
+
+#[derive(Debug, Clone)]
+struct GraphNode {
+    label: String,
+    tooltip: Option<String>,
+    cluster: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct GraphEdge {
+    from: usize,
+    to: usize,
+    label: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct GraphCluster {
+    label: String,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a node to the graph, and return its index.
+    pub fn add_node(&mut self, label: String, tooltip: Option<String>) -> usize {
+        self.add_node_in(label, tooltip, None)
+    }
+
+    /// Add a node to the graph inside the given cluster, and return its index.
+    pub fn add_node_in(&mut self, label: String, tooltip: Option<String>, cluster: Option<usize>) -> usize {
+        self.nodes.push(GraphNode { label, tooltip, cluster });
+        self.nodes.len() - 1
+    }
+
+    /// Add an edge between two nodes, from their indices.
+    pub fn add_edge(&mut self, from: usize, to: usize, label: Option<String>) {
+        self.edges.push(GraphEdge { from, to, label });
+    }
+
+    /// Add a cluster to the graph, and return its index.
+    pub fn add_cluster(&mut self, label: String) -> usize {
+        self.clusters.push(GraphCluster { label });
+        self.clusters.len() - 1
+    }
+
+    /// Build the graph of the tree starting at the given root.
+    ///
+    /// Each node is labeled with its tag and its data if it holds any, and its description is used as a tooltip.
+    pub fn from_tree(root: &dyn AbilityTreeNode) -> Self {
+        let mut graph = Self::new();
+        graph.add_tree_node(root);
+        graph
+    }
+
+    fn add_tree_node(&mut self, node: &dyn AbilityTreeNode) -> usize {
+        let label = match node.data() {
+            Some(data) => format!("{}\n{}", node.node_tag(), data_label(&data)),
+            None => node.node_tag().to_string(),
+        };
+        let id = self.add_node(label, Some(node.node_description()));
+        for child in node.children().into_iter() {
+            let child_id = self.add_tree_node(child);
+            self.add_edge(id, child_id, None);
+        }
+        id
+    }
+
+    /// Write the graph in the given format.
+    pub fn write<W: std::io::Write>(&self, format: GraphFormat, out: &mut W) -> std::io::Result<()> {
+        match format {
+            GraphFormat::Dot => self.write_dot(out),
+            GraphFormat::Mermaid => self.write_mermaid(out),
+        }
+    }
+
+    fn write_dot<W: std::io::Write>(&self, out: &mut W) -> std::io::Result<()> {
+        writeln!(out, "digraph {{")?;
+        writeln!(out, "    node [shape=box];")?;
+        for (cluster_id, cluster) in self.clusters.iter().enumerate() {
+            writeln!(out, "    subgraph cluster_{cluster_id} {{")?;
+            writeln!(out, "        label=\"{}\";", escape_dot(&cluster.label))?;
+            for (id, node) in self.nodes_in(Some(cluster_id)) {
+                write!(out, "    ")?;
+                write_dot_node(id, node, out)?;
+            }
+            writeln!(out, "    }}")?;
+        }
+        for (id, node) in self.nodes_in(None) {
+            write_dot_node(id, node, out)?;
+        }
+        for edge in self.edges.iter() {
+            match &edge.label {
+                Some(label) => writeln!(out, "    n{} -> n{} [label=\"{}\"];", edge.from, edge.to, escape_dot(label))?,
+                None => writeln!(out, "    n{} -> n{};", edge.from, edge.to)?,
+            }
+        }
+        writeln!(out, "}}")?;
+        Ok(())
+    }
+
+    fn write_mermaid<W: std::io::Write>(&self, out: &mut W) -> std::io::Result<()> {
+        writeln!(out, "flowchart TD")?;
+        for (cluster_id, cluster) in self.clusters.iter().enumerate() {
+            writeln!(out, "    subgraph c{cluster_id} [\"{}\"]", escape_mermaid(&cluster.label))?;
+            for (id, node) in self.nodes_in(Some(cluster_id)) {
+                write!(out, "    ")?;
+                writeln!(out, "    n{id}[\"{}\"]", escape_mermaid(&node.label))?;
+            }
+            writeln!(out, "    end")?;
+        }
+        for (id, node) in self.nodes_in(None) {
+            writeln!(out, "    n{id}[\"{}\"]", escape_mermaid(&node.label))?;
+        }
+        for edge in self.edges.iter() {
+            match &edge.label {
+                Some(label) => writeln!(out, "    n{} -->|\"{}\"| n{}", edge.from, escape_mermaid(label), edge.to)?,
+                None => writeln!(out, "    n{} --> n{}", edge.from, edge.to)?,
+            }
+        }
+        /* Mermaid only supports tooltips on clickable nodes, the callback can be left undefined */
+        for (id, node) in self.nodes.iter().enumerate() {
+            if let Some(tooltip) = &node.tooltip {
+                writeln!(out, "    click n{id} callback \"{}\"", escape_mermaid(tooltip))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn nodes_in(&self, cluster: Option<usize>) -> impl Iterator<Item = (usize, &GraphNode)> {
+        self.nodes.iter().enumerate().filter(move |(_, node)| node.cluster == cluster)
+    }
+}
+
+fn write_dot_node<W: std::io::Write>(id: usize, node: &GraphNode, out: &mut W) -> std::io::Result<()> {
+    match &node.tooltip {
+        Some(tooltip) => writeln!(
+            out,
+            "    n{id} [label=\"{}\", tooltip=\"{}\"];",
+            escape_dot(&node.label),
+            escape_dot(tooltip)
+        ),
+        None => writeln!(out, "    n{id} [label=\"{}\"];", escape_dot(&node.label)),
+    }
+}
+
+/// Write the tree starting at the given root as a Graphviz DOT digraph.
+///
+/// Each node of the tree is a box labeled with its tag, linked to its children.
+pub fn write_dot<W: std::io::Write>(root: &dyn AbilityTreeNode, out: &mut W) -> std::io::Result<()> {
+    Graph::from_tree(root).write(GraphFormat::Dot, out)
+}
+
+/// Write the tree starting at the given root as a Mermaid flowchart.
+pub fn write_mermaid<W: std::io::Write>(root: &dyn AbilityTreeNode, out: &mut W) -> std::io::Result<()> {
+    Graph::from_tree(root).write(GraphFormat::Mermaid, out)
+}
+
+fn data_label(data: &AbTreeNodeData) -> String {
+    match data {
+        AbTreeNodeData::Boolean { value } => value.to_string(),
+        AbTreeNodeData::Color { value } => value.to_string(),
+        AbTreeNodeData::ColorAndNumeric { color, numeric } => format!("{numeric} {color}"),
+        AbTreeNodeData::Numeric { value } => value.to_string(),
+        AbTreeNodeData::TypeLine { value } => value.to_string(),
+    }
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Mermaid labels are quoted strings, where quotes are written as entity codes and new lines as html breaks.
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;").replace('\n', "<br/>")
+}
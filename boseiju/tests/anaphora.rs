@@ -0,0 +1,49 @@
+use boseiju::ability_tree::NodeKind;
+use boseiju::ability_tree::NodePath;
+use boseiju::ability_tree::anaphora::AnaphoraResolution;
+use boseiju::ability_tree::anaphora::AntecedentKind;
+use boseiju::*;
+use idris::Idris;
+
+fn main() -> Result<(), String> {
+    let oracle_text = lexer::preprocess("Test Card", "Target player draws a card. That player loses 1 life.");
+    let tokens = lexer::lex(&oracle_text).map_err(|e| e.to_string())?;
+    let tree = parser::parse(&tokens).map_err(|e| e.to_string())?;
+
+    let resolution = AnaphoraResolution::resolve(&tree);
+    if !resolution.is_complete() {
+        return Err(format!("All references shall be resolved: {:?}", resolution.warnings));
+    }
+    if resolution.links.len() != 1 {
+        return Err(format!("Expected a single link, got {:?}", resolution.links));
+    }
+
+    /* "That player" in the second imperative refers to "target player" in the first one */
+    let link = &resolution.links[0];
+    let expected_reference = NodePath {
+        indices: vec![0, 0, 0, 0, 0, 1, 1],
+    };
+    let expected_antecedent = NodePath {
+        indices: vec![0, 0, 0, 0, 0, 0, 1],
+    };
+    if link.reference != expected_reference || link.antecedent != expected_antecedent {
+        return Err(format!(
+            "Expected link from {expected_reference:?} to {expected_antecedent:?}, got {link:?}"
+        ));
+    }
+    if link.kind != AntecedentKind::Player {
+        return Err(format!("Expected a player antecedent, got {:?}", link.kind));
+    }
+
+    /* Both paths shall point to player specifiers in the tree */
+    for path in [&link.reference, &link.antecedent] {
+        let node = path
+            .resolve(&tree)
+            .ok_or(format!("Path {path:?} does not exist in the tree"))?;
+        if node.node_id() != NodeKind::PlayerSpecifierIdMarker.id() {
+            return Err(format!("Expected a player specifier at {path:?}"));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use boseiju::lexer::CardName;
+use boseiju::lexer::NameForm;
+
+/// Replace the card name in the oracle text, and check the result and the forms that were substituted.
+fn check(card_name: &CardName, oracle_text: &str, expected: &str, expected_forms: &[NameForm]) -> Result<(), String> {
+    let (replaced, substitutions) = card_name.replace_in(oracle_text);
+    if replaced != expected {
+        return Err(format!("For {:?}, expected {expected:?}, got {replaced:?}", card_name.name));
+    }
+    let forms: Vec<NameForm> = substitutions.iter().map(|substitution| substitution.form).collect();
+    if forms != expected_forms {
+        return Err(format!(
+            "For {:?}, expected substitutions {expected_forms:?}, got {forms:?}",
+            card_name.name
+        ));
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), String> {
+    /* Names are only replaced on whole words */
+    let ash = CardName::new("Ash");
+    check(
+        &ash,
+        "flash. ash deals 1 damage to any target.",
+        "flash. ~ deals 1 damage to any target.",
+        &[NameForm::FullName],
+    )?;
+    check(
+        &ash,
+        "exile target card from a graveyard. ashes to ashes.",
+        "exile target card from a graveyard. ashes to ashes.",
+        &[],
+    )?;
+
+    /* Legendary cards can be referred to by the part of their name before the comma */
+    let gimli = CardName {
+        legendary: true,
+        ..CardName::new("Gimli, Counter of Kills")
+    };
+    check(
+        &gimli,
+        "whenever a creature an opponent controls dies, put a +1/+1 counter on gimli, counter of kills. gimli gets +1/+0.",
+        "whenever a creature an opponent controls dies, put a +1/+1 counter on ~. ~ gets +1/+0.",
+        &[NameForm::FullName, NameForm::ShortName],
+    )?;
+
+    /* Legendary cards with "the" in their name can be referred to by the part before it */
+    let borborygmos = CardName {
+        legendary: true,
+        ..CardName::new("Borborygmos the Great")
+    };
+    check(
+        &borborygmos,
+        "whenever borborygmos deals combat damage to a player, draw a card.",
+        "whenever ~ deals combat damage to a player, draw a card.",
+        &[NameForm::ShortNameBeforeThe],
+    )?;
+    /* But not non legendary cards */
+    let mage = CardName::new("Mage the Wise");
+    check(&mage, "mage draws a card.", "mage draws a card.", &[])?;
+
+    /* Split cards refer to each face by its own name */
+    let fire_ice = CardName::new("Fire // Ice");
+    check(
+        &fire_ice,
+        "fire deals 2 damage divided as you choose.",
+        "~ deals 2 damage divided as you choose.",
+        &[NameForm::FaceName],
+    )?;
+
+    /* Cards printed under an other name use it in their texts */
+    let flavored = CardName {
+        flavor_name: Some("Godzilla, King of the Monsters".to_string()),
+        ..CardName::new("Zilortha, Strength Incarnate")
+    };
+    check(
+        &flavored,
+        "godzilla, king of the monsters has trample.",
+        "~ has trample.",
+        &[NameForm::FlavorName],
+    )?;
+
+    /* Tokens and emblems mentioning the card creating them keep its name, it is not a self reference */
+    let token = CardName::new("Spirit");
+    check(
+        &token,
+        "flying. when kaya, geist hunter leaves the battlefield, sacrifice this token.",
+        "flying. when kaya, geist hunter leaves the battlefield, sacrifice this token.",
+        &[],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use boseiju::ability_tree::zone::OwnableZone;
+use boseiju::ability_tree::zone::ZoneReference;
+use boseiju::card::casting_cost::AlternativeCostKind;
+use boseiju::card::casting_cost::CastingCost;
+use boseiju::card::casting_cost::CastingMethod;
+
+/// Parse the oracle text, and list the ways to cast a card without mana cost that has these abilities.
+fn casting_costs(oracle_text: &str) -> Result<Vec<CastingCost>, String> {
+    let tree = common::parse(oracle_text)?;
+    Ok(boseiju::card::casting_cost::casting_costs(None, Some(&tree)))
+}
+
+fn main() -> Result<(), String> {
+    /* Flashback casts the card from the graveyard, paying the flashback mana cost */
+    let costs = casting_costs("Flashback {2}{R}")?;
+    let [normal, flashback] = costs.as_slice() else {
+        return Err(format!("Expected a normal and a flashback casting cost, got {costs:?}"));
+    };
+    if normal.method != CastingMethod::Normal || normal.mana_cost.is_some() {
+        return Err(format!(
+            "Expected the normal casting cost first, without mana cost, got {normal:?}"
+        ));
+    }
+    let CastingMethod::Alternative(alternative) = &flashback.method else {
+        return Err(format!("Expected an alternative casting cost, got {flashback:?}"));
+    };
+    if alternative.kind != AlternativeCostKind::Flashback {
+        return Err(format!("Expected a flashback cost, got {:?}", alternative.kind));
+    }
+    if !matches!(
+        &alternative.from_zone,
+        Some(ZoneReference::OwnedZone(owned)) if matches!(owned.zone, OwnableZone::Graveyard { .. })
+    ) {
+        return Err(format!(
+            "Expected flashback to cast from the graveyard, got {:?}",
+            alternative.from_zone
+        ));
+    }
+    match flashback.mana_cost.as_ref() {
+        Some(mana_cost) if mana_cost.mana_value() == 3 => {}
+        other => return Err(format!("Expected a flashback mana cost of mana value 3, got {other:?}")),
+    }
+
+    /* Other alternative costs are paid when casting from the hand */
+    let costs = casting_costs("Dash {1}{R}")?;
+    match costs.get(1).map(|cost| &cost.method) {
+        Some(CastingMethod::Alternative(alternative)) if alternative.from_zone.is_none() => {}
+        other => return Err(format!("Expected a dash cost cast from the hand, got {other:?}")),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+//! Helpers shared by the tests parsing oracle texts.
+//!
+//! Each test only uses some of them, hence the allowed dead code.
+#![allow(dead_code)]
+
+use boseiju::ability_tree::AbilityTreeNode;
+use boseiju::ability_tree::NodeKind;
+use boseiju::*;
+use idris::Idris;
+
+/// Name of the card of the tested oracle texts, replaced by "~" when preprocessing.
+pub const CARD_NAME: &str = "Test Card";
+
+/// Preprocess, lex and parse the oracle text.
+pub fn parse(oracle_text: &str) -> Result<AbilityTree, String> {
+    let preprocessed = lexer::preprocess(CARD_NAME, oracle_text);
+    let tokens = lexer::lex(&preprocessed).map_err(|e| format!("Failed to lex {oracle_text:?}: {e}"))?;
+    parser::parse(&tokens).map_err(|e| format!("Failed to parse {oracle_text:?}: {e}"))
+}
+
+/// Look for a node with the given id anywhere in the tree.
+pub fn contains_node(node: &dyn AbilityTreeNode, node_id: usize) -> bool {
+    node.node_id() == node_id || node.children().iter().any(|child| contains_node(*child, node_id))
+}
+
+/// Find the first node with the given id in the tree, depth first.
+pub fn find_node(node: &dyn AbilityTreeNode, node_id: usize) -> Option<&dyn AbilityTreeNode> {
+    if node.node_id() == node_id {
+        return Some(node);
+    }
+    node.children().into_iter().find_map(|child| find_node(child, node_id))
+}
+
+/// Parse the oracle text, and check the tree contains all the expected nodes.
+pub fn check_nodes(oracle_text: &str, expected: &[NodeKind]) -> Result<AbilityTree, String> {
+    let tree = parse(oracle_text)?;
+    for node_kind in expected.iter() {
+        if !contains_node(&tree, node_kind.id()) {
+            return Err(format!("Expected a {node_kind:?} node in the tree of {oracle_text:?}"));
+        }
+    }
+    Ok(tree)
+}
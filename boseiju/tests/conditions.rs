@@ -0,0 +1,73 @@
+mod common;
+
+use boseiju::ability_tree::AbilityTreeNode;
+use boseiju::ability_tree::NodeKind;
+use common::check_nodes;
+use idris::Idris;
+
+/// Look for a node with the given tag below the first node of the given kind.
+fn check_tag_below(oracle_text: &str, node_kind: NodeKind, tag: &str) -> Result<(), String> {
+    fn contains_tag(node: &dyn AbilityTreeNode, tag: &str) -> bool {
+        node.node_tag() == tag || node.children().iter().any(|child| contains_tag(*child, tag))
+    }
+    let tree = common::parse(oracle_text)?;
+    match common::find_node(&tree, node_kind.id()) {
+        Some(node) if contains_tag(node, tag) => Ok(()),
+        Some(_) => Err(format!("Expected a {tag:?} node below the {node_kind:?} of {oracle_text:?}")),
+        None => Err(format!("Expected a {node_kind:?} node in the tree of {oracle_text:?}")),
+    }
+}
+
+fn main() -> Result<(), String> {
+    /* Life totals, with "or more" and "or less" comparisons */
+    check_tag_below(
+        "As long as you have 30 or more life, Test Card gets +2/+2.",
+        NodeKind::ConditionPlayerLifeTotal,
+        "or more number",
+    )?;
+    check_tag_below(
+        "As long as you have 10 or less life, Test Card gets +2/+2.",
+        NodeKind::ConditionPlayerLifeTotal,
+        "up to number",
+    )?;
+
+    /* Comparisons between life totals */
+    check_nodes(
+        "As long as you have more life than an opponent, Test Card gets +2/+2.",
+        &[NodeKind::StaticAbility, NodeKind::ConditionNumberComparison],
+    )?;
+
+    /* Permanents controlled and cards in zones */
+    check_nodes(
+        "As long as you control seven or more lands, Test Card gets +2/+2.",
+        &[NodeKind::StaticAbility, NodeKind::PlayerControlsPermanent],
+    )?;
+    check_nodes(
+        "As long as seven or more cards are in your graveyard, Test Card gets +2/+2.",
+        &[NodeKind::StaticAbility, NodeKind::ConditionCardsInZone],
+    )?;
+
+    /* Spells cast this turn */
+    check_nodes(
+        "As long as you've cast two or more spells this turn, Test Card gets +2/+2.",
+        &[NodeKind::StaticAbility, NodeKind::ConditionSpellsCastThisTurn],
+    )?;
+
+    /* Permanent states */
+    check_nodes(
+        "As long as Test Card is enchanted, it gets +2/+2.",
+        &[NodeKind::StaticAbility, NodeKind::ConditionPermanentHasState],
+    )?;
+
+    /* Turns */
+    check_nodes(
+        "During your turn, Test Card gets +2/+2.",
+        &[NodeKind::StaticAbility, NodeKind::ThisIsYourTurn],
+    )?;
+    check_nodes(
+        "As long as it's your turn, Test Card gets +2/+2.",
+        &[NodeKind::StaticAbility, NodeKind::ThisIsYourTurn],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use boseiju::ability_tree::NodeKind;
+use common::check_nodes;
+use idris::Idris;
+
+/// Check the counters put on a permanent in the oracle text are of any kind, or of a given kind.
+fn check_counter_kind(oracle_text: &str, any_kind: bool) -> Result<(), String> {
+    let tree = check_nodes(oracle_text, &[NodeKind::CountersPutOnPermanentAction])?;
+    let action = common::find_node(&tree, NodeKind::CountersPutOnPermanentAction.id())
+        .ok_or(format!("No counters put on action in {oracle_text:?}"))?;
+    let counter = action.children().last().map(|child| child.node_id());
+    let is_any_kind = counter == Some(NodeKind::_NoneNode.id());
+    if is_any_kind != any_kind {
+        return Err(format!("For {oracle_text:?}, expected any kind of counter: {any_kind}"));
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), String> {
+    /* Zone changes */
+    check_nodes(
+        "Whenever Test Card leaves the battlefield, draw a card.",
+        &[NodeKind::ZoneChangeEvent],
+    )?;
+    check_nodes(
+        "Whenever a creature you control is put into a graveyard from anywhere, draw a card.",
+        &[NodeKind::ZoneChangeEvent],
+    )?;
+
+    /* Player actions */
+    check_nodes(
+        "Whenever you draw your second card each turn, you gain 1 life.",
+        &[NodeKind::PlayerDrawsCardAction],
+    )?;
+    check_nodes("Whenever you gain life, draw a card.", &[NodeKind::PlayerGainsLifeAction])?;
+    check_nodes(
+        "Whenever you cast a spell from exile, draw a card.",
+        &[NodeKind::PlayerCastsSpellAction],
+    )?;
+    check_nodes(
+        "Whenever you sacrifice a creature, draw a card.",
+        &[NodeKind::PlayerSacrificesAction],
+    )?;
+
+    /* Permanent and creature actions */
+    check_nodes(
+        "Whenever Test Card becomes the target of a spell, draw a card.",
+        &[NodeKind::PermanentBecomesTargetAction],
+    )?;
+    check_nodes(
+        "Whenever Test Card is dealt damage, draw a card.",
+        &[NodeKind::CreatureIsDealtDamageAction],
+    )?;
+
+    /* Counters, of a given kind or of any kind */
+    check_counter_kind(
+        "Whenever a +1/+1 counter is put on a creature you control, draw a card.",
+        false,
+    )?;
+    check_counter_kind(
+        "Whenever one or more +1/+1 counters are put on Test Card, draw a card.",
+        false,
+    )?;
+    check_counter_kind("Whenever a counter is put on a creature you control, draw a card.", true)?;
+    check_counter_kind("Whenever one or more counters are put on Test Card, draw a card.", true)?;
+
+    Ok(())
+}
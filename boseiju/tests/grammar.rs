@@ -0,0 +1,74 @@
+use boseiju::*;
+
+/// Number of lints of each kind accepted, shared with the `grammar_lints` binary.
+const BASELINE: &str = include_str!("../grammar_lints.baseline");
+
+/// Lints that mean some rules can never complete, and that fail the test.
+///
+/// Other lints are reported, and fail the test when there are more of them than in the baseline.
+fn is_breaking(lint: &parser::GrammarLint) -> bool {
+    match lint {
+        parser::GrammarLint::UnproducedNode { awaited_by, .. } => !awaited_by.is_empty(),
+        parser::GrammarLint::UnproducibleNode { .. } => true,
+        _ => false,
+    }
+}
+
+/// Read the number of lints accepted for each kind, from "<KIND>: <COUNT>" lines.
+fn baseline() -> Result<Vec<(String, usize)>, String> {
+    BASELINE
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let invalid = || format!("Invalid baseline line \"{line}\"");
+            let (kind, count) = line.split_once(':').ok_or_else(invalid)?;
+            let count = count.trim().parse().map_err(|_| invalid())?;
+            Ok((kind.trim().replace('-', " "), count))
+        })
+        .collect()
+}
+
+fn main() -> Result<(), String> {
+    let lints = parser::grammar_lints();
+
+    let mut kinds: Vec<&str> = lints.iter().map(|lint| lint.kind()).collect();
+    kinds.sort();
+    kinds.dedup();
+    println!("Grammar lints:");
+    for kind in kinds.iter() {
+        let count = lints.iter().filter(|lint| lint.kind() == *kind).count();
+        println!(" - {kind}: {count}");
+    }
+
+    let breaking: Vec<_> = lints.iter().filter(|lint| is_breaking(lint)).collect();
+    if !breaking.is_empty() {
+        for lint in breaking.iter() {
+            println!("{lint}");
+        }
+        return Err(format!(
+            "Found {} grammar lints where rules can never complete",
+            breaking.len()
+        ));
+    }
+
+    /* Kinds without baseline accept no lint */
+    let baseline = baseline()?;
+    for kind in parser::GrammarLint::KINDS.iter() {
+        let count = lints.iter().filter(|lint| lint.kind() == *kind).count();
+        let accepted = baseline
+            .iter()
+            .find(|(baseline_kind, _)| baseline_kind == kind)
+            .map_or(0, |(_, count)| *count);
+        if count > accepted {
+            for lint in lints.iter().filter(|lint| lint.kind() == *kind) {
+                println!("{lint}");
+            }
+            return Err(format!(
+                "Found {count} grammar lints of kind \"{kind}\", only {accepted} accepted by the baseline"
+            ));
+        }
+    }
+
+    Ok(())
+}
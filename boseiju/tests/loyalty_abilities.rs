@@ -0,0 +1,30 @@
+mod common;
+
+use boseiju::ability_tree::NodeKind;
+use idris::Idris;
+
+/// Parse the loyalty ability, and check its cost comes first with the expected nodes.
+fn check_cost(oracle_text: &str, expected: &[NodeKind]) -> Result<(), String> {
+    let tree = common::check_nodes(oracle_text, expected)?;
+    let ability = common::find_node(&tree, NodeKind::LoyaltyAbility.id())
+        .ok_or_else(|| format!("Expected a loyalty ability in the tree of {oracle_text:?}"))?;
+    match ability.children().first() {
+        Some(cost) if cost.node_id() == NodeKind::LoyaltyCost.id() => Ok(()),
+        _ => Err(format!("Expected the loyalty cost first in the tree of {oracle_text:?}")),
+    }
+}
+
+fn main() -> Result<(), String> {
+    /* Signed loyalty costs, with the unicode minus sign of the oracle texts */
+    check_cost("+1: Draw a card.", &[NodeKind::LoyaltyCostPlus])?;
+    check_cost("−2: Destroy target creature.", &[NodeKind::LoyaltyCostMinus])?;
+    check_cost("0: Draw a card.", &[NodeKind::LoyaltyCostZero])?;
+
+    /* X loyalty costs are chosen when activating the ability */
+    check_cost(
+        "−X: Destroy target creature.",
+        &[NodeKind::LoyaltyCostMinus, NodeKind::XFromCost],
+    )?;
+
+    Ok(())
+}
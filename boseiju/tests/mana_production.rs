@@ -0,0 +1,31 @@
+use boseiju::*;
+use krark::*;
+
+fn main() -> Result<(), String> {
+    let mut krark_harness = KrarkHarness::new("Mana production test: FDN cards".to_string());
+
+    krark_harness.run_filter(
+        |card| card.set == "fdn",
+        |raw_card, mut results| {
+            match Card::try_from(raw_card) {
+                /* Don't take into account cards we couldn't parse */
+                Err(_) => results.skip(),
+                Ok(card) => {
+                    let mut expected: Vec<mtg_data::Color> = raw_card
+                        .produced_mana
+                        .iter()
+                        .flatten()
+                        .filter_map(|mana| mana.parse().ok())
+                        .collect();
+                    expected.sort();
+                    expected.dedup();
+                    let obtained = card.mana_production().produced_colors();
+                    results.assert_eq(expected, obtained, "Check the produced mana matches scryfall".to_string());
+                }
+            }
+            results
+        },
+    );
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use boseiju::ability_tree::NodeKind;
+use boseiju::card::casting_cost::OptionalCostKind;
+use common::check_nodes;
+
+/// Parse the modal spell, and check its modes and the optional costs it can be cast with.
+fn check_modal(oracle_text: &str, expected_costs: &[OptionalCostKind]) -> Result<(), String> {
+    let tree = check_nodes(oracle_text, &[NodeKind::ModalMode])?;
+    let casting_costs = boseiju::card::casting_cost::casting_costs(None, Some(&tree));
+    let normal = casting_costs
+        .first()
+        .ok_or_else(|| format!("Expected a normal casting cost for {oracle_text:?}"))?;
+    let found: Vec<_> = normal.optional_costs.iter().map(|cost| cost.kind).collect();
+    if found != expected_costs {
+        return Err(format!(
+            "For {oracle_text:?}, expected the optional costs {expected_costs:?}, got {found:?}"
+        ));
+    }
+    if normal.optional_costs.iter().any(|cost| cost.cost.is_none()) {
+        return Err(format!(
+            "For {oracle_text:?}, expected all the optional costs to be paid with something"
+        ));
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), String> {
+    /* Spree, with a cost for each mode */
+    check_modal(
+        "Spree\n+ {1} — Draw a card.\n+ {2} — Destroy target creature.",
+        &[OptionalCostKind::SpreeMode, OptionalCostKind::SpreeMode],
+    )?;
+
+    /* Escalate, paid for each mode beyond the first */
+    check_modal(
+        "Choose one or more —\n• Draw a card.\n• Destroy target creature.\nEscalate {1}",
+        &[OptionalCostKind::Escalate],
+    )?;
+
+    /* Entwine, paid to choose all the modes */
+    check_modal(
+        "Choose one —\n• Draw a card.\n• Destroy target creature.\nEntwine {2}",
+        &[OptionalCostKind::Entwine],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use boseiju::ability_tree::NodeKind;
+use common::check_nodes;
+
+fn main() -> Result<(), String> {
+    /* Planeswalkers, on their own or among other permanent kinds */
+    check_nodes("Destroy target planeswalker.", &[NodeKind::PlaneswalkerKind])?;
+    check_nodes("Destroy target creature or planeswalker.", &[NodeKind::PlaneswalkerKind])?;
+    check_nodes(
+        "Destroy target planeswalker an opponent controls.",
+        &[NodeKind::SpecifiedPlaneswalker, NodeKind::PlaneswalkerSpecifier],
+    )?;
+    check_nodes(
+        "Destroy target planeswalker with three or more loyalty counters on it.",
+        &[NodeKind::SpecifiedPlaneswalker, NodeKind::CountersOnObjectSpecifier],
+    )?;
+
+    /* Battles, and the players protecting them */
+    check_nodes("Destroy target battle.", &[NodeKind::SpecifiedBattle, NodeKind::BattleKind])?;
+    check_nodes(
+        "Destroy each battle you protect.",
+        &[NodeKind::SpecifiedBattle, NodeKind::ProtectorSpecifier],
+    )?;
+    check_nodes(
+        "Destroy target battle you don't protect.",
+        &[NodeKind::SpecifiedBattle, NodeKind::ProtectorSpecifier],
+    )?;
+    check_nodes(
+        "Destroy target battle with three or more defense counters on it.",
+        &[NodeKind::SpecifiedBattle, NodeKind::CountersOnObjectSpecifier],
+    )?;
+
+    /* Players or permanents */
+    check_nodes(
+        "Attach Test Card to target player or planeswalker.",
+        &[NodeKind::PlayerOrPermanent, NodeKind::PlayerOrPermanentKind],
+    )?;
+
+    Ok(())
+}
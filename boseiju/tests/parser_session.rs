@@ -0,0 +1,79 @@
+use boseiju::*;
+
+const CARD_NAME: &str = "Test Card";
+
+/// Parse the oracle text from scratch, without a session.
+fn fresh_parse(oracle_text: &str) -> Result<AbilityTree, String> {
+    let preprocessed = lexer::preprocess(CARD_NAME, oracle_text);
+    let tokens = lexer::lex(&preprocessed).map_err(|e| e.to_string())?;
+    parser::parse(&tokens).map_err(|e| e.to_string())
+}
+
+/// Check the session gives the same result as a fresh parse of its oracle text, and whether the text is valid.
+///
+/// Errors can come from the lexer or the parser, so only their presence is compared.
+fn check(session: &parser::ParserSession, step: &str, valid: bool) -> Result<(), String> {
+    let oracle_text = session.oracle_text();
+    if session.text() != lexer::preprocess(CARD_NAME, oracle_text) {
+        return Err(format!("After {step}, the session text differs from the preprocessed text"));
+    }
+    match (session.parse(), fresh_parse(oracle_text)) {
+        (Ok(session_tree), Ok(fresh_tree)) if session_tree == fresh_tree && valid => Ok(()),
+        (Err(_), Err(_)) if !valid => Ok(()),
+        (session_result, fresh_result) => Err(format!(
+            "After {step}, the session and a fresh parse of {oracle_text:?} differ:\n{session_result:?}\n{fresh_result:?}"
+        )),
+    }
+}
+
+/// Apply the edit to the session, and check the result is the one of a fresh parse.
+fn edit(session: &mut parser::ParserSession, step: &str, from: &str, to: &str, valid: bool) -> Result<(), String> {
+    let start = session
+        .oracle_text()
+        .find(from)
+        .ok_or(format!("Can't find {from:?} for {step}"))?;
+    session.edit(start..start + from.len(), to);
+    check(session, step, valid)
+}
+
+fn main() -> Result<(), String> {
+    let mut session = parser::ParserSession::new(
+        CARD_NAME,
+        "Flying\nWhen Test Card enters, draw a card.\nTest Card deals 3 damage to any target.",
+    );
+    check(&session, "the creation of the session", true)?;
+
+    /* Edits on the first line */
+    edit(&mut session, "editing the first line", "Flying", "Trample", true)?;
+    edit(&mut session, "inserting at the start", "Trample", "Vigilance\nTrample", true)?;
+    edit(&mut session, "removing the first line", "Vigilance\n", "", true)?;
+
+    /* Edits on the last line, including ones that break it and fix it again */
+    edit(&mut session, "editing the last line", "3 damage", "2 damage", true)?;
+    edit(&mut session, "breaking the last line", "any target.", "any tar", false)?;
+    edit(&mut session, "fixing the last line", "any tar", "any target.", true)?;
+    edit(
+        &mut session,
+        "appending a line",
+        "any target.",
+        "any target.\nYou gain 2 life.",
+        true,
+    )?;
+
+    /* Edits in the middle, that change the lengths of the lines */
+    edit(&mut session, "editing a middle line", "draw a card", "draw two cards", true)?;
+    edit(
+        &mut session,
+        "breaking a middle line",
+        "draw two cards",
+        "draw two cardz",
+        false,
+    )?;
+    edit(&mut session, "fixing a middle line", "draw two cardz", "draw a card", true)?;
+
+    /* Replacing the whole text at once */
+    let oracle_text = session.oracle_text().to_string();
+    edit(&mut session, "replacing the whole text", &oracle_text, "Draw a card.", true)?;
+
+    Ok(())
+}
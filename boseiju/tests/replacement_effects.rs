@@ -0,0 +1,52 @@
+mod common;
+
+use boseiju::ability_tree::NodeKind;
+use common::check_nodes;
+
+fn main() -> Result<(), String> {
+    /* Events replaced by other events with "instead" */
+    check_nodes(
+        "If a creature would die, exile it instead.",
+        &[NodeKind::EventReplacementEffect, NodeKind::CreatureDiesAction],
+    )?;
+    check_nodes(
+        "If a creature you control would die, exile it instead.",
+        &[NodeKind::EventReplacementEffect, NodeKind::CreatureDiesAction],
+    )?;
+    check_nodes(
+        "If you would draw a card, draw two cards instead.",
+        &[NodeKind::EventReplacementEffect, NodeKind::PlayerDrawsCardAction],
+    )?;
+
+    /* Damage preventions, as a static ability or for the turn */
+    check_nodes(
+        "If a source would deal damage to you, prevent that damage.",
+        &[NodeKind::DamagePreventionEffect],
+    )?;
+    check_nodes(
+        "Prevent all combat damage that would be dealt this turn.",
+        &[NodeKind::DamagePreventionEffect],
+    )?;
+
+    /* Doubling effects */
+    check_nodes(
+        "If an effect would create one or more tokens under your control, it creates twice that many of those tokens instead.",
+        &[NodeKind::DoublingEffect],
+    )?;
+    check_nodes(
+        "If one or more +1/+1 counters would be put on a creature you control, twice that many +1/+1 counters are put on it instead.",
+        &[NodeKind::DoublingEffect],
+    )?;
+
+    /* Damage redirections, as a static ability or for the turn */
+    check_nodes(
+        "All damage that would be dealt to you is dealt to Test Card instead.",
+        &[NodeKind::DamageRedirectionEffect],
+    )?;
+    check_nodes(
+        "All damage that would be dealt to you this turn is dealt to Test Card instead.",
+        &[NodeKind::DamageRedirectionEffect],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use boseiju::ability_tree::number::Number;
+use boseiju::ability_tree::object::specified_object::CreatureSpecifier;
+use boseiju::ability_tree::targets::TargetSlot;
+use boseiju::ability_tree::targets::TargetSpecifier;
+use boseiju::ability_tree::targets::TargetedObject;
+
+/// Parse the oracle text, and list the target slots of all its abilities.
+fn targets(oracle_text: &str) -> Result<Vec<TargetSlot>, String> {
+    let tree = common::parse(oracle_text)?;
+    Ok(tree.abilities.iter().flat_map(|ability| ability.targets()).collect())
+}
+
+/// Check the slots have the expected targeted objects, distinct flags and numbers of controller constraints.
+fn check(oracle_text: &str, expected: &[(TargetedObject, bool, usize)]) -> Result<Vec<TargetSlot>, String> {
+    let slots = targets(oracle_text)?;
+    let found: Vec<_> = slots
+        .iter()
+        .map(|slot| (slot.object, slot.distinct, slot.controllers.len()))
+        .collect();
+    if found != expected {
+        return Err(format!("For {oracle_text:?}, expected {expected:?}, got {found:?}"));
+    }
+    if let Some(slot) = slots.iter().find(|slot| slot.consumed_by.is_none()) {
+        return Err(format!(
+            "For {oracle_text:?}, slot at {} is not consumed by an imperative",
+            slot.path
+        ));
+    }
+    Ok(slots)
+}
+
+fn main() -> Result<(), String> {
+    /* Single targets, with and without controller constraints */
+    check("Destroy target creature.", &[(TargetedObject::Creature, false, 0)])?;
+    check(
+        "Test Card deals 3 damage to any target.",
+        &[(TargetedObject::AnyTarget, false, 0)],
+    )?;
+    check("Target opponent loses 2 life.", &[(TargetedObject::Opponent, false, 0)])?;
+    check("Target player draws a card.", &[(TargetedObject::Player, false, 0)])?;
+
+    /* Permanents of several kinds */
+    check(
+        "Destroy target artifact or enchantment.",
+        &[(TargetedObject::Permanent, false, 0)],
+    )?;
+
+    /* Specifiers other than the controllers */
+    let slots = check("Destroy target black creature.", &[(TargetedObject::Creature, false, 0)])?;
+    if !matches!(
+        slots[0].specifiers.as_slice(),
+        [TargetSpecifier::Creature(CreatureSpecifier::Color(_))]
+    ) {
+        return Err(format!("Expected a color specifier, got {:?}", slots[0].specifiers));
+    }
+    let slots = check(
+        "Destroy target creature an opponent controls.",
+        &[(TargetedObject::Creature, false, 1)],
+    )?;
+    if !slots[0].specifiers.is_empty() {
+        return Err(format!("Expected no specifiers, got {:?}", slots[0].specifiers));
+    }
+
+    /* The player of a control specifier can be a target of its own, but doesn't add a controller to it */
+    let slots = check(
+        "Destroy target creature target opponent controls.",
+        &[(TargetedObject::Creature, false, 1), (TargetedObject::Opponent, false, 0)],
+    )?;
+    if slots[0].consumed_by != slots[1].consumed_by {
+        return Err("Both targets shall be consumed by the same imperative".to_string());
+    }
+
+    /* Several targets for a single slot must be distinct */
+    let slots = check("Destroy two target creatures.", &[(TargetedObject::Creature, true, 0)])?;
+    if !matches!(slots[0].count, Number::Number(ref number) if number.number == 2) {
+        return Err(format!("Expected two targets, got {:?}", slots[0].count));
+    }
+    let slots = check("Destroy up to two target creatures.", &[(TargetedObject::Creature, true, 0)])?;
+    if !matches!(slots[0].count, Number::UpTo(ref number) if number.maximum == 2) {
+        return Err(format!("Expected up to two targets, got {:?}", slots[0].count));
+    }
+    check(
+        "Destroy any number of target creatures.",
+        &[(TargetedObject::Creature, true, 0)],
+    )?;
+
+    /* No targets */
+    check("Draw a card.", &[])?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use boseiju::ability_tree::NodeKind;
+use common::check_nodes;
+
+fn main() -> Result<(), String> {
+    /* Steps and phases, with player and turn qualifiers */
+    check_nodes(
+        "At the beginning of each opponent's end step, draw a card.",
+        &[NodeKind::RecurrentInstant, NodeKind::StepOrPhase],
+    )?;
+    check_nodes(
+        "At the beginning of combat on your turn, draw a card.",
+        &[NodeKind::RecurrentInstant, NodeKind::StepOrPhase],
+    )?;
+    check_nodes(
+        "At the beginning of your next upkeep, draw a card.",
+        &[NodeKind::IncomingNextStepOrPhase, NodeKind::StepOrPhase],
+    )?;
+
+    /* Durations */
+    check_nodes(
+        "Creatures you control get +1/+1 until your next turn.",
+        &[NodeKind::GenerateContinuousEffectImperative],
+    )?;
+
+    /* During each of your turns */
+    check_nodes(
+        "Whenever you cast a spell during each of your turns, draw a card.",
+        &[NodeKind::ThisIsYourTurn],
+    )?;
+    check_nodes(
+        "During each of your turns, you may play a land from your graveyard.",
+        &[NodeKind::StaticAbility, NodeKind::ThisIsYourTurn],
+    )?;
+
+    /* Next time delayed triggers */
+    check_nodes(
+        "When you next cast a creature spell this turn, draw a card.",
+        &[
+            NodeKind::DelayedTriggerAbility,
+            NodeKind::IncomingNextTime,
+            NodeKind::PlayerCastsSpellAction,
+        ],
+    )?;
+    check_nodes(
+        "The next time a source of your choice would deal damage to you this turn, prevent that damage.",
+        &[NodeKind::GenerateContinuousEffectImperative, NodeKind::DamagePreventionEffect],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use boseiju::ability_tree::NodeKind;
+use common::check_nodes;
+
+fn main() -> Result<(), String> {
+    /* "equal to <game state number>" */
+    check_nodes(
+        "You gain life equal to your devotion to black.",
+        &[NodeKind::XFromGameState, NodeKind::Devotion],
+    )?;
+    check_nodes(
+        "You lose life equal to your devotion to black.",
+        &[NodeKind::XFromGameState, NodeKind::Devotion],
+    )?;
+    check_nodes(
+        "Draw cards equal to the number of creatures you control.",
+        &[NodeKind::XFromGameState],
+    )?;
+
+    /* "where X is <game state number>" outside of power and toughness modifications */
+    check_nodes(
+        "You gain X life, where X is your devotion to black.",
+        &[NodeKind::XFromGameState, NodeKind::Devotion],
+    )?;
+    check_nodes(
+        "Target player loses X life, where X is the number of creatures you control.",
+        &[NodeKind::XFromGameState],
+    )?;
+    check_nodes(
+        "Draw X cards, where X is the number of creatures you control.",
+        &[NodeKind::XFromGameState],
+    )?;
+
+    /* Numbers of times the card was kicked */
+    check_nodes(
+        "You gain life equal to the number of times Test Card was kicked.",
+        &[NodeKind::XFromGameState, NodeKind::NumberOfTimesKicked],
+    )?;
+    check_nodes(
+        "Draw a card for each time Test Card was kicked.",
+        &[NodeKind::ForEachImperative, NodeKind::NumberOfTimesKicked],
+    )?;
+
+    /* Damage amounts from the game state */
+    check_nodes(
+        "Test Card deals X damage to any target, where X is your devotion to black.",
+        &[NodeKind::DealsDamageImperative, NodeKind::XFromGameState, NodeKind::Devotion],
+    )?;
+    check_nodes(
+        "Test Card deals damage equal to the greatest power among creatures you control to any target.",
+        &[
+            NodeKind::DealsDamageImperative,
+            NodeKind::XFromGameState,
+            NodeKind::PermanentsAggregate,
+        ],
+    )?;
+
+    /* X on its own still comes from the cost */
+    check_nodes("Draw X cards.", &[NodeKind::XFromCost])?;
+
+    Ok(())
+}
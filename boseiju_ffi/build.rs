@@ -0,0 +1,24 @@
+/// Generate the C header of the library.
+///
+/// The header is always generated in the build output directory, so changes to the API are checked on each build.
+/// The committed `include/boseiju.h` is only overwritten when the `BOSEIJU_FFI_UPDATE_HEADER` environment variable is set.
+fn main() {
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").expect("Cargo shall provide the manifest dir");
+    let out_dir = std::env::var("OUT_DIR").expect("Cargo shall provide the output dir");
+    let config = cbindgen::Config::from_file(format!("{crate_dir}/cbindgen.toml")).expect("cbindgen.toml shall be valid");
+
+    match cbindgen::generate_with_config(&crate_dir, config) {
+        Ok(bindings) => {
+            bindings.write_to_file(format!("{out_dir}/boseiju.h"));
+            if std::env::var_os("BOSEIJU_FFI_UPDATE_HEADER").is_some() {
+                bindings.write_to_file(format!("{crate_dir}/include/boseiju.h"));
+            }
+        }
+        /* Don't fail the build for the header, but let the user know */
+        Err(e) => println!("cargo::warning=Failed to generate the C header: {e}"),
+    }
+
+    println!("cargo::rerun-if-changed=src");
+    println!("cargo::rerun-if-changed=cbindgen.toml");
+    println!("cargo::rerun-if-env-changed=BOSEIJU_FFI_UPDATE_HEADER");
+}
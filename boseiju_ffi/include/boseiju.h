@@ -0,0 +1,153 @@
+#ifndef BOSEIJU_H
+#define BOSEIJU_H
+
+/* This file is generated by the boseiju_ffi build script, do not edit it by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Node id returned for null node handles, that is never the id of an actual node.
+#define BOSEIJU_INVALID_NODE_ID ~0
+
+// Opaque handle to a parsed card.
+typedef struct BoseijuCard BoseijuCard;
+
+// Opaque handle to a node of an ability tree.
+//
+// A node borrows the tree or card it was obtained from, and is only valid as long as it lives.
+typedef struct BoseijuNode BoseijuNode;
+
+// Opaque handle to a parsed ability tree.
+typedef struct BoseijuTree BoseijuTree;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Parse the oracle text of a card into an ability tree.
+//
+// The card name is used to replace self references in the oracle text.
+// On error, returns null and writes the serialized lexer or parser error in `out_error`, if not null.
+//
+// # Safety
+//
+// `card_name` and `oracle_text` must be valid NUL-terminated strings.
+// `out_error` must be null or a valid pointer to write a string to.
+struct BoseijuTree *boseiju_parse(const char *card_name,
+                                  const char *oracle_text,
+                                  char **out_error);
+
+// Serialize an ability tree to JSON.
+//
+// # Safety
+//
+// `tree` must be a valid tree handle.
+char *boseiju_tree_to_json(const struct BoseijuTree *tree);
+
+// Get the root node of an ability tree.
+//
+// # Safety
+//
+// `tree` must be a valid tree handle, that outlives the returned node.
+struct BoseijuNode *boseiju_tree_root(const struct BoseijuTree *tree);
+
+// Free an ability tree.
+//
+// # Safety
+//
+// `tree` must be null or a tree handle that has not been freed yet.
+void boseiju_tree_free(struct BoseijuTree *tree);
+
+// Parse a card from a Scryfall card JSON object.
+//
+// On error, returns null and writes a JSON object with the error message in `out_error`, if not null.
+//
+// # Safety
+//
+// `card_json` must be a valid NUL-terminated string.
+// `out_error` must be null or a valid pointer to write a string to.
+struct BoseijuCard *boseiju_card_parse(const char *card_json,
+                                       char **out_error);
+
+// Serialize a card to JSON.
+//
+// # Safety
+//
+// `card` must be a valid card handle.
+char *boseiju_card_to_json(const struct BoseijuCard *card);
+
+// Get the root node of the layout of a card.
+//
+// # Safety
+//
+// `card` must be a valid card handle, that outlives the returned node.
+struct BoseijuNode *boseiju_card_root(const struct BoseijuCard *card);
+
+// Free a card.
+//
+// # Safety
+//
+// `card` must be null or a card handle that has not been freed yet.
+void boseiju_card_free(struct BoseijuCard *card);
+
+// Get the unique identifier of the kind of a node.
+//
+// Returns [`BOSEIJU_INVALID_NODE_ID`] if the node is null.
+//
+// # Safety
+//
+// `node` must be null or a valid node handle.
+size_t boseiju_node_id(const struct BoseijuNode *node);
+
+// Get the tag of a node, a short human readable name of its kind.
+//
+// # Safety
+//
+// `node` must be a valid node handle.
+char *boseiju_node_tag(const struct BoseijuNode *node);
+
+// Get the span of a node in the preprocessed oracle text, as byte offsets.
+//
+// Returns false if the node is null.
+//
+// # Safety
+//
+// `node` must be a valid node handle, `out_start` and `out_end` must be valid pointers.
+bool boseiju_node_span(const struct BoseijuNode *node, size_t *out_start, size_t *out_end);
+
+// Get the number of children of a node.
+//
+// # Safety
+//
+// `node` must be a valid node handle.
+size_t boseiju_node_children_count(const struct BoseijuNode *node);
+
+// Get the child of a node at the given index, or null if there is no such child.
+//
+// # Safety
+//
+// `node` must be a valid node handle. The returned node borrows the same tree or card as `node`.
+struct BoseijuNode *boseiju_node_child(const struct BoseijuNode *node, size_t index);
+
+// Free a node handle. This does not free the tree or card it comes from.
+//
+// # Safety
+//
+// `node` must be null or a node handle that has not been freed yet.
+void boseiju_node_free(struct BoseijuNode *node);
+
+// Free a string returned by this API.
+//
+// # Safety
+//
+// `string` must be null or a string returned by this API that has not been freed yet.
+void boseiju_string_free(char *string);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* BOSEIJU_H */
@@ -0,0 +1,340 @@
+//! C ABI for the Boseiju parser.
+//!
+//! This crate exposes the parser through opaque handles, so it can be called natively from
+//! any language with a C foreign function interface. The C header is committed in `include/boseiju.h`,
+//! and is regenerated by the build script when the `BOSEIJU_FFI_UPDATE_HEADER` environment variable is set.
+//!
+//! Ownership rules are the same for all the functions of this API:
+//! - Every handle returned by a function is owned by the caller, and shall be freed with
+//!   the matching `boseiju_*_free` function.
+//! - Every string returned by a function is a NUL-terminated UTF-8 string owned by the caller,
+//!   and shall be freed with [`boseiju_string_free`].
+//! - Nodes borrow the tree or card they come from: they shall be freed before it.
+//!
+//! Errors are reported through an optional `out_error` pointer, that receives a JSON object
+//! describing the error. On error, the function returns a null handle.
+
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::ffi::c_char;
+
+use boseiju::ability_tree::AbilityTreeNode;
+
+/// Node id returned for null node handles, that is never the id of an actual node.
+pub const BOSEIJU_INVALID_NODE_ID: usize = !0;
+
+/// Opaque handle to a parsed ability tree.
+pub struct BoseijuTree {
+    tree: boseiju::AbilityTree,
+}
+
+/// Opaque handle to a parsed card.
+pub struct BoseijuCard {
+    card: boseiju::Card,
+}
+
+/// Opaque handle to a node of an ability tree.
+///
+/// A node borrows the tree or card it was obtained from, and is only valid as long as it lives.
+pub struct BoseijuNode {
+    node: &'static dyn AbilityTreeNode,
+}
+
+impl BoseijuNode {
+    /// Create a new node handle, erasing the lifetime of the node.
+    ///
+    /// # Safety
+    ///
+    /// The node must outlive the returned handle, which the API requires from its callers.
+    unsafe fn new(node: &dyn AbilityTreeNode) -> *mut BoseijuNode {
+        let node: &'static dyn AbilityTreeNode = unsafe { std::mem::transmute(node) };
+        Box::into_raw(Box::new(BoseijuNode { node }))
+    }
+}
+
+/// Parse the oracle text of a card into an ability tree.
+///
+/// The card name is used to replace self references in the oracle text.
+/// On error, returns null and writes the serialized lexer or parser error in `out_error`, if not null.
+///
+/// # Safety
+///
+/// `card_name` and `oracle_text` must be valid NUL-terminated strings.
+/// `out_error` must be null or a valid pointer to write a string to.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_parse(
+    card_name: *const c_char,
+    oracle_text: *const c_char,
+    out_error: *mut *mut c_char,
+) -> *mut BoseijuTree {
+    let (card_name, oracle_text) = match unsafe { (c_str(card_name), c_str(oracle_text)) } {
+        (Some(card_name), Some(oracle_text)) => (card_name, oracle_text),
+        _ => {
+            return unsafe {
+                fail(
+                    out_error,
+                    invalid_argument("card name and oracle text must be valid UTF-8 strings"),
+                )
+            };
+        }
+    };
+
+    let preprocessed = boseiju::preprocess(card_name, oracle_text);
+    let tokens = match boseiju::lex(&preprocessed) {
+        Ok(tokens) => tokens,
+        Err(e) => return unsafe { fail(out_error, to_json(&e)) },
+    };
+    match boseiju::parse(&tokens) {
+        Ok(tree) => Box::into_raw(Box::new(BoseijuTree { tree })),
+        Err(e) => unsafe { fail(out_error, to_json(&e)) },
+    }
+}
+
+/// Serialize an ability tree to JSON.
+///
+/// # Safety
+///
+/// `tree` must be a valid tree handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_tree_to_json(tree: *const BoseijuTree) -> *mut c_char {
+    match unsafe { tree.as_ref() } {
+        Some(tree) => into_c_string(to_json(&tree.tree)),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Get the root node of an ability tree.
+///
+/// # Safety
+///
+/// `tree` must be a valid tree handle, that outlives the returned node.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_tree_root(tree: *const BoseijuTree) -> *mut BoseijuNode {
+    match unsafe { tree.as_ref() } {
+        Some(tree) => unsafe { BoseijuNode::new(&tree.tree) },
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Free an ability tree.
+///
+/// # Safety
+///
+/// `tree` must be null or a tree handle that has not been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_tree_free(tree: *mut BoseijuTree) {
+    if !tree.is_null() {
+        drop(unsafe { Box::from_raw(tree) });
+    }
+}
+
+/// Parse a card from a Scryfall card JSON object.
+///
+/// On error, returns null and writes a JSON object with the error message in `out_error`, if not null.
+///
+/// # Safety
+///
+/// `card_json` must be a valid NUL-terminated string.
+/// `out_error` must be null or a valid pointer to write a string to.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_card_parse(card_json: *const c_char, out_error: *mut *mut c_char) -> *mut BoseijuCard {
+    let card_json = match unsafe { c_str(card_json) } {
+        Some(card_json) => card_json,
+        None => return unsafe { fail(out_error, invalid_argument("card json must be a valid UTF-8 string")) },
+    };
+
+    let raw_card = match serde_json::from_str::<mtg_cardbase::Card>(card_json) {
+        Ok(raw_card) => raw_card,
+        Err(e) => return unsafe { fail(out_error, error_message("InvalidCardJson", &e.to_string())) },
+    };
+    match boseiju::Card::try_from(&raw_card) {
+        Ok(card) => Box::into_raw(Box::new(BoseijuCard { card })),
+        Err(e) => unsafe { fail(out_error, error_message("CardError", &e)) },
+    }
+}
+
+/// Serialize a card to JSON.
+///
+/// # Safety
+///
+/// `card` must be a valid card handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_card_to_json(card: *const BoseijuCard) -> *mut c_char {
+    match unsafe { card.as_ref() } {
+        Some(card) => into_c_string(to_json(&card.card)),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Get the root node of the layout of a card.
+///
+/// # Safety
+///
+/// `card` must be a valid card handle, that outlives the returned node.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_card_root(card: *const BoseijuCard) -> *mut BoseijuNode {
+    match unsafe { card.as_ref() } {
+        Some(card) => unsafe { BoseijuNode::new(card.card.layout.root()) },
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Free a card.
+///
+/// # Safety
+///
+/// `card` must be null or a card handle that has not been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_card_free(card: *mut BoseijuCard) {
+    if !card.is_null() {
+        drop(unsafe { Box::from_raw(card) });
+    }
+}
+
+/// Get the unique identifier of the kind of a node.
+///
+/// Returns [`BOSEIJU_INVALID_NODE_ID`] if the node is null.
+///
+/// # Safety
+///
+/// `node` must be null or a valid node handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_node_id(node: *const BoseijuNode) -> usize {
+    match unsafe { node.as_ref() } {
+        Some(node) => node.node.node_id(),
+        None => BOSEIJU_INVALID_NODE_ID,
+    }
+}
+
+/// Get the tag of a node, a short human readable name of its kind.
+///
+/// # Safety
+///
+/// `node` must be a valid node handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_node_tag(node: *const BoseijuNode) -> *mut c_char {
+    match unsafe { node.as_ref() } {
+        Some(node) => into_c_string(node.node.node_tag().to_string()),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Get the span of a node in the preprocessed oracle text, as byte offsets.
+///
+/// Returns false if the node is null.
+///
+/// # Safety
+///
+/// `node` must be a valid node handle, `out_start` and `out_end` must be valid pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_node_span(node: *const BoseijuNode, out_start: *mut usize, out_end: *mut usize) -> bool {
+    match unsafe { node.as_ref() } {
+        Some(node) => {
+            let span = node.node.node_span();
+            unsafe {
+                *out_start = span.start;
+                *out_end = span.end;
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+/// Get the number of children of a node.
+///
+/// # Safety
+///
+/// `node` must be a valid node handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_node_children_count(node: *const BoseijuNode) -> usize {
+    match unsafe { node.as_ref() } {
+        Some(node) => node.node.children().len(),
+        None => 0,
+    }
+}
+
+/// Get the child of a node at the given index, or null if there is no such child.
+///
+/// # Safety
+///
+/// `node` must be a valid node handle. The returned node borrows the same tree or card as `node`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_node_child(node: *const BoseijuNode, index: usize) -> *mut BoseijuNode {
+    let node = match unsafe { node.as_ref() } {
+        Some(node) => node,
+        None => return std::ptr::null_mut(),
+    };
+    match node.node.children().get(index) {
+        Some(child) => unsafe { BoseijuNode::new(*child) },
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Free a node handle. This does not free the tree or card it comes from.
+///
+/// # Safety
+///
+/// `node` must be null or a node handle that has not been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_node_free(node: *mut BoseijuNode) {
+    if !node.is_null() {
+        drop(unsafe { Box::from_raw(node) });
+    }
+}
+
+/// Free a string returned by this API.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by this API that has not been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn boseiju_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+/// Read a C string as a Rust string, if it is not null and is valid UTF-8.
+unsafe fn c_str<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+    unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+}
+
+/// Give a Rust string to the caller as a C string.
+///
+/// Strings can't contain NUL bytes, so they are stripped if any.
+fn into_c_string(string: String) -> *mut c_char {
+    let string = CString::new(string).unwrap_or_else(|e| {
+        let mut bytes = e.into_vec();
+        bytes.retain(|byte| *byte != 0);
+        CString::new(bytes).unwrap_or_default()
+    });
+    string.into_raw()
+}
+
+/// Write the error to the error output if any, and return a null handle.
+unsafe fn fail<T>(out_error: *mut *mut c_char, error: String) -> *mut T {
+    if !out_error.is_null() {
+        unsafe { *out_error = into_c_string(error) };
+    }
+    std::ptr::null_mut()
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> String {
+    match serde_json::to_string(value) {
+        Ok(serialized) => serialized,
+        Err(e) => error_message("SerializationError", &e.to_string()),
+    }
+}
+
+fn invalid_argument(message: &str) -> String {
+    error_message("InvalidArgument", message)
+}
+
+/// Build an error JSON object, with the same "kind" field as the lexer and parser errors.
+fn error_message(kind: &str, message: &str) -> String {
+    serde_json::json!({ "kind": kind, "message": message }).to_string()
+}
@@ -0,0 +1,86 @@
+use boseiju_ffi::*;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::ffi::c_char;
+
+/// Read a string returned by the API, and free it.
+fn take_string(string: *mut c_char) -> Result<String, String> {
+    if string.is_null() {
+        return Err("Expected a string, got null".to_string());
+    }
+    let result = unsafe { CStr::from_ptr(string) }.to_string_lossy().into_owned();
+    unsafe { boseiju_string_free(string) };
+    Ok(result)
+}
+
+/// Walk the nodes below the given one, freeing each child handle, and count them.
+fn walk(node: *const BoseijuNode) -> Result<usize, String> {
+    if unsafe { boseiju_node_id(node) } == BOSEIJU_INVALID_NODE_ID {
+        return Err("Valid nodes shall have a valid node id".to_string());
+    }
+    take_string(unsafe { boseiju_node_tag(node) })?;
+
+    let (mut start, mut end) = (0, 0);
+    if !unsafe { boseiju_node_span(node, &mut start, &mut end) } || start > end {
+        return Err(format!("Invalid node span: {start}..{end}"));
+    }
+
+    let mut count = 1;
+    for index in 0..unsafe { boseiju_node_children_count(node) } {
+        let child = unsafe { boseiju_node_child(node, index) };
+        if child.is_null() {
+            return Err(format!("Missing child at index {index}"));
+        }
+        let walked = walk(child);
+        unsafe { boseiju_node_free(child) };
+        count += walked?;
+    }
+    Ok(count)
+}
+
+fn main() -> Result<(), String> {
+    let card_name = CString::new("Test Card").unwrap();
+
+    /* Parse, walk, serialize and free a tree */
+    let oracle_text = CString::new("Target player draws a card.").unwrap();
+    let mut error = std::ptr::null_mut();
+    let tree = unsafe { boseiju_parse(card_name.as_ptr(), oracle_text.as_ptr(), &mut error) };
+    if tree.is_null() {
+        return Err(format!("Failed to parse the oracle text: {}", take_string(error)?));
+    }
+
+    let root = unsafe { boseiju_tree_root(tree) };
+    let walked = walk(root);
+    unsafe { boseiju_node_free(root) };
+    if walked? < 2 {
+        return Err("The tree shall have more than its root".to_string());
+    }
+
+    let json = take_string(unsafe { boseiju_tree_to_json(tree) })?;
+    serde_json::from_str::<serde_json::Value>(&json).map_err(|e| format!("Invalid tree JSON: {e}"))?;
+    unsafe { boseiju_tree_free(tree) };
+
+    /* Errors are reported as JSON objects, with a null handle */
+    let oracle_text = CString::new("Flibbertigibbet the wumpus.").unwrap();
+    let mut error = std::ptr::null_mut();
+    let tree = unsafe { boseiju_parse(card_name.as_ptr(), oracle_text.as_ptr(), &mut error) };
+    if !tree.is_null() {
+        unsafe { boseiju_tree_free(tree) };
+        return Err("Parsing an invalid oracle text shall fail".to_string());
+    }
+    let error = take_string(error)?;
+    let error: serde_json::Value = serde_json::from_str(&error).map_err(|e| format!("Invalid error JSON: {e}"))?;
+    if !error.is_object() {
+        return Err(format!("Errors shall be JSON objects, got {error}"));
+    }
+
+    /* Null handles are rejected without crashing */
+    if unsafe { boseiju_node_id(std::ptr::null()) } != BOSEIJU_INVALID_NODE_ID {
+        return Err("Null nodes shall have the invalid node id".to_string());
+    }
+    if !unsafe { boseiju_tree_root(std::ptr::null()) }.is_null() {
+        return Err("Null trees shall have no root".to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,221 @@
+//! Documents opened in the editor, and the language features computed on them.
+
+use crate::source_map::LineIndex;
+use crate::source_map::SourceMap;
+use boseiju::ability_tree::AbilityTreeNode;
+use boseiju::error::BoseijuError;
+use boseiju::parser::ParserError;
+use std::ops::Range;
+
+/// The file formats handled by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFormat {
+    /// A custom card file, where the first line is the card name and the rest of the file is its oracle text.
+    Card,
+    /// An oracle text alone, where the card refers to itself with "~".
+    Oracle,
+}
+
+impl FileFormat {
+    /// The file format from the extension of the document, oracle texts being the default.
+    pub fn from_uri(uri: &lsp_types::Uri) -> Self {
+        match uri.path().as_str().ends_with(".card") {
+            true => FileFormat::Card,
+            false => FileFormat::Oracle,
+        }
+    }
+}
+
+/// A document opened in the editor.
+///
+/// The document keeps a parser session on its oracle text, so that edits only parse again what changed.
+pub struct Document {
+    format: FileFormat,
+    text: String,
+    /// Byte offset of the oracle text in the document.
+    oracle_start: usize,
+    card_name: boseiju::lexer::CardName,
+    session: boseiju::parser::ParserSession,
+    source_map: SourceMap,
+    lines: LineIndex,
+}
+
+impl Document {
+    pub fn new(format: FileFormat, text: String) -> Self {
+        let (card_name, oracle_start) = split_card_name(format, &text);
+        let session = boseiju::parser::ParserSession::from_card_name(card_name.clone(), &text[oracle_start..]);
+        let source_map = source_map(&card_name, &text[oracle_start..]);
+        Self {
+            format,
+            lines: LineIndex::new(&text),
+            text,
+            oracle_start,
+            card_name,
+            session,
+            source_map,
+        }
+    }
+
+    /// Replace the content of the document.
+    pub fn update(&mut self, text: String) {
+        let (card_name, oracle_start) = split_card_name(self.format, &text);
+        match card_name == self.card_name {
+            true => self.session.set_oracle_text(&text[oracle_start..]),
+            false => self.session = boseiju::parser::ParserSession::from_card_name(card_name.clone(), &text[oracle_start..]),
+        }
+        self.source_map = source_map(&card_name, &text[oracle_start..]);
+        self.lines = LineIndex::new(&text);
+        self.text = text;
+        self.oracle_start = oracle_start;
+        self.card_name = card_name;
+    }
+
+    /// The lexer or parser error of the oracle text, if any.
+    pub fn diagnostics(&self) -> Vec<lsp_types::Diagnostic> {
+        let error = match self.session.parse() {
+            Ok(_) => return Vec::new(),
+            Err(error) => error,
+        };
+        let (range, message) = match &error {
+            BoseijuError::LexerError(lexer_error) => {
+                let boseiju::lexer::LexerError::NoTokenMatch { start, end, .. } = lexer_error;
+                (*start..*end, lexer_error.to_string())
+            }
+            BoseijuError::ParserError(parser_error) => {
+                let range = match parser_error {
+                    ParserError::UnexpectedToken { found, .. } => found.position..found.position + found.length,
+                    ParserError::FailedToApplyRule { position, length, .. } => *position..*position + *length,
+                    ParserError::AmbiguousCandidates { position, length, .. } => *position..*position + *length,
+                    ParserError::InvalidEarleyTable => 0..0,
+                };
+                (range, parser_error.to_string())
+            }
+        };
+        vec![lsp_types::Diagnostic {
+            range: self.editor_range(range),
+            severity: Some(lsp_types::DiagnosticSeverity::ERROR),
+            source: Some("boseiju".to_string()),
+            message,
+            ..Default::default()
+        }]
+    }
+
+    /// The description of the deepest node of the ability tree under the cursor.
+    pub fn hover(&self, position: lsp_types::Position) -> Option<lsp_types::Hover> {
+        let offset = self.preprocessed_offset(position)?;
+        let tree = self.session.parse().ok()?;
+        let node = node_at(&tree, offset)?;
+        let span = node.node_span();
+        Some(lsp_types::Hover {
+            contents: lsp_types::HoverContents::Markup(lsp_types::MarkupContent {
+                kind: lsp_types::MarkupKind::Markdown,
+                value: format!("**{}**\n\n{}", node.node_tag(), node.node_description()),
+            }),
+            range: Some(self.editor_range(span.start..span.end)),
+        })
+    }
+
+    /// The tokens that can be written at the cursor, from the Earley items awaiting them.
+    pub fn completions(&self, position: lsp_types::Position) -> Vec<lsp_types::CompletionItem> {
+        use boseiju::parser::ParserNode;
+        use idris::Idris;
+
+        let Some(offset) = self.preprocessed_offset(position) else {
+            return Vec::new();
+        };
+        self.session
+            .completions(offset)
+            .into_iter()
+            .map(|completion| {
+                let mut for_nodes: Vec<&str> = completion
+                    .for_nodes
+                    .iter()
+                    .map(|(node, _)| ParserNode::name_from_id(*node))
+                    .collect();
+                for_nodes.sort();
+                for_nodes.dedup();
+                lsp_types::CompletionItem {
+                    label: ParserNode::name_from_id(completion.expected).to_string(),
+                    kind: Some(lsp_types::CompletionItemKind::KEYWORD),
+                    detail: Some(format!("to create {}", for_nodes.join(", "))),
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    /// The semantic tokens of the document, from the kinds of the lexer tokens.
+    pub fn semantic_tokens(&self) -> Vec<lsp_types::SemanticToken> {
+        let mut result = Vec::new();
+        let mut previous = lsp_types::Position::new(0, 0);
+        for token in self.session.tokens().iter() {
+            let Some(token_type) = crate::semantic_tokens::token_type(token) else {
+                continue;
+            };
+            let span = token.span();
+            let range = self.editor_range(span.start..span.end);
+            /* Semantic tokens can't span several lines */
+            if range.start.line != range.end.line || range.start == range.end {
+                continue;
+            }
+            let delta_line = range.start.line - previous.line;
+            result.push(lsp_types::SemanticToken {
+                delta_line,
+                delta_start: match delta_line {
+                    0 => range.start.character - previous.character,
+                    _ => range.start.character,
+                },
+                length: range.end.character - range.start.character,
+                token_type,
+                token_modifiers_bitset: 0,
+            });
+            previous = range.start;
+        }
+        result
+    }
+
+    /// The editor range of a range of the preprocessed oracle text.
+    fn editor_range(&self, range: Range<usize>) -> lsp_types::Range {
+        let source = self.source_map.to_source(range);
+        self.lines
+            .range(&self.text, self.oracle_start + source.start..self.oracle_start + source.end)
+    }
+
+    /// The position in the preprocessed oracle text of an editor position, if it is in the oracle text.
+    fn preprocessed_offset(&self, position: lsp_types::Position) -> Option<usize> {
+        let offset = self.lines.offset(&self.text, position).checked_sub(self.oracle_start)?;
+        Some(self.source_map.to_preprocessed(offset))
+    }
+}
+
+/// The card name of the document and the byte offset of its oracle text.
+///
+/// Custom cards are not in the card base, so only their name is known.
+fn split_card_name(format: FileFormat, text: &str) -> (boseiju::lexer::CardName, usize) {
+    let (name, oracle_start) = match format {
+        FileFormat::Card => match text.find('\n') {
+            Some(index) => (text[..index].trim(), index + 1),
+            None => (text.trim(), text.len()),
+        },
+        FileFormat::Oracle => ("~", 0),
+    };
+    (boseiju::lexer::CardName::new(name), oracle_start)
+}
+
+fn source_map(card_name: &boseiju::lexer::CardName, oracle_text: &str) -> SourceMap {
+    let preprocessed = boseiju::lexer::preprocess_card(card_name, oracle_text);
+    SourceMap::new(oracle_text, &preprocessed)
+}
+
+/// The deepest node whose span contains the offset.
+///
+/// Some nodes have no span of their own, so their children are searched even if the node does not match.
+fn node_at(node: &dyn AbilityTreeNode, offset: usize) -> Option<&dyn AbilityTreeNode> {
+    for child in node.children().into_iter() {
+        if let Some(found) = node_at(child, offset) {
+            return Some(found);
+        }
+    }
+    let span = node.node_span();
+    (span.start <= offset && offset < span.end).then_some(node)
+}
@@ -0,0 +1,174 @@
+//! Language server for oracle texts and custom card files.
+//!
+//! The server handles two file formats:
+//! - `.oracle` files, containing an oracle text alone, where the card refers to itself with "~";
+//! - `.card` files, where the first line is the card name and the rest of the file is the oracle text.
+//!
+//! It provides diagnostics from the lexer and parser errors, hovers with the description of the
+//! node under the cursor, completions of the tokens the parser can accept next, and semantic
+//! highlighting from the kinds of the lexer tokens.
+//!
+//! The server communicates through an [`lsp_server::Connection`], that can be the standard
+//! input and output as in the `boseiju-lsp` binary, or an in-memory connection for tests.
+
+mod document;
+mod semantic_tokens;
+mod source_map;
+
+pub use document::Document;
+pub use document::FileFormat;
+
+use lsp_server::Connection;
+use lsp_server::Message;
+use lsp_server::Notification;
+use lsp_server::Request;
+use lsp_server::Response;
+use lsp_types::notification::Notification as _;
+use lsp_types::request::Request as _;
+
+type Error = Box<dyn std::error::Error + Send + Sync>;
+
+/// The capabilities of the server, sent to the client on initialization.
+pub fn server_capabilities() -> lsp_types::ServerCapabilities {
+    lsp_types::ServerCapabilities {
+        text_document_sync: Some(lsp_types::TextDocumentSyncCapability::Kind(
+            lsp_types::TextDocumentSyncKind::FULL,
+        )),
+        hover_provider: Some(lsp_types::HoverProviderCapability::Simple(true)),
+        completion_provider: Some(lsp_types::CompletionOptions {
+            trigger_characters: Some(vec![" ".to_string()]),
+            ..Default::default()
+        }),
+        semantic_tokens_provider: Some(lsp_types::SemanticTokensServerCapabilities::SemanticTokensOptions(
+            lsp_types::SemanticTokensOptions {
+                legend: semantic_tokens::legend(),
+                full: Some(lsp_types::SemanticTokensFullOptions::Bool(true)),
+                ..Default::default()
+            },
+        )),
+        ..Default::default()
+    }
+}
+
+/// Run the server on the connection, until the client asks for a shutdown.
+pub fn run(connection: &Connection) -> Result<(), Error> {
+    connection.initialize(serde_json::to_value(server_capabilities())?)?;
+
+    let mut server = Server {
+        documents: std::collections::HashMap::new(),
+    };
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    return Ok(());
+                }
+                let response = server.handle_request(request);
+                connection.sender.send(Message::Response(response))?;
+            }
+            Message::Notification(notification) => {
+                if let Some(diagnostics) = server.handle_notification(notification)? {
+                    let notification =
+                        Notification::new(lsp_types::notification::PublishDiagnostics::METHOD.to_string(), diagnostics);
+                    connection.sender.send(Message::Notification(notification))?;
+                }
+            }
+            Message::Response(_) => { /* The server never sends requests */ }
+        }
+    }
+    Ok(())
+}
+
+struct Server {
+    documents: std::collections::HashMap<lsp_types::Uri, Document>,
+}
+
+impl Server {
+    fn handle_request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            lsp_types::request::HoverRequest::METHOD => {
+                self.with_params::<lsp_types::request::HoverRequest, _>(request, |server, params| {
+                    let position = params.text_document_position_params;
+                    let document = server.documents.get(&position.text_document.uri)?;
+                    document.hover(position.position)
+                })
+            }
+            lsp_types::request::Completion::METHOD => {
+                self.with_params::<lsp_types::request::Completion, _>(request, |server, params| {
+                    let position = params.text_document_position;
+                    let document = server.documents.get(&position.text_document.uri)?;
+                    Some(lsp_types::CompletionResponse::Array(document.completions(position.position)))
+                })
+            }
+            lsp_types::request::SemanticTokensFullRequest::METHOD => self
+                .with_params::<lsp_types::request::SemanticTokensFullRequest, _>(request, |server, params| {
+                    let document = server.documents.get(&params.text_document.uri)?;
+                    Some(lsp_types::SemanticTokensResult::Tokens(lsp_types::SemanticTokens {
+                        result_id: None,
+                        data: document.semantic_tokens(),
+                    }))
+                }),
+            _ => Response::new_err(
+                request.id,
+                lsp_server::ErrorCode::MethodNotFound as i32,
+                format!("Unknown request: {}", request.method),
+            ),
+        }
+    }
+
+    /// Extract the parameters of the request, and respond with the result of the handler.
+    fn with_params<R, T>(&self, request: Request, handler: impl Fn(&Self, R::Params) -> Option<T>) -> Response
+    where
+        R: lsp_types::request::Request,
+        T: serde::Serialize,
+    {
+        let id = request.id.clone();
+        match request.extract::<R::Params>(R::METHOD) {
+            Ok((id, params)) => Response::new_ok(id, handler(self, params)),
+            Err(error) => Response::new_err(id, lsp_server::ErrorCode::InvalidParams as i32, error.to_string()),
+        }
+    }
+
+    /// Update the documents, and return the new diagnostics of the changed document if any.
+    fn handle_notification(&mut self, notification: Notification) -> Result<Option<lsp_types::PublishDiagnosticsParams>, Error> {
+        let uri = match notification.method.as_str() {
+            lsp_types::notification::DidOpenTextDocument::METHOD => {
+                let params: lsp_types::DidOpenTextDocumentParams = serde_json::from_value(notification.params)?;
+                let document = params.text_document;
+                let format = FileFormat::from_uri(&document.uri);
+                self.documents
+                    .insert(document.uri.clone(), Document::new(format, document.text));
+                document.uri
+            }
+            lsp_types::notification::DidChangeTextDocument::METHOD => {
+                let params: lsp_types::DidChangeTextDocumentParams = serde_json::from_value(notification.params)?;
+                /* Documents are synchronized in full, the last change holds the whole text */
+                let (Some(document), Some(change)) = (
+                    self.documents.get_mut(&params.text_document.uri),
+                    params.content_changes.into_iter().last(),
+                ) else {
+                    return Ok(None);
+                };
+                document.update(change.text);
+                params.text_document.uri
+            }
+            lsp_types::notification::DidCloseTextDocument::METHOD => {
+                let params: lsp_types::DidCloseTextDocumentParams = serde_json::from_value(notification.params)?;
+                self.documents.remove(&params.text_document.uri);
+                /* Clear the diagnostics of the closed document */
+                return Ok(Some(lsp_types::PublishDiagnosticsParams {
+                    uri: params.text_document.uri,
+                    diagnostics: Vec::new(),
+                    version: None,
+                }));
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(self.documents.get(&uri).map(|document| lsp_types::PublishDiagnosticsParams {
+            diagnostics: document.diagnostics(),
+            uri,
+            version: None,
+        }))
+    }
+}
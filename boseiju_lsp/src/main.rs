@@ -0,0 +1,9 @@
+//! Language server for oracle texts and custom card files, communicating through the standard input and output.
+
+fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let (connection, io_threads) = lsp_server::Connection::stdio();
+    boseiju_lsp::run(&connection)?;
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
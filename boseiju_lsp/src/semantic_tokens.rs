@@ -0,0 +1,68 @@
+//! Semantic highlighting of the oracle texts, from the kinds of the lexer tokens.
+
+use boseiju::lexer::tokens::Token;
+use lsp_types::SemanticTokenType;
+
+/// The semantic token types used by the server, the index of a type in this list is its id.
+pub const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::KEYWORD,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::TYPE,
+    SemanticTokenType::NUMBER,
+    SemanticTokenType::ENUM_MEMBER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::OPERATOR,
+];
+
+pub fn legend() -> lsp_types::SemanticTokensLegend {
+    lsp_types::SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: Vec::new(),
+    }
+}
+
+/// The semantic token type of a lexer token, as an index in [`TOKEN_TYPES`].
+///
+/// Tokens that are plain english words are not highlighted.
+pub fn token_type(token: &Token) -> Option<u32> {
+    let token_type = match token {
+        /* Keywords of the game */
+        Token::AbilityWord(_) | Token::KeywordAbility(_) | Token::KeywordAction(_) => SemanticTokenType::KEYWORD,
+        /* Things players and cards do */
+        Token::ActionKeyword(_) | Token::CardActions(_) | Token::PlayerAction(_) => SemanticTokenType::FUNCTION,
+        /* Type line elements */
+        Token::ArtifactSubtype(_)
+        | Token::BattleSubtype(_)
+        | Token::CardType(_)
+        | Token::CreatureSubtype(_)
+        | Token::EnchantmentSubtype(_)
+        | Token::InstantSorcerySubtype(_)
+        | Token::LandSubtype(_)
+        | Token::PlaneswalkerSubtype(_)
+        | Token::Supertype(_) => SemanticTokenType::TYPE,
+        /* Numbers and mana */
+        Token::Mana { .. }
+        | Token::Number(_)
+        | Token::NumberOfTimes(_)
+        | Token::PowerToughness { .. }
+        | Token::PowerToughnessModElements(_)
+        | Token::SagaChapterNumber { .. } => SemanticTokenType::NUMBER,
+        /* Named game elements */
+        Token::Color(_)
+        | Token::Counter(_)
+        | Token::DamageKind(_)
+        | Token::GlobalZone(_)
+        | Token::NamedToken(_)
+        | Token::OwnableZone(_)
+        | Token::Phase(_)
+        | Token::Step(_) => SemanticTokenType::ENUM_MEMBER,
+        /* References to objects and players */
+        Token::CardOwnName(_) | Token::ChoiceReference(_) | Token::PlayerSpecifier(_) => SemanticTokenType::VARIABLE,
+        Token::ControlFlow(_) | Token::NumberOperation(_) => SemanticTokenType::OPERATOR,
+        _ => return None,
+    };
+    TOKEN_TYPES
+        .iter()
+        .position(|known| *known == token_type)
+        .map(|index| index as u32)
+}
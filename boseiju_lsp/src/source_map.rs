@@ -0,0 +1,166 @@
+//! Conversions between positions in the preprocessed oracle text, the document and the editor.
+//!
+//! The parser works on the preprocessed oracle text, where reminder texts are removed and the card name is
+//! replaced by "~". Editors work on the document as it was typed, with lines and UTF-16 columns.
+
+use std::ops::Range;
+
+/// Map from the bytes of a preprocessed oracle text to the bytes of the oracle text it was built from.
+pub struct SourceMap {
+    /// For each byte of the preprocessed text, the range of the oracle text it was built from.
+    sources: Vec<Range<usize>>,
+    oracle_text_len: usize,
+}
+
+impl SourceMap {
+    /// Align the preprocessed text with the oracle text it was built from.
+    ///
+    /// Preprocessing lowercases the text, removes reminder texts, replaces the escaped characters
+    /// and the names of the card, and trims the result. Every character of the preprocessed text
+    /// is matched to its source, skipping the characters of the oracle text that were removed.
+    pub fn new(oracle_text: &str, preprocessed: &boseiju::lexer::PreprocessedText) -> Self {
+        let mut names: Vec<&str> = preprocessed
+            .name_substitutions
+            .iter()
+            .map(|substitution| substitution.name.as_str())
+            .collect();
+        /* Try the longest names first, since shorter forms are prefixes of the full name */
+        names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+
+        let mut sources = Vec::with_capacity(preprocessed.text.len());
+        let mut source = 0;
+        for char in preprocessed.text.chars() {
+            let matched = loop {
+                let remaining = &oracle_text[source..];
+                let Some(source_char) = remaining.chars().next() else {
+                    break 0;
+                };
+                if source_char.to_lowercase().eq(std::iter::once(char)) {
+                    break source_char.len_utf8();
+                }
+                if char == '\n' && remaining.starts_with("\\n") {
+                    break 2;
+                }
+                if char == '~' {
+                    let lowercase = remaining.to_lowercase();
+                    if let Some(name) = names.iter().find(|name| lowercase.starts_with(*name)) {
+                        break name.len();
+                    }
+                }
+                if let Some(escaped) = escaped_char(remaining)
+                    && escaped.to_lowercase().eq(std::iter::once(char))
+                {
+                    break 6;
+                }
+                /* The character was removed by the preprocessing, skip it */
+                source += match source_char {
+                    '(' => reminder_text_len(remaining),
+                    other => other.len_utf8(),
+                };
+            };
+            for _ in 0..char.len_utf8() {
+                sources.push(source..source + matched);
+            }
+            source += matched;
+        }
+
+        Self {
+            sources,
+            oracle_text_len: oracle_text.len(),
+        }
+    }
+
+    /// The range of the oracle text a range of the preprocessed text was built from.
+    pub fn to_source(&self, range: Range<usize>) -> Range<usize> {
+        let start = match self.sources.get(range.start) {
+            Some(source) => source.start,
+            None => self.sources.last().map(|source| source.end).unwrap_or(self.oracle_text_len),
+        };
+        let end = match range.end.checked_sub(1).and_then(|last| self.sources.get(last)) {
+            Some(source) if range.end > range.start => source.end,
+            _ => start,
+        };
+        start..end
+    }
+
+    /// The position in the preprocessed text matching a position in the oracle text.
+    ///
+    /// This is the position right after all the preprocessed characters coming from before the position.
+    pub fn to_preprocessed(&self, position: usize) -> usize {
+        self.sources.partition_point(|source| source.end <= position)
+    }
+}
+
+/// The character escaped as "\uXXXX" at the start of the text, if any.
+fn escaped_char(text: &str) -> Option<char> {
+    let digits = text.strip_prefix("\\u")?.get(..4)?;
+    char::from_u32(u32::from_str_radix(digits, 16).ok()?)
+}
+
+/// Length of the reminder text at the start of the text, nested parenthesis included.
+fn reminder_text_len(text: &str) -> usize {
+    let mut depth = 0;
+    for (index, char) in text.char_indices() {
+        match char {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 {
+            return index + 1;
+        }
+    }
+    text.len()
+}
+
+/// Conversions between byte offsets in a document and the line and UTF-16 column positions of editors.
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self { line_starts }
+    }
+
+    /// The editor position of a byte offset of the text.
+    pub fn position(&self, text: &str, offset: usize) -> lsp_types::Position {
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        let line_start = self.line_starts[line];
+        let character = text[line_start..offset].encode_utf16().count();
+        lsp_types::Position::new(line as u32, character as u32)
+    }
+
+    /// The editor range of a byte range of the text.
+    pub fn range(&self, text: &str, range: Range<usize>) -> lsp_types::Range {
+        lsp_types::Range::new(self.position(text, range.start), self.position(text, range.end))
+    }
+
+    /// The byte offset of an editor position in the text, clamped to the line.
+    pub fn offset(&self, text: &str, position: lsp_types::Position) -> usize {
+        let line_start = match self.line_starts.get(position.line as usize) {
+            Some(line_start) => *line_start,
+            None => return text.len(),
+        };
+        let line_end = self
+            .line_starts
+            .get(position.line as usize + 1)
+            .copied()
+            .unwrap_or(text.len());
+        let line_end = match text[line_start..line_end].ends_with('\n') {
+            true => line_end - 1,
+            false => line_end,
+        };
+        let mut utf16_count = 0;
+        for (index, char) in text[line_start..line_end].char_indices() {
+            if utf16_count >= position.character as usize {
+                return line_start + index;
+            }
+            utf16_count += char.len_utf16();
+        }
+        line_end
+    }
+}
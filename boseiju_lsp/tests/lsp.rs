@@ -0,0 +1,158 @@
+use lsp_server::Connection;
+use lsp_server::Message;
+use lsp_server::Notification;
+use lsp_server::Request;
+use lsp_server::RequestId;
+use lsp_types::notification::Notification as _;
+
+const TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// In-process client, talking to a server running on an other thread.
+struct Client {
+    connection: Connection,
+    next_id: i32,
+}
+
+impl Client {
+    fn request<R: lsp_types::request::Request>(&mut self, params: R::Params) -> Result<R::Result, String> {
+        self.next_id += 1;
+        let id = RequestId::from(self.next_id);
+        let request = Request::new(id.clone(), R::METHOD.to_string(), params);
+        self.send(Message::Request(request))?;
+        loop {
+            match self.receive()? {
+                Message::Response(response) if response.id == id => match (response.result, response.error) {
+                    (Some(result), None) => return serde_json::from_value(result).map_err(|e| e.to_string()),
+                    (_, error) => return Err(format!("Request {} failed: {error:?}", R::METHOD)),
+                },
+                _ => continue,
+            }
+        }
+    }
+
+    fn notify<N: lsp_types::notification::Notification>(&mut self, params: N::Params) -> Result<(), String> {
+        self.send(Message::Notification(Notification::new(N::METHOD.to_string(), params)))
+    }
+
+    fn diagnostics(&mut self) -> Result<Vec<lsp_types::Diagnostic>, String> {
+        loop {
+            match self.receive()? {
+                Message::Notification(notification)
+                    if notification.method == lsp_types::notification::PublishDiagnostics::METHOD =>
+                {
+                    let params: lsp_types::PublishDiagnosticsParams =
+                        serde_json::from_value(notification.params).map_err(|e| e.to_string())?;
+                    return Ok(params.diagnostics);
+                }
+                _ => continue,
+            }
+        }
+    }
+
+    fn send(&self, message: Message) -> Result<(), String> {
+        self.connection.sender.send(message).map_err(|e| e.to_string())
+    }
+
+    fn receive(&self) -> Result<Message, String> {
+        self.connection.receiver.recv_timeout(TIMEOUT).map_err(|e| e.to_string())
+    }
+}
+
+fn main() -> Result<(), String> {
+    let (server, client) = Connection::memory();
+    let server = std::thread::spawn(move || boseiju_lsp::run(&server).map_err(|e| e.to_string()));
+    let mut client = Client {
+        connection: client,
+        next_id: 0,
+    };
+
+    let capabilities = client.request::<lsp_types::request::Initialize>(Default::default())?;
+    if capabilities.capabilities.hover_provider.is_none() {
+        return Err("Server shall provide hovers".to_string());
+    }
+    client.notify::<lsp_types::notification::Initialized>(lsp_types::InitializedParams {})?;
+
+    let uri: lsp_types::Uri = "file:///cards/bear.card".parse().map_err(|_| "Invalid uri".to_string())?;
+    let text_document = lsp_types::TextDocumentIdentifier { uri: uri.clone() };
+    let at = |line: u32, character: u32| lsp_types::TextDocumentPositionParams {
+        text_document: text_document.clone(),
+        position: lsp_types::Position::new(line, character),
+    };
+
+    /* A valid card has no diagnostics */
+    client.notify::<lsp_types::notification::DidOpenTextDocument>(lsp_types::DidOpenTextDocumentParams {
+        text_document: lsp_types::TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "card".to_string(),
+            version: 0,
+            text: "Bear\nFlying (This creature can't be blocked except by creatures with flying.)\nDestroy target creature."
+                .to_string(),
+        },
+    })?;
+    let diagnostics = client.diagnostics()?;
+    if !diagnostics.is_empty() {
+        return Err(format!("Expected no diagnostics, got {diagnostics:?}"));
+    }
+
+    /* Hovers describe the node under the cursor */
+    let hover = client.request::<lsp_types::request::HoverRequest>(lsp_types::HoverParams {
+        text_document_position_params: at(2, 10),
+        work_done_progress_params: Default::default(),
+    })?;
+    match hover.and_then(|hover| hover.range) {
+        Some(range) if range.start.line == 2 => {}
+        other => return Err(format!("Expected a hover on the third line, got {other:?}")),
+    }
+
+    /* Semantic tokens highlight the keyword ability and the type */
+    let tokens = client.request::<lsp_types::request::SemanticTokensFullRequest>(lsp_types::SemanticTokensParams {
+        text_document: text_document.clone(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    })?;
+    match tokens {
+        Some(lsp_types::SemanticTokensResult::Tokens(tokens)) if tokens.data.len() >= 2 => {
+            let flying = &tokens.data[0];
+            if (flying.delta_line, flying.delta_start, flying.length) != (1, 0, 6) {
+                return Err(format!("Expected the first token to be flying, got {flying:?}"));
+            }
+        }
+        other => return Err(format!("Expected semantic tokens, got {other:?}")),
+    }
+
+    /* Errors are reported where they are in the document */
+    client.notify::<lsp_types::notification::DidChangeTextDocument>(lsp_types::DidChangeTextDocumentParams {
+        text_document: lsp_types::VersionedTextDocumentIdentifier {
+            uri: uri.clone(),
+            version: 1,
+        },
+        content_changes: vec![lsp_types::TextDocumentContentChangeEvent {
+            range: None,
+            range_length: None,
+            text: "Bear\nFlying\nDestroy target creature draw.".to_string(),
+        }],
+    })?;
+    let diagnostics = client.diagnostics()?;
+    match diagnostics.as_slice() {
+        [diagnostic] if diagnostic.range.start == lsp_types::Position::new(2, 24) => {}
+        other => return Err(format!("Expected a diagnostic on \"draw\", got {other:?}")),
+    }
+
+    /* Completions list the tokens the parser can accept */
+    let completions = client.request::<lsp_types::request::Completion>(lsp_types::CompletionParams {
+        text_document_position: at(2, 15),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+        context: None,
+    })?;
+    match completions {
+        Some(lsp_types::CompletionResponse::Array(items)) if items.iter().any(|item| item.label == "Creature") => {}
+        other => return Err(format!("Expected creature in the completions, got {other:?}")),
+    }
+
+    client.request::<lsp_types::request::Shutdown>(())?;
+    client.notify::<lsp_types::notification::Exit>(())?;
+    server.join().map_err(|_| "Server thread panicked".to_string())??;
+
+    Ok(())
+}
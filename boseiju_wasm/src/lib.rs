@@ -10,6 +10,33 @@ struct Node {
     hover_text: String,
 }
 
+/// Result of parsing a whole card, with the errors of every failing stage.
+#[derive(serde::Serialize)]
+struct CardParseResult {
+    card: Option<boseiju::Card>,
+    errors: Vec<StageError>,
+}
+
+#[derive(serde::Serialize)]
+struct StageError {
+    stage: &'static str,
+    message: String,
+    /// The serialized error, for the stages with structured errors such as the lexer and parser ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<serde_json::Value>,
+}
+
+impl StageError {
+    /// Stage error for an error that can be serialized, so its location can be used by the caller.
+    fn serialized<E: serde::Serialize + std::fmt::Display>(stage: &'static str, error: &E) -> StageError {
+        StageError {
+            stage,
+            message: error.to_string(),
+            error: Some(utils::error_to_json(error)),
+        }
+    }
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn lexer_preprocessor(
     card_name_ptr: *const u8,
@@ -37,7 +64,7 @@ pub extern "C" fn lex(
     let preprocessed = boseiju::preprocess(card_name, oracle_text);
     let tokens = match boseiju::lex(&preprocessed) {
         Ok(tokens) => tokens,
-        Err(e) => return utils::rust_string_to_ptr(format!("{{\"err\":{}}}", utils::lexer_error_to_json(e))),
+        Err(e) => return utils::rust_string_to_ptr(serde_json::json!({ "err": utils::error_to_json(&e) }).to_string()),
     };
 
     let nodes = tokens
@@ -58,12 +85,12 @@ pub extern "C" fn lex(
         })
         .collect::<Vec<_>>();
 
-    let result = match serde_json::to_string(&nodes) {
-        Ok(serialized) => format!("{{\"tokens\":{serialized}}}"),
-        Err(e) => return utils::rust_string_to_ptr(format!("{{\"err\":\"{}\"}}", e.to_string().replace('"', "\\\""))),
+    let result = match serde_json::to_value(&nodes) {
+        Ok(serialized) => serde_json::json!({ "tokens": serialized }),
+        Err(e) => serde_json::json!({ "err": e.to_string() }),
     };
 
-    utils::rust_string_to_ptr(result)
+    utils::rust_string_to_ptr(result.to_string())
 }
 
 #[unsafe(no_mangle)]
@@ -79,23 +106,113 @@ pub extern "C" fn parse(
     let preprocessed = boseiju::preprocess(card_name, oracle_text);
     let tokens = match boseiju::lex(&preprocessed) {
         Ok(tokens) => tokens,
-        Err(e) => return utils::rust_string_to_ptr(format!("{{\"err\":{}}}", utils::lexer_error_to_json(e))),
+        Err(e) => return utils::rust_string_to_ptr(serde_json::json!({ "err": utils::error_to_json(&e) }).to_string()),
     };
     let ab_tree = match boseiju::parse(tokens.as_slice()) {
         Ok(tree) => tree,
-        Err(e) => return utils::rust_string_to_ptr(format!("{{\"err\":{}}}", utils::parser_error_to_json(e))),
+        Err(e) => return utils::rust_string_to_ptr(serde_json::json!({ "err": utils::error_to_json(&e) }).to_string()),
     };
 
     let (nodes, _) = build_tree_nodes(&ab_tree as &dyn boseiju::ability_tree::AbilityTreeNode);
 
-    let result = match serde_json::to_string(&nodes) {
-        Ok(serialized) => format!("{{\"nodes\":{serialized}}}"),
-        Err(e) => return utils::rust_string_to_ptr(format!("{{\"err\":\"{}\"}}", e.to_string().replace('"', "\\\""))),
+    let result = match serde_json::to_value(&nodes) {
+        Ok(serialized) => serde_json::json!({ "nodes": serialized }),
+        Err(e) => serde_json::json!({ "err": e.to_string() }),
+    };
+
+    utils::rust_string_to_ptr(result.to_string())
+}
+
+/// Parse a whole card, provided as a Scryfall card JSON object.
+///
+/// On success, the full serialized card is returned. Otherwise, each stage of the
+/// conversion is run on its own to report all the stages that failed.
+#[unsafe(no_mangle)]
+pub extern "C" fn parse_card(card_json_ptr: *const u8, card_json_len: usize) -> *const u8 {
+    let card_json = utils::ptr_len_to_str(card_json_ptr, card_json_len);
+
+    let result = match serde_json::from_str::<mtg_cardbase::Card>(card_json) {
+        Ok(raw_card) => match boseiju::Card::try_from(&raw_card) {
+            Ok(card) => CardParseResult {
+                card: Some(card),
+                errors: Vec::new(),
+            },
+            Err(e) => CardParseResult {
+                card: None,
+                errors: card_stage_errors(&raw_card, e),
+            },
+        },
+        Err(e) => CardParseResult {
+            card: None,
+            errors: vec![StageError {
+                stage: "json",
+                message: e.to_string(),
+                error: None,
+            }],
+        },
+    };
+
+    let result = match serde_json::to_string(&result) {
+        Ok(serialized) => serialized,
+        Err(e) => serde_json::json!({ "err": e.to_string() }).to_string(),
     };
 
     utils::rust_string_to_ptr(result)
 }
 
+/// Run each stage of the card conversion separately, and collect the errors.
+///
+/// The card conversion error is kept as a fallback, for the stages that are not run here.
+fn card_stage_errors(raw_card: &mtg_cardbase::Card, card_error: String) -> Vec<StageError> {
+    let mut errors = Vec::new();
+
+    if let Err(e) = boseiju::card::legalities::Legalities::try_from(&raw_card.legalities) {
+        errors.push(StageError {
+            stage: "legalities",
+            message: e.to_string(),
+            error: None,
+        });
+    }
+    if let Err(e) = boseiju::ability_tree::colors::Colors::try_from(raw_card.color_identity.as_slice()) {
+        errors.push(StageError {
+            stage: "color_identity",
+            message: e.to_string(),
+            error: None,
+        });
+    }
+    if let Err(e) = boseiju::card::layout::Layout::try_from(raw_card) {
+        /* Oracle text errors are the most common layout errors, give them their own stages */
+        if let Some(oracle_text) = raw_card.oracle_text.as_ref() {
+            /* Same preprocessing as the layout, so the reported errors are the ones it failed on */
+            let card_name = boseiju::lexer::CardName::from_raw_card(raw_card);
+            let preprocessed = boseiju::lexer::preprocess_card(&card_name, oracle_text).text;
+            match boseiju::lex(&preprocessed) {
+                Ok(tokens) => {
+                    if let Err(e) = boseiju::parse(tokens.as_slice()) {
+                        errors.push(StageError::serialized("parser", &e));
+                    }
+                }
+                Err(e) => errors.push(StageError::serialized("lexer", &e)),
+            }
+        }
+        errors.push(StageError {
+            stage: "layout",
+            message: e,
+            error: None,
+        });
+    }
+
+    if errors.is_empty() {
+        errors.push(StageError {
+            stage: "card",
+            message: card_error,
+            error: None,
+        });
+    }
+
+    errors
+}
+
 fn build_tree_nodes(tree: &dyn boseiju::ability_tree::AbilityTreeNode) -> (Vec<Node>, usize) {
     let mut result = Vec::new();
 
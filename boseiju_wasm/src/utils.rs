@@ -31,57 +31,15 @@ pub fn idris_name<T: idris::Idris>(value: &T) -> &'static str {
     T::name_from_id(value.id())
 }
 
-pub fn lexer_error_to_json(error: boseiju::lexer::LexerError) -> String {
-    match error {
-        boseiju::lexer::LexerError::NoTokenMatch { start, end, tokens } => {
-            let message = format!("No tokens match for: \"{tokens}\"");
-            let message = message.replace('"', "\\\"");
-            let message = message.replace('\n', "\\n");
-            format!("{{\"start\":{start},\"end\":{end},\"message\":\"{message}\"}}")
-        }
-    }
-}
-
-pub fn parser_error_to_json(error: boseiju::parser::ParserError) -> String {
-    match error {
-        boseiju::parser::ParserError::AmbiguousCandidates => {
-            let message = "Multiple possible ways of merging the tokens !";
-            format!("{{\"start\":0,\"end\":0,\"message\":\"{message}\"}}")
-        }
-        boseiju::parser::ParserError::FailedToApplyRule { merge_error, for_rule } => {
-            let message = format!("Failed to apply rule: {merge_error} (rule defined at {for_rule})");
-            let message = message.replace('"', "\\\"");
-            let message = message.replace('\n', "\\n");
-            format!("{{\"start\":0,\"end\":0,\"message\":\"{message}\"}}")
-        }
-        boseiju::parser::ParserError::InvalidEarleyTable => {
-            let message = "Invalid Earley table, something went VERY wrong :)";
-            let message = message.replace('"', "\\\"");
-            let message = message.replace('\n', "\\n");
-            format!("{{\"start\":0,\"end\":0,\"message\":\"{message}\"}}")
-        }
-        boseiju::parser::ParserError::UnexpectedToken { found, expecting, .. } => {
-            let mut message = format!("Unexpected token {} at position {}", found.name, found.position);
-            message.push_str(&format!("\nExpecting one of:"));
-            for expecting in expecting.iter().take(10) {
-                let node_name = <boseiju::parser::ParserNode as idris::Idris>::name_from_id(expecting.expected);
-                message.push_str(&format!("\n - token \"{node_name}\" to create nodes"));
-                for (i, (for_node, for_rule)) in expecting.for_nodes.iter().enumerate() {
-                    let node_name = <boseiju::parser::ParserNode as idris::Idris>::name_from_id(*for_node);
-                    if i == 0 {
-                        message.push_str(&format!(" \"{node_name}\" (at {for_rule})"));
-                    } else {
-                        message.push_str(&format!(", \"{node_name}\" (at {for_rule})"));
-                    }
-                }
-            }
-            let message = message.replace('"', "\\\"");
-            let message = message.replace('\n', "\\n");
-            format!(
-                "{{\"start\":{},\"end\":{},\"message\":\"{message}\"}}",
-                found.position,
-                found.position + found.length
-            )
-        }
+/// Serialize an error to JSON.
+///
+/// If the error can't be serialized, the serialization error is returned in its place.
+pub fn error_to_json<E: serde::Serialize>(error: &E) -> serde_json::Value {
+    match serde_json::to_value(error) {
+        Ok(serialized) => serialized,
+        Err(e) => serde_json::json!({
+            "kind": "SerializationError",
+            "message": e.to_string(),
+        }),
     }
 }
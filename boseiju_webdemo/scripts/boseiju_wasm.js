@@ -127,6 +127,27 @@ async function load_wasm() {
 
       return json_result.nodes;
     },
+
+    parse_card(card_json) {
+      /* card_json is a Scryfall card object, as a JSON string */
+      const wasm_card_json = WasmString.fromJS(wasm, card_json);
+
+      const card_ptr = wasm.instance.exports.parse_card(
+        wasm_card_json.ptr,
+        wasm_card_json.len,
+      );
+      const wasm_card = WasmString.fromPtr(wasm, card_ptr);
+      const card = wasm_card.toJS();
+
+      wasm_card_json.free();
+      wasm_card.free();
+
+      const json_result = JSON.parse(card);
+      if (json_result.err) throw json_result.err;
+
+      /* Stage errors are returned along the card, for the caller to display */
+      return json_result;
+    },
   };
 }
 
@@ -173,11 +194,64 @@ function fill_grid_with_tokens(grid, tokens) {
   }
 }
 
+/// Location of a lexer or parser error in the preprocessed text, as a [start, end) pair.
+function error_location(error) {
+  switch (error.kind) {
+    case "NoTokenMatch":
+      return [error.start, error.end];
+    case "UnexpectedToken":
+      return [error.found.position, error.found.position + error.found.length];
+    case "FailedToApplyRule":
+    case "AmbiguousCandidates":
+      return [error.position, error.position + error.length];
+    default:
+      return [0, 0];
+  }
+}
+
+/// Human readable message of a lexer or parser error.
+function error_message(error) {
+  const rule = (loc) => `${loc.file}:${loc.line}`;
+  switch (error.kind) {
+    case "NoTokenMatch":
+      return `No tokens match for: "${error.tokens}"`;
+    case "UnexpectedToken":
+      return `Unexpected token ${error.found.name} at position ${error.found.position}, expecting one of:`;
+    case "FailedToApplyRule":
+      return `Failed to apply rule: ${error.merge_error} (rule defined at ${rule(error.for_rule)})`;
+    case "AmbiguousCandidates":
+      return `Multiple possible ways of merging the tokens: ${error.candidates.map(rule).join(", ")}`;
+    case "InvalidEarleyTable":
+      return "Invalid Earley table, something went VERY wrong :)";
+    default:
+      return error.message || "Unknown error";
+  }
+}
+
+/// Create a clickable suggestion for an expected token, that reveals the rules expecting it.
+function expected_token_suggestion(expecting) {
+  const suggestion = document.createElement("details");
+  suggestion.className = "error-suggestion";
+
+  const summary = document.createElement("summary");
+  summary.textContent = expecting.expected;
+  suggestion.appendChild(summary);
+
+  const list = document.createElement("ul");
+  for (const for_node of expecting.for_nodes) {
+    const item = document.createElement("li");
+    item.textContent = `${for_node.node} (at ${for_node.rule.file}:${for_node.rule.line})`;
+    list.appendChild(item);
+  }
+  suggestion.appendChild(list);
+
+  return suggestion;
+}
+
 function add_error_row(grid, layer, error) {
   const row = layer + 2; // 1 = chars, 2 = layer 0
 
-  const start = error.start;
-  const end = error.end;
+  const [start, end] = error_location(error);
 
   const currentRows = getComputedStyle(grid).gridTemplateRows.split(" ").length;
   if (row > currentRows) {
@@ -196,7 +270,10 @@ function add_error_row(grid, layer, error) {
   message.className = "error-message";
   message.style.gridRow = row + 1;
   message.style.gridColumn = `1 / -1`;
-  message.textContent = error.message;
+  message.textContent = error_message(error);
+  for (const expecting of error.expecting || []) {
+    message.appendChild(expected_token_suggestion(expecting));
+  }
   grid.appendChild(message);
 
   grid.style.gridTemplateRows = `repeat(${row + 1}, auto)`;
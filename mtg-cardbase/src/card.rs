@@ -11,6 +11,8 @@ pub struct Card {
     pub tcgplayer_id: Option<u64>,
     pub cardmarket_id: Option<u64>,
     pub name: String,
+    pub flavor_name: Option<String>,
+    pub printed_name: Option<String>,
     pub lang: String,
     pub released_at: String,
     pub uri: String,
@@ -30,6 +32,7 @@ pub struct Card {
     pub keywords: arrayvec::ArrayVec<String, 16>,
     pub produced_mana: Option<arrayvec::ArrayVec<String, 8>>,
     pub loyalty: Option<String>,
+    pub defense: Option<String>,
     pub legalities: Legalities,
     pub games: arrayvec::ArrayVec<String, 8>,
     pub reserved: bool,